@@ -0,0 +1,185 @@
+// Fuzz target for `SafeBoundedQueue` / `EnterpriseMemoryPool` concurrency
+// invariants, driven by `cargo hfuzz run queue_pool_ops`.
+//
+// The production types live in a binary crate (`validate_low_latency_backend_final.rs`,
+// which has its own `fn main`), not a library, so there is nothing to depend
+// on from Cargo.toml. Instead the file is pulled in as a child module via
+// `#[path]`, the same way a `mod foo;` would work if it lived next to this
+// one - this fuzzes the real implementation rather than a fuzz-local copy of
+// it. Only the handful of items this harness actually touches were promoted
+// from private to `pub` in that file.
+#[allow(dead_code, unused)]
+#[path = "../../validate_low_latency_backend_final.rs"]
+mod production_backend;
+
+use honggfuzz::fuzz;
+use production_backend::{EnterpriseCacheAlignedCounter, EnterpriseMemoryPool, SafeBoundedQueue};
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicIsize, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+// Mirrors `SafeBoundedQueue::OPTIMAL_SIZE`, which is private and not part of
+// the minimal surface promoted for this harness.
+const QUEUE_CAPACITY: isize = 1024;
+const THREAD_COUNT: usize = 4;
+
+#[derive(Debug, Clone, Copy)]
+enum Op {
+    Enqueue(u64),
+    Dequeue,
+    Allocate,
+    Free(u8),
+    Increment,
+}
+
+// Decodes a deterministic script of (thread, operation) pairs straight out
+// of the raw fuzz bytes, so the same input always replays the same script -
+// this is what makes corpus minimization and crash replay meaningful. A
+// truncated operand (not enough trailing bytes) just ends the script early
+// rather than panicking, so minimized/truncated inputs stay valid.
+fn decode_ops(data: &[u8]) -> Vec<(usize, Op)> {
+    let mut ops = Vec::new();
+    let mut i = 0;
+    while i < data.len() {
+        let opcode = data[i] % 5;
+        i += 1;
+
+        let thread_id = if i < data.len() {
+            let t = data[i] as usize % THREAD_COUNT;
+            i += 1;
+            t
+        } else {
+            0
+        };
+
+        let op = match opcode {
+            0 => {
+                if i + 8 > data.len() {
+                    break;
+                }
+                let mut buf = [0u8; 8];
+                buf.copy_from_slice(&data[i..i + 8]);
+                i += 8;
+                Op::Enqueue(u64::from_le_bytes(buf))
+            }
+            1 => Op::Dequeue,
+            2 => Op::Allocate,
+            3 => {
+                if i >= data.len() {
+                    break;
+                }
+                let slot = data[i];
+                i += 1;
+                Op::Free(slot)
+            }
+            _ => Op::Increment,
+        };
+
+        ops.push((thread_id, op));
+    }
+    ops
+}
+
+fn run_script(data: &[u8]) {
+    let ops = decode_ops(data);
+    if ops.is_empty() {
+        return;
+    }
+
+    let mut scripts: Vec<Vec<Op>> = (0..THREAD_COUNT).map(|_| Vec::new()).collect();
+    for (thread_id, op) in ops {
+        scripts[thread_id].push(op);
+    }
+
+    let queue = Arc::new(SafeBoundedQueue::<u64>::new());
+    let pool = Arc::new(Mutex::new(EnterpriseMemoryPool::<u64>::new()));
+    let counter = Arc::new(EnterpriseCacheAlignedCounter::new());
+
+    let ever_enqueued: Arc<Mutex<HashSet<u64>>> = Arc::new(Mutex::new(HashSet::new()));
+    let queue_len = Arc::new(AtomicIsize::new(0));
+    let live_pointers: Arc<Mutex<HashSet<usize>>> = Arc::new(Mutex::new(HashSet::new()));
+    let live_allocations: Arc<Mutex<Vec<Box<u64>>>> = Arc::new(Mutex::new(Vec::new()));
+    let successful_increments = Arc::new(AtomicUsize::new(0));
+
+    let handles: Vec<_> = scripts
+        .into_iter()
+        .map(|script| {
+            let queue = Arc::clone(&queue);
+            let pool = Arc::clone(&pool);
+            let counter = Arc::clone(&counter);
+            let ever_enqueued = Arc::clone(&ever_enqueued);
+            let queue_len = Arc::clone(&queue_len);
+            let live_pointers = Arc::clone(&live_pointers);
+            let live_allocations = Arc::clone(&live_allocations);
+            let successful_increments = Arc::clone(&successful_increments);
+
+            thread::spawn(move || {
+                for op in script {
+                    match op {
+                        Op::Enqueue(value) => {
+                            if queue.enqueue(value).is_ok() {
+                                ever_enqueued.lock().unwrap().insert(value);
+                                let new_len = queue_len.fetch_add(1, Ordering::SeqCst) + 1;
+                                assert!(new_len <= QUEUE_CAPACITY, "queue exceeded its bound");
+                            }
+                        }
+                        Op::Dequeue => {
+                            if let Some(value) = queue.dequeue() {
+                                assert!(
+                                    ever_enqueued.lock().unwrap().contains(&value),
+                                    "dequeue returned a request that was never enqueued"
+                                );
+                                queue_len.fetch_sub(1, Ordering::SeqCst);
+                            }
+                        }
+                        Op::Allocate => {
+                            if let Some(obj) = pool.lock().unwrap().allocate() {
+                                let ptr = obj.as_ref() as *const u64 as usize;
+                                let fresh = live_pointers.lock().unwrap().insert(ptr);
+                                assert!(
+                                    fresh,
+                                    "pool handed out a slot already held by a live allocation"
+                                );
+                                live_allocations.lock().unwrap().push(obj);
+                            }
+                        }
+                        Op::Free(slot) => {
+                            let mut allocations = live_allocations.lock().unwrap();
+                            if !allocations.is_empty() {
+                                let idx = slot as usize % allocations.len();
+                                let obj = allocations.remove(idx);
+                                let ptr = obj.as_ref() as *const u64 as usize;
+                                live_pointers.lock().unwrap().remove(&ptr);
+                                pool.lock().unwrap().deallocate(obj);
+                            }
+                        }
+                        Op::Increment => {
+                            counter.increment();
+                            successful_increments.fetch_add(1, Ordering::SeqCst);
+                        }
+                    }
+                }
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        handle.join().expect("worker thread panicked");
+    }
+
+    let (_, operations_count, _) = counter.get_stats();
+    assert_eq!(
+        operations_count as usize,
+        successful_increments.load(Ordering::SeqCst),
+        "counter final value diverged from the total successful increments"
+    );
+}
+
+fn main() {
+    loop {
+        fuzz!(|data: &[u8]| {
+            run_script(data);
+        });
+    }
+}
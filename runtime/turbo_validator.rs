@@ -2,7 +2,8 @@
 // Integrates with backend startup to prove 99.9% performance on every deployment
 
 use std::collections::VecDeque;
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Mutex;
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use std::sync::Arc;
 use std::mem;
@@ -23,6 +24,17 @@ use super::*;
 #[derive(Debug, Clone)]
 pub struct TurboResults {
     pub avg_latency_ns: f64,
+    pub min_latency_ns: f64,
+    pub max_latency_ns: f64,
+    pub std_dev_latency_ns: f64,
+    pub p50_latency_ns: f64,
+    pub p90_latency_ns: f64,
+    pub p99_latency_ns: f64,
+    pub p999_latency_ns: f64,
+    /// Raw per-bucket sample counts from the run's [`LatencyHistogram`],
+    /// kept around so a Prometheus `histogram` type can be exported
+    /// alongside the summary percentiles above.
+    pub latency_bucket_counts: Vec<u64>,
     pub throughput: f64,
     pub iterations: usize,
     pub safety_factor: f64,
@@ -31,6 +43,257 @@ pub struct TurboResults {
     pub execution_count: usize,
 }
 
+// PRODUCTION: Fixed-bucket log-scale latency histogram.
+//
+// A 100k-iteration run can't afford to retain every sample just to compute
+// percentiles afterward, so each per-iteration duration is folded into a
+// power-of-two bucket as it's measured. Bucket `i` covers `[2^i, 2^(i+1))`
+// nanoseconds; 28 buckets span ~1ns up to ~134ms, comfortably bracketing
+// the ~10ns-to-100ms range this module cares about, with the top bucket
+// catching anything slower (which, against a 20ms SLA, is already a
+// failure worth seeing).
+struct LatencyHistogram {
+    buckets: [u64; Self::BUCKET_COUNT],
+    count: u64,
+    sum_ns: f64,
+    sum_sq_ns: f64,
+    min_ns: f64,
+    max_ns: f64,
+}
+
+impl LatencyHistogram {
+    const BUCKET_COUNT: usize = 28;
+
+    fn new() -> Self {
+        Self {
+            buckets: [0; Self::BUCKET_COUNT],
+            count: 0,
+            sum_ns: 0.0,
+            sum_sq_ns: 0.0,
+            min_ns: f64::MAX,
+            max_ns: 0.0,
+        }
+    }
+
+    fn record(&mut self, duration_ns: f64) {
+        let bucket = if duration_ns < 1.0 {
+            0
+        } else {
+            (duration_ns.log2().floor() as usize).min(Self::BUCKET_COUNT - 1)
+        };
+        self.buckets[bucket] += 1;
+        self.count += 1;
+        self.sum_ns += duration_ns;
+        self.sum_sq_ns += duration_ns * duration_ns;
+        if duration_ns < self.min_ns {
+            self.min_ns = duration_ns;
+        }
+        if duration_ns > self.max_ns {
+            self.max_ns = duration_ns;
+        }
+    }
+
+    fn bucket_upper_bound_ns(bucket: usize) -> f64 {
+        (1u64 << (bucket + 1)) as f64
+    }
+
+    fn bucket_counts(&self) -> [u64; Self::BUCKET_COUNT] {
+        self.buckets
+    }
+
+    // Walks cumulative bucket counts until the target rank is crossed and
+    // reports that bucket's upper bound as the percentile estimate.
+    fn percentile(&self, p: f64) -> f64 {
+        if self.count == 0 {
+            return 0.0;
+        }
+        let target = (p * self.count as f64).ceil() as u64;
+        let mut cumulative = 0u64;
+        for (bucket, &bucket_count) in self.buckets.iter().enumerate() {
+            cumulative += bucket_count;
+            if cumulative >= target {
+                return Self::bucket_upper_bound_ns(bucket);
+            }
+        }
+        self.max_ns
+    }
+
+    fn mean_ns(&self) -> f64 {
+        if self.count == 0 {
+            0.0
+        } else {
+            self.sum_ns / self.count as f64
+        }
+    }
+
+    fn std_dev_ns(&self) -> f64 {
+        if self.count == 0 {
+            return 0.0;
+        }
+        let mean = self.mean_ns();
+        let variance = (self.sum_sq_ns / self.count as f64) - (mean * mean);
+        variance.max(0.0).sqrt()
+    }
+
+    fn min_ns(&self) -> f64 {
+        if self.count == 0 {
+            0.0
+        } else {
+            self.min_ns
+        }
+    }
+}
+
+// PRODUCTION: Hardware capability profile.
+//
+// A 99.9% validation score is meaningless without knowing what machine
+// produced it: an under-provisioned CI runner could "pass" a benchmark a
+// production box would fail. This measures the host's raw CPU, memory, and
+// disk capability so the comprehensive validation result can be read next
+// to the hardware it ran on, and optionally gates validation on configured
+// minimums.
+#[derive(Debug, Clone)]
+pub struct HardwareProfile {
+    pub cpu_score: f64,
+    pub memory_bandwidth_gib_per_sec: f64,
+    pub disk_throughput_mib_per_sec: f64,
+    pub cache_line_size: usize,
+    pub isa_extensions: Vec<&'static str>,
+}
+
+impl HardwareProfile {
+    // Iterations/second achieved by `measure_cpu_score`'s loop on the
+    // baseline development machine the 20ms target was tuned against. A
+    // score of `1.0` means "about as fast as that machine".
+    const REFERENCE_CPU_SCORE_ITERS_PER_SEC: f64 = 200_000_000.0;
+    const REFERENCE_MEMORY_BANDWIDTH_GIB_PER_SEC: f64 = 10.0;
+
+    pub fn probe() -> Self {
+        let cpu_features = CpuFeatures::detect();
+        Self {
+            cpu_score: Self::measure_cpu_score(),
+            memory_bandwidth_gib_per_sec: Self::measure_memory_bandwidth(),
+            disk_throughput_mib_per_sec: Self::measure_disk_throughput(),
+            cache_line_size: cpu_features.cache_line_size,
+            isa_extensions: Self::describe_isa_extensions(&cpu_features),
+        }
+    }
+
+    /// Tight integer/float loop timed via [`ProductionHighPrecisionTimer`],
+    /// normalized against `REFERENCE_CPU_SCORE_ITERS_PER_SEC`.
+    fn measure_cpu_score() -> f64 {
+        const ITERATIONS: u64 = 20_000_000;
+        let timer = ProductionHighPrecisionTimer::new();
+
+        let (_, duration, _) = timer.measure_precise(|| {
+            let mut acc_i: u64 = 0;
+            let mut acc_f: f64 = 0.0;
+            for i in 0..ITERATIONS {
+                acc_i = acc_i.wrapping_add(i).wrapping_mul(2654435761);
+                acc_f += (i as f64).sqrt();
+            }
+            // Keep the optimizer from proving the loop has no observable
+            // effect and eliminating it.
+            std::hint::black_box((acc_i, acc_f));
+        });
+
+        let elapsed = duration.as_secs_f64().max(1e-9);
+        (ITERATIONS as f64 / elapsed) / Self::REFERENCE_CPU_SCORE_ITERS_PER_SEC
+    }
+
+    /// Sums a buffer several times the size of a typical L2 cache, over
+    /// several passes, so the measurement reflects main-memory bandwidth
+    /// rather than cache hits.
+    fn measure_memory_bandwidth() -> f64 {
+        const BUFFER_BYTES: usize = 32 * 1024 * 1024;
+        const PASSES: usize = 4;
+
+        let buffer = vec![0xA5u8; BUFFER_BYTES];
+        let start = Instant::now();
+        let mut sum: u64 = 0;
+        for _ in 0..PASSES {
+            for &byte in &buffer {
+                sum = sum.wrapping_add(byte as u64);
+            }
+        }
+        std::hint::black_box(sum);
+
+        let elapsed = start.elapsed().as_secs_f64().max(1e-9);
+        let total_gib = (BUFFER_BYTES * PASSES) as f64 / (1024.0 * 1024.0 * 1024.0);
+        total_gib / elapsed
+    }
+
+    /// Writes then reads back a scratch file under `std::env::temp_dir()`
+    /// to measure rough disk throughput, averaging the write and read
+    /// passes since either alone can be skewed by page-cache effects on a
+    /// freshly written file. Cleans up the scratch file regardless of
+    /// outcome, and returns `0.0` rather than panicking if the host has no
+    /// writable temp directory.
+    fn measure_disk_throughput() -> f64 {
+        const FILE_BYTES: usize = 64 * 1024 * 1024;
+        let path = std::env::temp_dir().join(format!("turbo_validator_disk_probe_{}.tmp", std::process::id()));
+        let buffer = vec![0x5Au8; FILE_BYTES];
+
+        let write_start = Instant::now();
+        let write_ok = fs::write(&path, &buffer).is_ok();
+        let write_elapsed = write_start.elapsed().as_secs_f64().max(1e-9);
+
+        let read_start = Instant::now();
+        let read_ok = fs::read(&path).map(|data| data.len() == FILE_BYTES).unwrap_or(false);
+        let read_elapsed = read_start.elapsed().as_secs_f64().max(1e-9);
+
+        let _ = fs::remove_file(&path);
+
+        if !write_ok || !read_ok {
+            return 0.0;
+        }
+
+        let total_mib = FILE_BYTES as f64 / (1024.0 * 1024.0);
+        (total_mib / write_elapsed + total_mib / read_elapsed) / 2.0
+    }
+
+    fn describe_isa_extensions(cpu_features: &CpuFeatures) -> Vec<&'static str> {
+        let mut extensions = Vec::new();
+        if cpu_features.has_rdtsc {
+            extensions.push("rdtsc");
+        }
+        if cpu_features.has_prefetch {
+            extensions.push("prefetch");
+        }
+        if cpu_features.has_avx {
+            extensions.push("avx");
+        }
+        extensions
+    }
+
+    pub fn meets_minimums(&self, minimums: &HardwareMinimums) -> bool {
+        self.cpu_score >= minimums.min_cpu_score
+            && self.memory_bandwidth_gib_per_sec >= minimums.min_memory_bandwidth_gib_per_sec
+            && self.disk_throughput_mib_per_sec >= minimums.min_disk_throughput_mib_per_sec
+    }
+}
+
+// PRODUCTION: Minimum hardware scores a host must clear for comprehensive
+// validation to report a passing score. Defaults to "no minimum" so calling
+// `run_comprehensive_validation()` with no configuration keeps its existing
+// behavior.
+#[derive(Debug, Clone)]
+pub struct HardwareMinimums {
+    pub min_cpu_score: f64,
+    pub min_memory_bandwidth_gib_per_sec: f64,
+    pub min_disk_throughput_mib_per_sec: f64,
+}
+
+impl Default for HardwareMinimums {
+    fn default() -> Self {
+        Self {
+            min_cpu_score: 0.0,
+            min_memory_bandwidth_gib_per_sec: 0.0,
+            min_disk_throughput_mib_per_sec: 0.0,
+        }
+    }
+}
+
 // PRODUCTION: Turbo validation module
 pub mod turbo_validator {
     use super::*;
@@ -57,9 +320,13 @@ pub mod turbo_validator {
                 .as_secs();
 
             let log_entry = format!(
-                "[{}] TURBO_VALIDATION: latency={:.2}ns throughput={:.0}req/s safety_factor={:.0}x passed={} execution={}\n",
+                "[{}] TURBO_VALIDATION: latency_avg={:.2}ns latency_p50={:.0}ns latency_p99={:.0}ns latency_p999={:.0}ns std_dev={:.2}ns throughput={:.0}req/s safety_factor={:.0}x passed={} execution={}\n",
                 timestamp_str,
                 results.avg_latency_ns,
+                results.p50_latency_ns,
+                results.p99_latency_ns,
+                results.p999_latency_ns,
+                results.std_dev_latency_ns,
                 results.throughput,
                 results.safety_factor,
                 results.passed,
@@ -101,14 +368,18 @@ pub mod turbo_validator {
         let timer = ProductionHighPrecisionTimer::new();
 
         let iterations = 100_000;
+        let mut histogram = LatencyHistogram::new();
 
-        // Run the benchmark
+        // Run the benchmark, timing each iteration individually so the
+        // histogram captures the real per-op distribution rather than just
+        // the batch average.
         let (_, duration, _) = timer.measure_precise(|| {
             for i in 0..iterations {
                 let mut request = ProductionOptimizedRequest::default();
                 request.request_id = i as u64;
                 request.priority = (i % 4) as u32;
 
+                let op_start = Instant::now();
                 // Simulate full pipeline with safety checks
                 match queue.enqueue(request) {
                     Ok(_) => {
@@ -122,23 +393,41 @@ pub mod turbo_validator {
                         // Queue full - this demonstrates backpressure
                     }
                 }
+                histogram.record(op_start.elapsed().as_nanos() as f64);
             }
         });
 
         let avg_latency_ns = duration.as_nanos() as f64 / iterations as f64;
         let throughput = iterations as f64 / duration.as_secs_f64();
-        let safety_factor = 20_000_000.0 / avg_latency_ns; // 20ms target
-        let passed = avg_latency_ns < 20_000_000.0;
+        let p50_latency_ns = histogram.percentile(0.50);
+        let p90_latency_ns = histogram.percentile(0.90);
+        let p99_latency_ns = histogram.percentile(0.99);
+        let p999_latency_ns = histogram.percentile(0.999);
+        // Gate on p99 rather than the mean: a tail that blows the 20ms SLA
+        // can hide entirely behind a healthy average.
+        let safety_factor = 20_000_000.0 / p99_latency_ns; // 20ms target
+        let passed = p99_latency_ns < 20_000_000.0;
 
         println!("   📊 Turbo Validation Results:");
         println!("   • Iterations: {}", iterations);
         println!("   • Average latency: {:.2}ns", avg_latency_ns);
+        println!("   • Min/Max latency: {:.2}ns / {:.2}ns", histogram.min_ns(), histogram.max_ns());
+        println!("   • Std dev: {:.2}ns", histogram.std_dev_ns());
+        println!("   • p50/p90/p99/p999: {:.0}ns / {:.0}ns / {:.0}ns / {:.0}ns", p50_latency_ns, p90_latency_ns, p99_latency_ns, p999_latency_ns);
         println!("   • Throughput: {:.0} requests/second", throughput);
-        println!("   • Safety factor: {:.0}x", safety_factor);
+        println!("   • Safety factor (p99): {:.0}x", safety_factor);
         println!("   • Status: {}", if passed { "✅ PASSED" } else { "❌ FAILED" });
 
         TurboResults {
             avg_latency_ns,
+            min_latency_ns: histogram.min_ns(),
+            max_latency_ns: histogram.max_ns(),
+            std_dev_latency_ns: histogram.std_dev_ns(),
+            p50_latency_ns,
+            p90_latency_ns,
+            p99_latency_ns,
+            p999_latency_ns,
+            latency_bucket_counts: histogram.bucket_counts().to_vec(),
             throughput,
             iterations,
             safety_factor,
@@ -199,12 +488,40 @@ pub mod turbo_validator {
 
     // PRODUCTION: Comprehensive validation suite
     pub fn run_comprehensive_validation() -> ComprehensiveValidationResults {
+        run_comprehensive_validation_with_minimums(HardwareMinimums::default())
+    }
+
+    // PRODUCTION: Comprehensive validation suite, gated on the host clearing
+    // `minimums` before the benchmark is trusted to mean anything.
+    pub fn run_comprehensive_validation_with_minimums(minimums: HardwareMinimums) -> ComprehensiveValidationResults {
         println!("🔬 RUNNING COMPREHENSIVE VALIDATION SUITE...");
 
+        println!("   🖥️  Profiling host hardware...");
+        let hardware_profile = HardwareProfile::probe();
+        let hardware_ok = hardware_profile.meets_minimums(&minimums);
+        println!(
+            "   • CPU score: {:.2}x reference | Memory bandwidth: {:.2} GiB/s | Disk throughput: {:.2} MiB/s",
+            hardware_profile.cpu_score, hardware_profile.memory_bandwidth_gib_per_sec, hardware_profile.disk_throughput_mib_per_sec
+        );
+        println!(
+            "   • Cache line: {} bytes | ISA extensions: {}",
+            hardware_profile.cache_line_size,
+            if hardware_profile.isa_extensions.is_empty() {
+                "none detected".to_string()
+            } else {
+                hardware_profile.isa_extensions.join(", ")
+            }
+        );
+        if !hardware_ok {
+            println!("   ⚠️  Host falls below configured minimum hardware scores");
+        }
+
         let turbo_results = run_turbo_validation();
         let safety_results = validate_safety_components();
 
-        let overall_score = if turbo_results.passed && safety_results.overall_passed {
+        let overall_score = if !hardware_ok {
+            0.0
+        } else if turbo_results.passed && safety_results.overall_passed {
             99.9
         } else if turbo_results.passed || safety_results.overall_passed {
             85.0
@@ -217,10 +534,251 @@ pub mod turbo_validator {
         ComprehensiveValidationResults {
             turbo_results,
             safety_results,
+            hardware_profile,
+            hardware_ok,
             overall_score,
             timestamp: SystemTime::now(),
         }
     }
+
+    // PRODUCTION: Token-bucket rate limiter, used to pace continuous load
+    // generation to a realistic requests/sec instead of running open-loop.
+    struct TokenBucket {
+        rate_per_sec: f64,
+        capacity: f64,
+        tokens: f64,
+        last_refill: Instant,
+    }
+
+    impl TokenBucket {
+        fn new(rate_per_sec: f64) -> Self {
+            let capacity = rate_per_sec.max(1.0);
+            Self {
+                rate_per_sec,
+                capacity,
+                tokens: capacity,
+                last_refill: Instant::now(),
+            }
+        }
+
+        fn refill(&mut self) {
+            let now = Instant::now();
+            let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+            self.tokens = (self.tokens + elapsed * self.rate_per_sec).min(self.capacity);
+            self.last_refill = now;
+        }
+
+        // Spins (with a short sleep between polls) until a token is
+        // available, then consumes it. A spin rather than a blocking timer
+        // keeps pacing simple and avoids dragging in a dependency for
+        // sub-millisecond scheduling.
+        fn acquire(&mut self) {
+            loop {
+                self.refill();
+                if self.tokens >= 1.0 {
+                    self.tokens -= 1.0;
+                    return;
+                }
+                thread::sleep(Duration::from_micros(100));
+            }
+        }
+    }
+
+    // PRODUCTION: Configuration for a continuous (as opposed to single-burst)
+    // validation run.
+    #[derive(Debug, Clone)]
+    pub struct ContinuousValidationConfig {
+        /// Stop after this much wall-clock time has elapsed, if set.
+        pub duration: Option<Duration>,
+        /// Stop once this many total requests have been issued (failed
+        /// enqueues included), if set.
+        /// `duration` and `target_request_count` may both be set; whichever
+        /// is reached first wins.
+        pub target_request_count: Option<u64>,
+        /// Aggregate requests/sec across all workers.
+        pub rate_per_sec: f64,
+        pub worker_count: usize,
+        /// How often to emit an aggregate snapshot while the run is in
+        /// progress.
+        pub snapshot_interval: Duration,
+        /// Consecutive enqueue failures (across all workers) before the run
+        /// trips the shared stop-on-fatal flag.
+        pub fatal_enqueue_failure_threshold: u64,
+    }
+
+    impl Default for ContinuousValidationConfig {
+        fn default() -> Self {
+            Self {
+                duration: Some(Duration::from_secs(60)),
+                target_request_count: None,
+                rate_per_sec: 10_000.0,
+                worker_count: 4,
+                snapshot_interval: Duration::from_secs(5),
+                fatal_enqueue_failure_threshold: 1_000,
+            }
+        }
+    }
+
+    // PRODUCTION: An aggregate metrics snapshot taken at one point during a
+    // continuous validation run.
+    #[derive(Debug, Clone)]
+    pub struct ContinuousValidationSnapshot {
+        pub elapsed: Duration,
+        pub total_requests: u64,
+        pub throughput: f64,
+        pub avg_latency_ns: f64,
+        pub p99_latency_ns: f64,
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct ContinuousValidationResults {
+        pub snapshots: Vec<ContinuousValidationSnapshot>,
+        pub total_requests: u64,
+        pub stopped_on_fatal: bool,
+        pub elapsed: Duration,
+    }
+
+    // PRODUCTION: Drive the queue/counter pipeline continuously at a bounded
+    // rate instead of firing one fixed-size burst, so throughput and tail
+    // latency can be watched evolve over a realistic run rather than read
+    // off a single end-of-run average.
+    pub fn run_continuous_validation(config: ContinuousValidationConfig) -> ContinuousValidationResults {
+        println!("🔁 RUNNING CONTINUOUS VALIDATION...");
+
+        let queue: Arc<SafeBoundedQueue<ProductionOptimizedRequest>> = Arc::new(SafeBoundedQueue::new());
+        let counter: Arc<EnterpriseCacheAlignedCounter> = Arc::new(EnterpriseCacheAlignedCounter::new());
+        let histogram: Arc<Mutex<LatencyHistogram>> = Arc::new(Mutex::new(LatencyHistogram::new()));
+        let stop_on_fatal = Arc::new(AtomicBool::new(false));
+        let total_requests = Arc::new(AtomicUsize::new(0));
+        let consecutive_enqueue_failures = Arc::new(AtomicUsize::new(0));
+
+        let worker_count = config.worker_count.max(1);
+        let per_worker_rate = config.rate_per_sec / worker_count as f64;
+        let duration = config.duration;
+        let target_request_count = config.target_request_count;
+        let fatal_threshold = config.fatal_enqueue_failure_threshold;
+
+        let start = Instant::now();
+
+        let workers: Vec<_> = (0..worker_count)
+            .map(|worker_id| {
+                let queue = Arc::clone(&queue);
+                let counter = Arc::clone(&counter);
+                let histogram = Arc::clone(&histogram);
+                let stop_on_fatal = Arc::clone(&stop_on_fatal);
+                let total_requests = Arc::clone(&total_requests);
+                let consecutive_enqueue_failures = Arc::clone(&consecutive_enqueue_failures);
+
+                thread::spawn(move || {
+                    let mut bucket = TokenBucket::new(per_worker_rate);
+                    let mut next_id: u64 = worker_id as u64;
+
+                    loop {
+                        if stop_on_fatal.load(Ordering::Relaxed) {
+                            break;
+                        }
+                        if let Some(d) = duration {
+                            if start.elapsed() >= d {
+                                break;
+                            }
+                        }
+                        if let Some(target) = target_request_count {
+                            if total_requests.load(Ordering::Relaxed) as u64 >= target {
+                                break;
+                            }
+                        }
+
+                        bucket.acquire();
+
+                        let mut request = ProductionOptimizedRequest::default();
+                        request.request_id = next_id;
+                        request.priority = (next_id % 4) as u32;
+                        next_id += worker_count as u64;
+
+                        let op_start = Instant::now();
+                        match queue.enqueue(request) {
+                            Ok(_) => {
+                                counter.increment();
+                                if let Some(_) = queue.dequeue() {
+                                    // Processing would happen here
+                                }
+                                consecutive_enqueue_failures.store(0, Ordering::Relaxed);
+                            }
+                            Err(_) => {
+                                let failures = consecutive_enqueue_failures.fetch_add(1, Ordering::Relaxed) + 1;
+                                if failures as u64 >= fatal_threshold {
+                                    eprintln!("   💀 FATAL: {} consecutive enqueue failures - stopping all workers", failures);
+                                    stop_on_fatal.store(true, Ordering::Relaxed);
+                                }
+                            }
+                        }
+                        if let Ok(mut hist) = histogram.lock() {
+                            hist.record(op_start.elapsed().as_nanos() as f64);
+                        }
+                        total_requests.fetch_add(1, Ordering::Relaxed);
+                    }
+                })
+            })
+            .collect();
+
+        // Poll on the calling thread and emit a snapshot every
+        // `snapshot_interval`, so operators watching a deploy see
+        // latency/throughput evolve rather than waiting for a single
+        // end-of-run summary.
+        let mut snapshots = Vec::new();
+        loop {
+            thread::sleep(config.snapshot_interval.min(Duration::from_millis(100)));
+
+            let elapsed = start.elapsed();
+            let workers_done = workers.iter().all(|w| w.is_finished());
+            let should_snapshot = snapshots.is_empty()
+                || elapsed - snapshots.last().map(|s: &ContinuousValidationSnapshot| s.elapsed).unwrap_or(Duration::ZERO)
+                    >= config.snapshot_interval;
+
+            if should_snapshot || workers_done {
+                let total = total_requests.load(Ordering::Relaxed) as u64;
+                let (avg_latency_ns, p99_latency_ns) = histogram
+                    .lock()
+                    .map(|hist| (hist.mean_ns(), hist.percentile(0.99)))
+                    .unwrap_or((0.0, 0.0));
+                let throughput = total as f64 / elapsed.as_secs_f64().max(0.001);
+
+                let snapshot = ContinuousValidationSnapshot {
+                    elapsed,
+                    total_requests: total,
+                    throughput,
+                    avg_latency_ns,
+                    p99_latency_ns,
+                };
+                println!(
+                    "   📈 t={:?} requests={} throughput={:.0}/s avg={:.0}ns p99={:.0}ns",
+                    snapshot.elapsed, snapshot.total_requests, snapshot.throughput, snapshot.avg_latency_ns, snapshot.p99_latency_ns
+                );
+                snapshots.push(snapshot);
+            }
+
+            if workers_done {
+                break;
+            }
+        }
+
+        for worker in workers {
+            let _ = worker.join();
+        }
+
+        let total_requests = total_requests.load(Ordering::Relaxed) as u64;
+        let stopped_on_fatal = stop_on_fatal.load(Ordering::Relaxed);
+        let elapsed = start.elapsed();
+
+        println!("   🎯 Continuous validation complete: {} requests in {:?} (stopped_on_fatal={})", total_requests, elapsed, stopped_on_fatal);
+
+        ContinuousValidationResults {
+            snapshots,
+            total_requests,
+            stopped_on_fatal,
+            elapsed,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -237,10 +795,250 @@ pub struct SafetyValidationResults {
 pub struct ComprehensiveValidationResults {
     pub turbo_results: TurboResults,
     pub safety_results: SafetyValidationResults,
+    pub hardware_profile: HardwareProfile,
+    pub hardware_ok: bool,
     pub overall_score: f64,
     pub timestamp: SystemTime,
 }
 
+// PRODUCTION: Git-tagged, baseline-comparable metrics reports.
+//
+// A deployment proving it hasn't regressed needs more than a text log of
+// averages: it needs the exact build that produced a number, and something
+// to compare that number against. This turns each validation run into a
+// machine-readable report tagged with git provenance, and can gate CI on a
+// regression against a committed baseline.
+pub mod metrics_report {
+    use super::*;
+
+    /// One named benchmark's summary statistics, in nanoseconds.
+    #[derive(Debug, Clone)]
+    pub struct NamedResult {
+        pub name: String,
+        pub mean: f64,
+        pub std_dev: f64,
+        pub min: f64,
+        pub max: f64,
+    }
+
+    impl NamedResult {
+        fn to_json(&self) -> String {
+            format!(
+                "{{\"name\":\"{}\",\"mean\":{:.2},\"std_dev\":{:.2},\"min\":{:.2},\"max\":{:.2}}}",
+                self.name, self.mean, self.std_dev, self.min, self.max
+            )
+        }
+
+        fn from_json(obj: &str) -> Option<Self> {
+            Some(Self {
+                name: json_field_str(obj, "name")?,
+                mean: json_field_f64(obj, "mean")?,
+                std_dev: json_field_f64(obj, "std_dev")?,
+                min: json_field_f64(obj, "min")?,
+                max: json_field_f64(obj, "max")?,
+            })
+        }
+    }
+
+    /// A benchmark report for one run, tagged with the build that produced
+    /// it so a regression can be traced back to the commit that caused it.
+    #[derive(Debug, Clone)]
+    pub struct MetricsReport {
+        pub git_human_readable: String,
+        pub git_revision: String,
+        pub git_commit_date: String,
+        pub date: String,
+        pub results: Vec<NamedResult>,
+    }
+
+    impl MetricsReport {
+        pub fn capture(results: Vec<NamedResult>) -> Self {
+            Self {
+                git_human_readable: git_command_output(&["describe", "--dirty", "--always"]),
+                git_revision: git_command_output(&["rev-parse", "HEAD"]),
+                git_commit_date: git_command_output(&["log", "-1", "--format=%cI"]),
+                date: report_date_string(),
+                results,
+            }
+        }
+
+        pub fn from_turbo_results(results: &TurboResults) -> Self {
+            Self::capture(vec![NamedResult {
+                name: "turbo_validation".to_string(),
+                mean: results.avg_latency_ns,
+                std_dev: results.std_dev_latency_ns,
+                min: results.min_latency_ns,
+                max: results.max_latency_ns,
+            }])
+        }
+
+        pub fn to_json(&self) -> String {
+            let results_json: Vec<String> = self.results.iter().map(NamedResult::to_json).collect();
+            format!(
+                "{{\"git_human_readable\":\"{}\",\"git_revision\":\"{}\",\"git_commit_date\":\"{}\",\"date\":\"{}\",\"results\":[{}]}}",
+                self.git_human_readable, self.git_revision, self.git_commit_date, self.date, results_json.join(",")
+            )
+        }
+
+        pub fn write_to_file(&self, path: &str) -> std::io::Result<()> {
+            fs::write(path, self.to_json())
+        }
+
+        /// Parses back a report written by `to_json`. Deliberately minimal -
+        /// this only needs to round-trip this module's own output, not parse
+        /// arbitrary JSON, so it leans on field scraping rather than a real
+        /// parser.
+        pub fn from_json(text: &str) -> Option<Self> {
+            let results = json_array_field(text, "results")?
+                .iter()
+                .filter_map(|obj| NamedResult::from_json(obj))
+                .collect();
+            Some(Self {
+                git_human_readable: json_field_str(text, "git_human_readable")?,
+                git_revision: json_field_str(text, "git_revision")?,
+                git_commit_date: json_field_str(text, "git_commit_date")?,
+                date: json_field_str(text, "date")?,
+                results,
+            })
+        }
+
+        pub fn load(path: &str) -> Option<Self> {
+            fs::read_to_string(path).ok().and_then(|text| Self::from_json(&text))
+        }
+
+        fn find_result(&self, name: &str) -> Option<&NamedResult> {
+            self.results.iter().find(|r| r.name == name)
+        }
+
+        /// A regression is flagged when the new mean exceeds the baseline
+        /// mean by more than `std_dev_threshold` baseline standard
+        /// deviations, or by more than `pct_threshold` percent - whichever
+        /// fires first, since a near-zero baseline std_dev would otherwise
+        /// make the std_dev check trip on ordinary noise.
+        pub fn check_regression(&self, baseline: &Self, std_dev_threshold: f64, pct_threshold: f64) -> Vec<String> {
+            let mut regressions = Vec::new();
+            for current in &self.results {
+                if let Some(base) = baseline.find_result(&current.name) {
+                    let std_dev_limit = base.mean + std_dev_threshold * base.std_dev;
+                    let pct_limit = base.mean * (1.0 + pct_threshold / 100.0);
+                    if current.mean > std_dev_limit || current.mean > pct_limit {
+                        regressions.push(format!(
+                            "{}: mean {:.2}ns exceeds baseline {:.2}ns (+{}\u{3c3}={:.2}ns, +{}%={:.2}ns)",
+                            current.name, current.mean, base.mean, std_dev_threshold, std_dev_limit, pct_threshold, pct_limit
+                        ));
+                    }
+                }
+            }
+            regressions
+        }
+    }
+
+    const DEFAULT_STD_DEV_THRESHOLD: f64 = 3.0;
+    const DEFAULT_PCT_THRESHOLD: f64 = 10.0;
+    const DEFAULT_BASELINE_PATH: &str = "baseline.json";
+
+    /// Shells out to `git` for build provenance. Falls back to `"unknown"`
+    /// rather than failing the run outside a git checkout (e.g. from an
+    /// extracted release tarball).
+    fn git_command_output(args: &[&str]) -> String {
+        std::process::Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .and_then(|output| String::from_utf8(output.stdout).ok())
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .unwrap_or_else(|| "unknown".to_string())
+    }
+
+    /// No `chrono` dependency is available here, so the report date is just
+    /// the Unix timestamp of report generation - still enough to order runs.
+    fn report_date_string() -> String {
+        let secs = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or(Duration::from_secs(0)).as_secs();
+        secs.to_string()
+    }
+
+    fn json_field_str(text: &str, field: &str) -> Option<String> {
+        let needle = format!("\"{}\":\"", field);
+        let start = text.find(&needle)? + needle.len();
+        let end = text[start..].find('"')? + start;
+        Some(text[start..end].to_string())
+    }
+
+    fn json_field_f64(text: &str, field: &str) -> Option<f64> {
+        let needle = format!("\"{}\":", field);
+        let start = text.find(&needle)? + needle.len();
+        let rest = &text[start..];
+        let end = rest.find(|c: char| c == ',' || c == '}').unwrap_or(rest.len());
+        rest[..end].trim().parse::<f64>().ok()
+    }
+
+    fn json_array_field(text: &str, field: &str) -> Option<Vec<String>> {
+        let needle = format!("\"{}\":[", field);
+        let start = text.find(&needle)? + needle.len();
+        let end = text[start..].find(']')? + start;
+        let inner = &text[start..end];
+        let mut objects = Vec::new();
+        let mut depth = 0;
+        let mut obj_start = 0;
+        for (i, ch) in inner.char_indices() {
+            match ch {
+                '{' => {
+                    if depth == 0 {
+                        obj_start = i;
+                    }
+                    depth += 1;
+                }
+                '}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        objects.push(inner[obj_start..=i].to_string());
+                    }
+                }
+                _ => {}
+            }
+        }
+        Some(objects)
+    }
+
+    /// Runs turbo validation, compares it against the stored baseline (if
+    /// any), and returns the process exit code CI should use to gate a
+    /// deploy. When `update_baseline` is set, the current run simply
+    /// becomes the new baseline instead of being checked against it.
+    pub fn run_baseline_gated_validation(update_baseline: bool) -> i32 {
+        let results = super::turbo_validator::run_turbo_validation();
+        let report = MetricsReport::from_turbo_results(&results);
+
+        if update_baseline {
+            match report.write_to_file(DEFAULT_BASELINE_PATH) {
+                Ok(()) => println!("📌 Baseline updated at {}", DEFAULT_BASELINE_PATH),
+                Err(e) => eprintln!("⚠️  Failed to write baseline: {}", e),
+            }
+            return 0;
+        }
+
+        match MetricsReport::load(DEFAULT_BASELINE_PATH) {
+            Some(baseline) => {
+                let regressions = report.check_regression(&baseline, DEFAULT_STD_DEV_THRESHOLD, DEFAULT_PCT_THRESHOLD);
+                if regressions.is_empty() {
+                    println!("✅ No regression vs baseline {}", baseline.git_human_readable);
+                    0
+                } else {
+                    for r in &regressions {
+                        eprintln!("❌ REGRESSION: {}", r);
+                    }
+                    1
+                }
+            }
+            None => {
+                println!("ℹ️  No baseline found at {} - run with --update-baseline to create one", DEFAULT_BASELINE_PATH);
+                0
+            }
+        }
+    }
+}
+
 // PRODUCTION: Prometheus metrics integration
 pub mod prometheus_metrics {
     use super::*;
@@ -248,29 +1046,71 @@ pub mod prometheus_metrics {
     // Simple in-memory metrics storage (in production, use actual Prometheus client)
     pub struct TurboMetrics {
         pub avg_latency_ns: f64,
+        pub min_latency_ns: f64,
+        pub max_latency_ns: f64,
+        pub std_dev_latency_ns: f64,
+        pub p50_latency_ns: f64,
+        pub p90_latency_ns: f64,
+        pub p99_latency_ns: f64,
+        pub p999_latency_ns: f64,
         pub throughput_ops: f64,
         pub safety_factor: f64,
         pub validation_passed: bool,
         pub last_updated: SystemTime,
+        pub hardware_cpu_score: f64,
+        pub hardware_memory_bandwidth_gib_per_sec: f64,
+        pub hardware_disk_throughput_mib_per_sec: f64,
+        pub hardware_cache_line_size: usize,
+        pub hardware_ok: bool,
+        pub latency_bucket_counts: Vec<u64>,
     }
 
     impl TurboMetrics {
         pub fn new() -> Self {
             Self {
                 avg_latency_ns: 0.0,
+                min_latency_ns: 0.0,
+                max_latency_ns: 0.0,
+                std_dev_latency_ns: 0.0,
+                p50_latency_ns: 0.0,
+                p90_latency_ns: 0.0,
+                p99_latency_ns: 0.0,
+                p999_latency_ns: 0.0,
                 throughput_ops: 0.0,
                 safety_factor: 0.0,
                 validation_passed: false,
                 last_updated: SystemTime::now(),
+                hardware_cpu_score: 0.0,
+                hardware_memory_bandwidth_gib_per_sec: 0.0,
+                hardware_disk_throughput_mib_per_sec: 0.0,
+                hardware_cache_line_size: 0,
+                hardware_ok: true,
+                latency_bucket_counts: Vec::new(),
             }
         }
 
         pub fn update(&mut self, results: &TurboResults) {
             self.avg_latency_ns = results.avg_latency_ns;
+            self.min_latency_ns = results.min_latency_ns;
+            self.max_latency_ns = results.max_latency_ns;
+            self.std_dev_latency_ns = results.std_dev_latency_ns;
+            self.p50_latency_ns = results.p50_latency_ns;
+            self.p90_latency_ns = results.p90_latency_ns;
+            self.p99_latency_ns = results.p99_latency_ns;
+            self.p999_latency_ns = results.p999_latency_ns;
             self.throughput_ops = results.throughput;
             self.safety_factor = results.safety_factor;
             self.validation_passed = results.passed;
             self.last_updated = results.timestamp;
+            self.latency_bucket_counts = results.latency_bucket_counts.clone();
+        }
+
+        pub fn update_hardware(&mut self, profile: &HardwareProfile, hardware_ok: bool) {
+            self.hardware_cpu_score = profile.cpu_score;
+            self.hardware_memory_bandwidth_gib_per_sec = profile.memory_bandwidth_gib_per_sec;
+            self.hardware_disk_throughput_mib_per_sec = profile.disk_throughput_mib_per_sec;
+            self.hardware_cache_line_size = profile.cache_line_size;
+            self.hardware_ok = hardware_ok;
         }
 
         pub fn to_prometheus_format(&self) -> String {
@@ -279,23 +1119,116 @@ pub mod prometheus_metrics {
                 # TYPE sprint_turbo_avg_latency_ns gauge\n\
                 sprint_turbo_avg_latency_ns {}\n\
                 \n\
+                # HELP sprint_turbo_min_latency_ns Minimum turbo latency in nanoseconds\n\
+                # TYPE sprint_turbo_min_latency_ns gauge\n\
+                sprint_turbo_min_latency_ns {}\n\
+                \n\
+                # HELP sprint_turbo_max_latency_ns Maximum turbo latency in nanoseconds\n\
+                # TYPE sprint_turbo_max_latency_ns gauge\n\
+                sprint_turbo_max_latency_ns {}\n\
+                \n\
+                # HELP sprint_turbo_std_dev_latency_ns Standard deviation of turbo latency in nanoseconds\n\
+                # TYPE sprint_turbo_std_dev_latency_ns gauge\n\
+                sprint_turbo_std_dev_latency_ns {}\n\
+                \n\
+                # HELP sprint_turbo_p50_latency_ns p50 turbo latency in nanoseconds\n\
+                # TYPE sprint_turbo_p50_latency_ns gauge\n\
+                sprint_turbo_p50_latency_ns {}\n\
+                \n\
+                # HELP sprint_turbo_p90_latency_ns p90 turbo latency in nanoseconds\n\
+                # TYPE sprint_turbo_p90_latency_ns gauge\n\
+                sprint_turbo_p90_latency_ns {}\n\
+                \n\
+                # HELP sprint_turbo_p99_latency_ns p99 turbo latency in nanoseconds\n\
+                # TYPE sprint_turbo_p99_latency_ns gauge\n\
+                sprint_turbo_p99_latency_ns {}\n\
+                \n\
+                # HELP sprint_turbo_p999_latency_ns p999 turbo latency in nanoseconds\n\
+                # TYPE sprint_turbo_p999_latency_ns gauge\n\
+                sprint_turbo_p999_latency_ns {}\n\
+                \n\
                 # HELP sprint_turbo_throughput_ops Throughput operations per second\n\
                 # TYPE sprint_turbo_throughput_ops gauge\n\
                 sprint_turbo_throughput_ops {}\n\
                 \n\
-                # HELP sprint_turbo_safety_factor Safety factor vs 20ms SLA\n\
+                # HELP sprint_turbo_safety_factor Safety factor (p99) vs 20ms SLA\n\
                 # TYPE sprint_turbo_safety_factor gauge\n\
                 sprint_turbo_safety_factor {}\n\
                 \n\
                 # HELP sprint_turbo_validation_passed Turbo validation status (1=pass, 0=fail)\n\
                 # TYPE sprint_turbo_validation_passed gauge\n\
-                sprint_turbo_validation_passed {}\n",
+                sprint_turbo_validation_passed {}\n\
+                \n\
+                # HELP sprint_turbo_hardware_cpu_score Host CPU score, normalized to the reference machine\n\
+                # TYPE sprint_turbo_hardware_cpu_score gauge\n\
+                sprint_turbo_hardware_cpu_score {}\n\
+                \n\
+                # HELP sprint_turbo_hardware_memory_bandwidth_gib_per_sec Host sequential memory bandwidth in GiB/s\n\
+                # TYPE sprint_turbo_hardware_memory_bandwidth_gib_per_sec gauge\n\
+                sprint_turbo_hardware_memory_bandwidth_gib_per_sec {}\n\
+                \n\
+                # HELP sprint_turbo_hardware_disk_throughput_mib_per_sec Host scratch-file read/write throughput in MiB/s\n\
+                # TYPE sprint_turbo_hardware_disk_throughput_mib_per_sec gauge\n\
+                sprint_turbo_hardware_disk_throughput_mib_per_sec {}\n\
+                \n\
+                # HELP sprint_turbo_hardware_cache_line_size Detected CPU cache line size in bytes\n\
+                # TYPE sprint_turbo_hardware_cache_line_size gauge\n\
+                sprint_turbo_hardware_cache_line_size {}\n\
+                \n\
+                # HELP sprint_turbo_hardware_ok Host cleared configured minimum hardware scores (1=ok, 0=below minimum)\n\
+                # TYPE sprint_turbo_hardware_ok gauge\n\
+                sprint_turbo_hardware_ok {}\n",
                 self.avg_latency_ns,
+                self.min_latency_ns,
+                self.max_latency_ns,
+                self.std_dev_latency_ns,
+                self.p50_latency_ns,
+                self.p90_latency_ns,
+                self.p99_latency_ns,
+                self.p999_latency_ns,
                 self.throughput_ops,
                 self.safety_factor,
-                if self.validation_passed { 1.0 } else { 0.0 }
-            )
+                if self.validation_passed { 1.0 } else { 0.0 },
+                self.hardware_cpu_score,
+                self.hardware_memory_bandwidth_gib_per_sec,
+                self.hardware_disk_throughput_mib_per_sec,
+                self.hardware_cache_line_size,
+                if self.hardware_ok { 1.0 } else { 0.0 }
+            ) + &latency_histogram_prometheus(&self.latency_bucket_counts)
+        }
+    }
+
+    // Emits a real `histogram` type - cumulative `_bucket{le="..."}` lines
+    // plus `_sum`/`_count` - from the raw per-bucket counts, so Grafana can
+    // compute quantiles server-side instead of relying only on the
+    // pre-computed p50/p90/p99/p999 gauges above.
+    fn latency_histogram_prometheus(bucket_counts: &[u64]) -> String {
+        let mut out = String::new();
+        out.push_str("\n# HELP sprint_turbo_latency_seconds Turbo validation per-iteration latency distribution\n");
+        out.push_str("# TYPE sprint_turbo_latency_seconds histogram\n");
+
+        let mut cumulative = 0u64;
+        let mut approx_sum_seconds = 0.0f64;
+        for (bucket, &count) in bucket_counts.iter().enumerate() {
+            cumulative += count;
+            let upper_ns = LatencyHistogram::bucket_upper_bound_ns(bucket);
+            if count > 0 {
+                // Geometric midpoint of [2^bucket, 2^(bucket+1)) ns as an
+                // approximate per-sample value, same approach used for the
+                // SecureChannelPool latency histogram's Prometheus export.
+                let lower_ns = if bucket == 0 { 1.0 } else { (1u64 << bucket) as f64 };
+                approx_sum_seconds += ((lower_ns * upper_ns).sqrt() / 1_000_000_000.0) * count as f64;
+            }
+            out.push_str(&format!(
+                "sprint_turbo_latency_seconds_bucket{{le=\"{:.9}\"}} {}\n",
+                upper_ns / 1_000_000_000.0,
+                cumulative
+            ));
         }
+        out.push_str(&format!("sprint_turbo_latency_seconds_bucket{{le=\"+Inf\"}} {}\n", cumulative));
+        out.push_str(&format!("sprint_turbo_latency_seconds_sum {:.9}\n", approx_sum_seconds));
+        out.push_str(&format!("sprint_turbo_latency_seconds_count {}\n", cumulative));
+        out
     }
 
     // Global metrics instance
@@ -310,6 +1243,13 @@ pub mod prometheus_metrics {
         }
     }
 
+    pub fn update_global_comprehensive_metrics(results: &ComprehensiveValidationResults) {
+        if let Ok(mut metrics) = GLOBAL_TURBO_METRICS.lock() {
+            metrics.update(&results.turbo_results);
+            metrics.update_hardware(&results.hardware_profile, results.hardware_ok);
+        }
+    }
+
     pub fn get_global_metrics_prometheus() -> String {
         if let Ok(metrics) = GLOBAL_TURBO_METRICS.lock() {
             metrics.to_prometheus_format()
@@ -317,6 +1257,97 @@ pub mod prometheus_metrics {
             "# Error: Could not access metrics\n".to_string()
         }
     }
+
+    // PRODUCTION: Prometheus Pushgateway support.
+    //
+    // Short-lived validation runs during a deploy finish and exit long
+    // before anything would scrape them, so nothing ever sees their
+    // numbers. Pushing the same exposition text to a Pushgateway over HTTP
+    // gets it into monitoring anyway.
+
+    /// Inserts `job`/`instance` labels into every metric sample line of a
+    /// Prometheus exposition text, merging into any labels already present
+    /// (e.g. the `{le="..."}` on histogram bucket lines) rather than adding
+    /// a second brace pair. `#` comment lines (`HELP`/`TYPE`) are left
+    /// untouched.
+    fn apply_job_instance_labels(exposition: &str, job: &str, instance: &str) -> String {
+        let mut out = String::with_capacity(exposition.len() + 64);
+        for line in exposition.lines() {
+            if line.is_empty() || line.starts_with('#') {
+                out.push_str(line);
+                out.push('\n');
+                continue;
+            }
+            match line.find('{') {
+                Some(brace_pos) => {
+                    let name = &line[..brace_pos];
+                    let rest = &line[brace_pos + 1..];
+                    let close = rest.find('}').unwrap_or(rest.len());
+                    let existing_labels = &rest[..close];
+                    let value_part = &rest[close..]; // "} <value>"
+                    out.push_str(name);
+                    out.push('{');
+                    out.push_str(existing_labels);
+                    out.push_str(&format!(",job=\"{}\",instance=\"{}\"", job, instance));
+                    out.push_str(value_part);
+                }
+                None => match line.find(' ') {
+                    Some(space_pos) => {
+                        let (name, value) = line.split_at(space_pos);
+                        out.push_str(name);
+                        out.push_str(&format!("{{job=\"{}\",instance=\"{}\"}}", job, instance));
+                        out.push_str(value);
+                    }
+                    None => out.push_str(line),
+                },
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Sends a raw HTTP POST over a plain `TcpStream` - no `reqwest` runtime
+    /// is set up in this synchronous module, so this hand-rolls just enough
+    /// of the protocol to deliver one request body and discard the
+    /// response.
+    fn post_to_http(addr: &str, path: &str, body: &str) -> std::io::Result<()> {
+        let mut stream = TcpStream::connect(addr)?;
+        let request = format!(
+            "POST {path} HTTP/1.1\r\nHost: {addr}\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {len}\r\nConnection: close\r\n\r\n{body}",
+            path = path,
+            addr = addr,
+            len = body.len(),
+            body = body,
+        );
+        stream.write_all(request.as_bytes())?;
+        // Fire-and-forget: drain the response so the gateway isn't left
+        // with a half-read connection, but there's nothing in it this
+        // module needs.
+        let mut response = Vec::new();
+        let _ = stream.read_to_end(&mut response);
+        Ok(())
+    }
+
+    /// POSTs the current global metrics, with `job`/`instance` labels
+    /// applied, to a Prometheus Pushgateway at `addr` (`host:port`).
+    pub fn push_to_gateway(addr: &str, job: &str, instance: &str) -> std::io::Result<()> {
+        let exposition = get_global_metrics_prometheus();
+        let labeled = apply_job_instance_labels(&exposition, job, instance);
+        let path = format!("/metrics/job/{}/instance/{}", job, instance);
+        post_to_http(addr, &path, &labeled)
+    }
+
+    /// Spawns a background thread that pushes the current global metrics to
+    /// `addr` every `interval`, so a validation run's numbers keep landing
+    /// in monitoring even after the run itself has finished.
+    pub fn spawn_periodic_pusher(addr: String, job: String, instance: String, interval: Duration) -> thread::JoinHandle<()> {
+        thread::spawn(move || loop {
+            if let Err(e) = push_to_gateway(&addr, &job, &instance) {
+                eprintln!("⚠️  Failed to push metrics to pushgateway at {}: {}", addr, e);
+            }
+            thread::sleep(interval);
+        })
+    }
 }
 
 // PRODUCTION: API endpoint integration
@@ -329,6 +1360,13 @@ pub mod api_endpoints {
                 r#"{{
     "turbo_validation": {{
         "avg_latency_ns": {:.2},
+        "min_latency_ns": {:.2},
+        "max_latency_ns": {:.2},
+        "std_dev_latency_ns": {:.2},
+        "p50_latency_ns": {:.2},
+        "p90_latency_ns": {:.2},
+        "p99_latency_ns": {:.2},
+        "p999_latency_ns": {:.2},
         "throughput_ops": {:.0},
         "safety_factor": {:.0},
         "validation_passed": {},
@@ -338,6 +1376,13 @@ pub mod api_endpoints {
     "validation_score": "99.9/100"
 }}"#,
                 metrics.avg_latency_ns,
+                metrics.min_latency_ns,
+                metrics.max_latency_ns,
+                metrics.std_dev_latency_ns,
+                metrics.p50_latency_ns,
+                metrics.p90_latency_ns,
+                metrics.p99_latency_ns,
+                metrics.p999_latency_ns,
                 metrics.throughput_ops,
                 metrics.safety_factor,
                 metrics.validation_passed,
@@ -355,12 +1400,20 @@ pub mod api_endpoints {
         if let Ok(metrics) = prometheus_metrics::GLOBAL_TURBO_METRICS.lock() {
             format!(
                 "✅ Turbo Validation Status\n\
-                📊 Avg Latency: {:.2}ns\n\
+                📊 Avg Latency: {:.2}ns (min {:.2}ns / max {:.2}ns / std dev {:.2}ns)\n\
+                📈 Percentiles: p50 {:.0}ns / p90 {:.0}ns / p99 {:.0}ns / p999 {:.0}ns\n\
                 ⚡ Throughput: {:.0} ops/sec\n\
-                🛡️ Safety Factor: {:.0}x\n\
+                🛡️ Safety Factor (p99): {:.0}x\n\
                 🎯 Status: {}\n\
                 🏆 Validation Score: 99.9/100\n",
                 metrics.avg_latency_ns,
+                metrics.min_latency_ns,
+                metrics.max_latency_ns,
+                metrics.std_dev_latency_ns,
+                metrics.p50_latency_ns,
+                metrics.p90_latency_ns,
+                metrics.p99_latency_ns,
+                metrics.p999_latency_ns,
                 metrics.throughput_ops,
                 metrics.safety_factor,
                 if metrics.validation_passed { "PASSED ✅" } else { "FAILED ❌" }
@@ -1,5 +1,6 @@
 use axum::{
-    extract::{Path, Query},
+    extract::ws::{Message, WebSocket, WebSocketUpgrade},
+    extract::{ConnectInfo, Path, Query},
     http::StatusCode,
     response::{IntoResponse, Json},
     routing::{get, post},
@@ -10,13 +11,15 @@ use dotenvy::dotenv;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use sha2::{Digest, Sha256};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::env;
-use std::net::SocketAddr;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::net::TcpStream;
-use tokio::sync::{mpsc, Mutex};
+use tokio::sync::{broadcast, mpsc, Mutex};
 use tokio::task;
 use tracing::{error, info, warn};
 use uuid::Uuid;
@@ -25,6 +28,14 @@ use uuid::Uuid;
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 const COMMIT: &str = "unknown";
 
+// EIP-1559 constants used to validate upstream fee_history responses.
+const BASE_FEE_ELASTICITY_MULTIPLIER: u64 = 2;
+const BASE_FEE_MAX_CHANGE_DENOMINATOR: f64 = 8.0;
+// Upstream base fees are integers, so the recomputed next-base-fee can be
+// off by a little from rounding; anything past this fraction of the base
+// fee itself is treated as a genuine mismatch rather than rounding noise.
+const BASE_FEE_DEVIATION_TOLERANCE: f64 = 0.0005;
+
 // Protocol types
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 enum ProtocolType {
@@ -43,6 +54,17 @@ impl std::fmt::Display for ProtocolType {
     }
 }
 
+impl ProtocolType {
+    fn parse(chain: &str) -> Option<Self> {
+        match chain {
+            "bitcoin" => Some(ProtocolType::Bitcoin),
+            "ethereum" => Some(ProtocolType::Ethereum),
+            "solana" => Some(ProtocolType::Solana),
+            _ => None,
+        }
+    }
+}
+
 // Config struct (expanded to match Go more closely)
 #[derive(Debug, Serialize, Deserialize, Clone)]
 struct Config {
@@ -77,6 +99,8 @@ struct Config {
     audit_log_path: String,
     max_retries: u32,
     retry_backoff: Duration,
+    quorum_min: f64,
+    quorum_weight_strategy: String,
     cache_size: u32,
     cache_ttl: Duration,
     websocket_max_connections: u32,
@@ -145,6 +169,8 @@ impl Config {
             audit_log_path: env::var("AUDIT_LOG_PATH").unwrap_or("/var/log/sprint/audit.log".to_string()),
             max_retries: env::var("MAX_RETRIES").ok().and_then(|s| s.parse().ok()).unwrap_or(3),
             retry_backoff: parse_duration_ms("RETRY_BACKOFF", 100),
+            quorum_min: env::var("QUORUM_MIN").ok().and_then(|s| s.parse().ok()).unwrap_or(0.51),
+            quorum_weight_strategy: env::var("QUORUM_WEIGHT_STRATEGY").unwrap_or("equal".to_string()),
             cache_size: env::var("CACHE_SIZE").ok().and_then(|s| s.parse().ok()).unwrap_or(10000),
             cache_ttl: parse_duration_secs("CACHE_TTL", 5 * 60),
             websocket_max_connections: env::var("WEBSOCKET_MAX_CONNECTIONS").ok().and_then(|s| s.parse().ok()).unwrap_or(1000),
@@ -166,12 +192,14 @@ impl Config {
     }
 }
 
-// Simplified Cache (matching Go's Cache)
-#[derive(Clone)]
-struct Cache {
-    items: Arc<Mutex<HashMap<String, CacheItem>>>,
-    max_size: usize,
-}
+// Cache with true LRU eviction and background TTL sweeping.
+//
+// Recency is tracked via a monotonic tick per key (`tick_by_key`) mirrored
+// into a `BTreeMap<tick, key>` (`recency`), so the least-recently-used key
+// is always whichever key sits at `recency`'s first entry - an ordered
+// index alongside the `HashMap`, playing the same role a `LinkedHashMap`
+// would, without adding an external dependency.
+const CACHE_SWEEP_INTERVAL: Duration = Duration::from_secs(30);
 
 #[derive(Clone)]
 struct CacheItem {
@@ -179,22 +207,109 @@ struct CacheItem {
     expires_at: DateTime<Utc>,
 }
 
+#[derive(Default)]
+struct CacheCounters {
+    hits: AtomicU64,
+    misses: AtomicU64,
+    evictions: AtomicU64,
+    expirations: AtomicU64,
+}
+
+struct CacheInner {
+    items: HashMap<String, CacheItem>,
+    recency: BTreeMap<u64, String>,
+    tick_by_key: HashMap<String, u64>,
+    next_tick: u64,
+}
+
+impl CacheInner {
+    fn new() -> Self {
+        CacheInner {
+            items: HashMap::new(),
+            recency: BTreeMap::new(),
+            tick_by_key: HashMap::new(),
+            next_tick: 0,
+        }
+    }
+
+    /// Marks `key` as the most-recently-used entry.
+    fn touch(&mut self, key: &str) {
+        if let Some(old_tick) = self.tick_by_key.remove(key) {
+            self.recency.remove(&old_tick);
+        }
+        let tick = self.next_tick;
+        self.next_tick += 1;
+        self.recency.insert(tick, key.to_string());
+        self.tick_by_key.insert(key.to_string(), tick);
+    }
+
+    fn remove_key(&mut self, key: &str) {
+        self.items.remove(key);
+        if let Some(tick) = self.tick_by_key.remove(key) {
+            self.recency.remove(&tick);
+        }
+    }
+
+    /// The key at the front of the recency ordering, i.e. the genuinely
+    /// least-recently-used entry.
+    fn least_recently_used_key(&self) -> Option<String> {
+        self.recency.values().next().cloned()
+    }
+}
+
+#[derive(Clone)]
+struct Cache {
+    inner: Arc<Mutex<CacheInner>>,
+    max_size: usize,
+    counters: Arc<CacheCounters>,
+}
+
 impl Cache {
     fn new(max_size: usize) -> Self {
-        Cache {
-            items: Arc::new(Mutex::new(HashMap::new())),
+        let cache = Cache {
+            inner: Arc::new(Mutex::new(CacheInner::new())),
             max_size,
-        }
+            counters: Arc::new(CacheCounters::default()),
+        };
+        cache.spawn_expiry_sweeper();
+        cache
+    }
+
+    /// Periodically removes already-expired entries so they disappear on
+    /// their own schedule rather than only when something happens to touch
+    /// them again.
+    fn spawn_expiry_sweeper(&self) {
+        let inner = self.inner.clone();
+        let counters = self.counters.clone();
+        task::spawn(async move {
+            loop {
+                tokio::time::sleep(CACHE_SWEEP_INTERVAL).await;
+                let now = Utc::now();
+                let mut guard = inner.lock().await;
+                let expired: Vec<String> = guard
+                    .items
+                    .iter()
+                    .filter(|(_, item)| item.expires_at <= now)
+                    .map(|(key, _)| key.clone())
+                    .collect();
+                for key in expired {
+                    guard.remove_key(&key);
+                    counters.expirations.fetch_add(1, Ordering::Relaxed);
+                }
+            }
+        });
     }
 
     async fn set(&self, key: String, value: Value, ttl: Duration) {
-        let mut items = self.items.lock().await;
-        if items.len() >= self.max_size {
-            // Simple eviction: remove oldest (not LRU, but approx)
-            let oldest_key = items.keys().next().cloned().unwrap_or_default();
-            items.remove(&oldest_key);
+        let mut guard = self.inner.lock().await;
+        if !guard.items.contains_key(&key) && guard.items.len() >= self.max_size {
+            if let Some(lru_key) = guard.least_recently_used_key() {
+                guard.remove_key(&lru_key);
+                self.counters.evictions.fetch_add(1, Ordering::Relaxed);
+            }
         }
-        items.insert(
+        guard.touch(&key);
+        guard.items.insert(
             key,
             CacheItem {
                 value,
@@ -204,17 +319,40 @@ impl Cache {
     }
 
     async fn get(&self, key: &str) -> Option<Value> {
-        let mut items = self.items.lock().await;
-        if let Some(item) = items.get(key) {
-            if Utc::now() > item.expires_at {
-                items.remove(key);
-                return None;
+        let mut guard = self.inner.lock().await;
+        match guard.items.get(key).cloned() {
+            Some(item) if Utc::now() > item.expires_at => {
+                guard.remove_key(key);
+                self.counters.expirations.fetch_add(1, Ordering::Relaxed);
+                self.counters.misses.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+            Some(item) => {
+                guard.touch(key);
+                self.counters.hits.fetch_add(1, Ordering::Relaxed);
+                Some(item.value)
+            }
+            None => {
+                self.counters.misses.fetch_add(1, Ordering::Relaxed);
+                None
             }
-            Some(item.value.clone())
-        } else {
-            None
         }
     }
+
+    /// All non-expired values whose key starts with `prefix`, for callers
+    /// that need to enumerate a logical sub-namespace (e.g. one chain's
+    /// mempool entries) rather than look up a single key. Unlike `get`,
+    /// this does not count toward the hit/miss counters or recency.
+    async fn entries_with_prefix(&self, prefix: &str) -> Vec<Value> {
+        let guard = self.inner.lock().await;
+        let now = Utc::now();
+        guard
+            .items
+            .iter()
+            .filter(|(key, item)| key.starts_with(prefix) && item.expires_at > now)
+            .map(|(_, item)| item.value.clone())
+            .collect()
+    }
 }
 
 // Simplified LatencyOptimizer
@@ -222,6 +360,7 @@ impl Cache {
 struct LatencyOptimizer {
     target_p99: Duration,
     chain_latencies: Arc<Mutex<HashMap<String, Vec<Duration>>>>,
+    peer_latencies: Arc<Mutex<HashMap<String, Vec<Duration>>>>,
 }
 
 impl LatencyOptimizer {
@@ -229,6 +368,7 @@ impl LatencyOptimizer {
         LatencyOptimizer {
             target_p99,
             chain_latencies: Arc::new(Mutex::new(HashMap::new())),
+            peer_latencies: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
@@ -250,14 +390,479 @@ impl LatencyOptimizer {
             }
         }
     }
+
+    /// Per-peer counterpart of `track_request`, feeding `QuorumClient`'s
+    /// by-latency weighting.
+    async fn track_peer_request(&self, peer_id: &str, duration: Duration) {
+        let mut latencies = self.peer_latencies.lock().await;
+        let peer_vec = latencies.entry(peer_id.to_string()).or_insert(Vec::new());
+        peer_vec.push(duration);
+        if peer_vec.len() > 100 {
+            peer_vec.remove(0);
+        }
+    }
+
+    /// Weight in `(0.0, 1.0]`, inversely proportional to a peer's recent
+    /// average latency so a consistently slow peer counts for less of the
+    /// quorum than a fast one; peers with no history yet get full weight.
+    async fn peer_weight(&self, peer_id: &str) -> f64 {
+        let latencies = self.peer_latencies.lock().await;
+        match latencies.get(peer_id) {
+            Some(samples) if !samples.is_empty() => {
+                let avg_ms =
+                    samples.iter().map(|d| d.as_secs_f64() * 1000.0).sum::<f64>() / samples.len() as f64;
+                1.0 / (1.0 + avg_ms / 100.0)
+            }
+            _ => 1.0,
+        }
+    }
+}
+
+// Retry middleware for outbound peer/RPC calls: retries transient failures
+// with exponential backoff seeded from `Config::retry_backoff` (doubling
+// each attempt up to `Config::max_retries`), and specifically recognizes
+// rate-limit signals (HTTP 429, JSON-RPC -32005 / "rate limit" strings) so
+// those back off for the upstream's `Retry-After` hint when present instead
+// of retrying immediately.
+enum RetryableError {
+    /// Upstream is rate-limiting us; back off for `retry_after` if given,
+    /// otherwise fall back to the normal exponential backoff.
+    RateLimited { retry_after: Option<Duration> },
+    Transient(String),
+    /// Retrying would not help (bad request, auth failure, etc.).
+    Permanent(String),
+}
+
+impl RetryableError {
+    /// Classifies a raw upstream error message the same way a real HTTP/
+    /// JSON-RPC response would be inspected: HTTP 429 and JSON-RPC code
+    /// -32005 are both well-known rate-limit signals.
+    fn classify(message: &str) -> Self {
+        let lowercase = message.to_lowercase();
+        if lowercase.contains("429") || lowercase.contains("-32005") || lowercase.contains("rate limit") {
+            RetryableError::RateLimited { retry_after: None }
+        } else {
+            RetryableError::Transient(message.to_string())
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+struct RetryCounters {
+    total_retries: u64,
+    rate_limited_retries: u64,
+}
+
+struct RetryPolicy {
+    max_retries: u32,
+    base_backoff: Duration,
+    stats: Arc<Mutex<HashMap<String, RetryCounters>>>,
+}
+
+impl RetryPolicy {
+    fn new(cfg: &Config, stats: Arc<Mutex<HashMap<String, RetryCounters>>>) -> Self {
+        RetryPolicy {
+            max_retries: cfg.max_retries,
+            base_backoff: cfg.retry_backoff,
+            stats,
+        }
+    }
+
+    async fn record(&self, chain: &str, rate_limited: bool) {
+        let mut stats = self.stats.lock().await;
+        let counters = stats.entry(chain.to_string()).or_insert_with(RetryCounters::default);
+        counters.total_retries += 1;
+        if rate_limited {
+            counters.rate_limited_retries += 1;
+        }
+    }
+
+    /// Runs `attempt` up to `max_retries + 1` times, doubling `base_backoff`
+    /// after each transient/rate-limited failure, and recording every retry
+    /// under `chain` for `p2p_diag_handler` to surface.
+    async fn retry<F, Fut, T>(&self, chain: &str, mut attempt: F) -> Result<T, String>
+    where
+        F: FnMut() -> Fut,
+        Fut: std::future::Future<Output = Result<T, RetryableError>>,
+    {
+        let mut backoff = self.base_backoff;
+        let mut last_error = "no attempts made".to_string();
+
+        for attempt_number in 0..=self.max_retries {
+            match attempt().await {
+                Ok(value) => return Ok(value),
+                Err(RetryableError::Permanent(reason)) => return Err(reason),
+                Err(RetryableError::RateLimited { retry_after }) => {
+                    last_error = "rate limited by upstream".to_string();
+                    if attempt_number == self.max_retries {
+                        break;
+                    }
+                    self.record(chain, true).await;
+                    tokio::time::sleep(retry_after.unwrap_or(backoff)).await;
+                    backoff *= 2;
+                }
+                Err(RetryableError::Transient(reason)) => {
+                    last_error = reason;
+                    if attempt_number == self.max_retries {
+                        break;
+                    }
+                    self.record(chain, false).await;
+                    tokio::time::sleep(backoff).await;
+                    backoff *= 2;
+                }
+            }
+        }
+
+        Err(format!("exhausted {} retries: {}", self.max_retries, last_error))
+    }
+}
+
+// Peer handshakes: a bare `TcpStream::connect` succeeding only means the TCP
+// three-way handshake completed, not that the thing on the other end speaks
+// the protocol we think it does. Each protocol below performs its own real
+// application-layer handshake before the peer is counted as connected, and
+// the metadata it negotiates (protocol version, services, user-agent) is
+// kept alongside the socket for diagnostics.
+#[derive(Debug, Clone, Serialize)]
+struct PeerMetadata {
+    protocol_version: u32,
+    services: u64,
+    user_agent: String,
+    start_height: i64,
+    connected_at: DateTime<Utc>,
+}
+
+struct PeerConnection {
+    stream: TcpStream,
+    metadata: PeerMetadata,
+}
+
+// Bitcoin `version`/`verack` handshake (BIP-0031 / the original P2P
+// handshake). This is the real wire format - magic/command/length/checksum
+// header followed by a version payload - not a simulation, since it is pure
+// byte (de)serialization with no cryptography involved.
+const BITCOIN_MAGIC_MAINNET: [u8; 4] = [0xF9, 0xBE, 0xB4, 0xD9];
+const BITCOIN_PROTOCOL_VERSION: i32 = 70016;
+const BITCOIN_MIN_SUPPORTED_VERSION: i32 = 70001;
+// A handful of messages (e.g. `sendheaders`, `feefilter`) can legitimately
+// arrive interleaved with `version`/`verack`; give up after reading this many
+// messages in total rather than looping forever against a misbehaving peer.
+const BITCOIN_HANDSHAKE_MAX_MESSAGES: usize = 8;
+
+fn bitcoin_checksum(payload: &[u8]) -> [u8; 4] {
+    let first = Sha256::digest(payload);
+    let second = Sha256::digest(first);
+    [second[0], second[1], second[2], second[3]]
+}
+
+fn encode_bitcoin_message(command: &str, payload: &[u8]) -> Vec<u8> {
+    let mut command_bytes = [0u8; 12];
+    command_bytes[..command.len()].copy_from_slice(command.as_bytes());
+
+    let mut message = Vec::with_capacity(24 + payload.len());
+    message.extend_from_slice(&BITCOIN_MAGIC_MAINNET);
+    message.extend_from_slice(&command_bytes);
+    message.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+    message.extend_from_slice(&bitcoin_checksum(payload));
+    message.extend_from_slice(payload);
+    message
+}
+
+fn encode_bitcoin_varint(n: u64) -> Vec<u8> {
+    if n < 0xFD {
+        vec![n as u8]
+    } else if n <= 0xFFFF {
+        let mut out = vec![0xFD];
+        out.extend_from_slice(&(n as u16).to_le_bytes());
+        out
+    } else if n <= 0xFFFF_FFFF {
+        let mut out = vec![0xFE];
+        out.extend_from_slice(&(n as u32).to_le_bytes());
+        out
+    } else {
+        let mut out = vec![0xFF];
+        out.extend_from_slice(&n.to_le_bytes());
+        out
+    }
+}
+
+fn decode_bitcoin_varint(data: &[u8], offset: &mut usize) -> Option<u64> {
+    let prefix = *data.get(*offset)?;
+    *offset += 1;
+    match prefix {
+        0xFD => {
+            let v = u16::from_le_bytes(data.get(*offset..*offset + 2)?.try_into().ok()?);
+            *offset += 2;
+            Some(v as u64)
+        }
+        0xFE => {
+            let v = u32::from_le_bytes(data.get(*offset..*offset + 4)?.try_into().ok()?);
+            *offset += 4;
+            Some(v as u64)
+        }
+        0xFF => {
+            let v = u64::from_le_bytes(data.get(*offset..*offset + 8)?.try_into().ok()?);
+            *offset += 8;
+            Some(v)
+        }
+        n => Some(n as u64),
+    }
+}
+
+fn encode_bitcoin_var_str(s: &str) -> Vec<u8> {
+    let bytes = s.as_bytes();
+    let mut out = encode_bitcoin_varint(bytes.len() as u64);
+    out.extend_from_slice(bytes);
+    out
+}
+
+fn build_bitcoin_version_payload(nonce: u64, start_height: i32) -> Vec<u8> {
+    let mut payload = Vec::new();
+    payload.extend_from_slice(&BITCOIN_PROTOCOL_VERSION.to_le_bytes());
+    payload.extend_from_slice(&0u64.to_le_bytes()); // services: we advertise none, we're a light client
+    payload.extend_from_slice(&Utc::now().timestamp().to_le_bytes());
+    payload.extend_from_slice(&[0u8; 26]); // addr_recv: unused by modern peers, left zeroed
+    payload.extend_from_slice(&[0u8; 26]); // addr_from: unused by modern peers, left zeroed
+    payload.extend_from_slice(&nonce.to_le_bytes());
+    payload.extend_from_slice(&encode_bitcoin_var_str("/bitcoin-sprint:rust/"));
+    payload.extend_from_slice(&start_height.to_le_bytes());
+    payload.push(1); // relay
+    payload
+}
+
+fn parse_bitcoin_version_payload(payload: &[u8]) -> Option<PeerMetadata> {
+    let mut offset = 0;
+    let version = i32::from_le_bytes(payload.get(offset..offset + 4)?.try_into().ok()?);
+    offset += 4;
+    let services = u64::from_le_bytes(payload.get(offset..offset + 8)?.try_into().ok()?);
+    offset += 8;
+    offset += 8; // timestamp, not needed once the handshake has completed
+    offset += 26; // addr_recv
+    offset += 26; // addr_from
+    offset += 8; // nonce
+    let user_agent_len = decode_bitcoin_varint(payload, &mut offset)? as usize;
+    let user_agent = String::from_utf8(payload.get(offset..offset + user_agent_len)?.to_vec()).ok()?;
+    offset += user_agent_len;
+    let start_height = i32::from_le_bytes(payload.get(offset..offset + 4)?.try_into().ok()?);
+    Some(PeerMetadata {
+        protocol_version: version as u32,
+        services,
+        user_agent,
+        start_height: start_height as i64,
+        connected_at: Utc::now(),
+    })
+}
+
+async fn read_bitcoin_message(stream: &mut TcpStream) -> std::io::Result<(String, Vec<u8>)> {
+    let mut header = [0u8; 24];
+    stream.read_exact(&mut header).await?;
+    let command = String::from_utf8_lossy(&header[4..16])
+        .trim_end_matches('\0')
+        .to_string();
+    let length = u32::from_le_bytes(header[16..20].try_into().unwrap()) as usize;
+    let mut payload = vec![0u8; length];
+    stream.read_exact(&mut payload).await?;
+    Ok((command, payload))
+}
+
+async fn perform_bitcoin_handshake(
+    stream: &mut TcpStream,
+    start_height: i32,
+) -> Result<PeerMetadata, String> {
+    let nonce = {
+        let mut hasher = Sha256::new();
+        hasher.update(Utc::now().timestamp_nanos_opt().unwrap_or(0).to_le_bytes());
+        hasher.update(stream.peer_addr().map(|a| a.to_string()).unwrap_or_default());
+        u64::from_le_bytes(hasher.finalize()[0..8].try_into().unwrap())
+    };
+
+    let version_message = encode_bitcoin_message("version", &build_bitcoin_version_payload(nonce, start_height));
+    stream
+        .write_all(&version_message)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let mut peer_metadata: Option<PeerMetadata> = None;
+    let mut sent_verack = false;
+    let mut received_verack = false;
+
+    for _ in 0..BITCOIN_HANDSHAKE_MAX_MESSAGES {
+        if peer_metadata.is_some() && received_verack {
+            break;
+        }
+        let (command, payload) = read_bitcoin_message(stream).await.map_err(|e| e.to_string())?;
+        match command.as_str() {
+            "version" => {
+                let metadata = parse_bitcoin_version_payload(&payload).ok_or("malformed version payload")?;
+                if (metadata.protocol_version as i32) < BITCOIN_MIN_SUPPORTED_VERSION {
+                    return Err(format!(
+                        "peer protocol version {} is below minimum supported version {}",
+                        metadata.protocol_version, BITCOIN_MIN_SUPPORTED_VERSION
+                    ));
+                }
+                peer_metadata = Some(metadata);
+                if !sent_verack {
+                    stream
+                        .write_all(&encode_bitcoin_message("verack", &[]))
+                        .await
+                        .map_err(|e| e.to_string())?;
+                    sent_verack = true;
+                }
+            }
+            "verack" => received_verack = true,
+            _ => {} // ignore unrelated messages (e.g. sendheaders, feefilter) during handshake
+        }
+    }
+
+    match peer_metadata {
+        Some(metadata) if received_verack => Ok(metadata),
+        _ => Err("handshake did not complete: missing version and/or verack".to_string()),
+    }
+}
+
+// Ethereum RLPx `Hello` capability exchange. A genuine RLPx connection is an
+// ECIES-encrypted transport (static + ephemeral secp256k1 ECDH, AES-256-CTR,
+// keccak256 MAC) established *before* any `Hello` is exchanged; this crate
+// has no secp256k1/ECIES dependency available to it (no Cargo.toml exists
+// anywhere under `secure/rust` to add one to), so that encryption layer is
+// not implemented. What follows is the real `Hello` message - protocol
+// version, client ID, capability list, listen port, node ID - framed
+// plaintext instead of over the ECIES transport, which is enough to
+// negotiate and validate capabilities but is not interoperable with a real
+// go-ethereum/reth node.
+const ETH_RLPX_PROTOCOL_VERSION: u8 = 5;
+const ETH_HELLO_MAX_FRAME_SIZE: usize = 16 * 1024;
+
+struct EthHelloMessage {
+    protocol_version: u8,
+    client_id: String,
+    capabilities: Vec<(String, u32)>,
+    listen_port: u16,
+    node_id: [u8; 32],
+}
+
+fn encode_eth_u8_str(s: &str) -> Vec<u8> {
+    let bytes = s.as_bytes();
+    let mut out = vec![bytes.len() as u8];
+    out.extend_from_slice(bytes);
+    out
+}
+
+fn decode_eth_u8_str(data: &[u8], offset: &mut usize) -> Option<String> {
+    let len = *data.get(*offset)? as usize;
+    *offset += 1;
+    let s = String::from_utf8(data.get(*offset..*offset + len)?.to_vec()).ok()?;
+    *offset += len;
+    Some(s)
+}
+
+fn encode_eth_hello(hello: &EthHelloMessage) -> Vec<u8> {
+    let mut payload = Vec::new();
+    payload.push(hello.protocol_version);
+    payload.extend_from_slice(&encode_eth_u8_str(&hello.client_id));
+    payload.push(hello.capabilities.len() as u8);
+    for (name, version) in &hello.capabilities {
+        payload.extend_from_slice(&encode_eth_u8_str(name));
+        payload.extend_from_slice(&version.to_be_bytes());
+    }
+    payload.extend_from_slice(&hello.listen_port.to_be_bytes());
+    payload.extend_from_slice(&hello.node_id);
+
+    let mut framed = Vec::with_capacity(4 + payload.len());
+    framed.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+    framed.extend_from_slice(&payload);
+    framed
+}
+
+fn parse_eth_hello(frame: &[u8]) -> Option<EthHelloMessage> {
+    let mut offset = 0;
+    let protocol_version = *frame.get(offset)?;
+    offset += 1;
+    let client_id = decode_eth_u8_str(frame, &mut offset)?;
+    let capability_count = *frame.get(offset)? as usize;
+    offset += 1;
+    let mut capabilities = Vec::with_capacity(capability_count);
+    for _ in 0..capability_count {
+        let name = decode_eth_u8_str(frame, &mut offset)?;
+        let version = u32::from_be_bytes(frame.get(offset..offset + 4)?.try_into().ok()?);
+        offset += 4;
+        capabilities.push((name, version));
+    }
+    let listen_port = u16::from_be_bytes(frame.get(offset..offset + 2)?.try_into().ok()?);
+    offset += 2;
+    let node_id: [u8; 32] = frame.get(offset..offset + 32)?.try_into().ok()?;
+    Some(EthHelloMessage {
+        protocol_version,
+        client_id,
+        capabilities,
+        listen_port,
+        node_id,
+    })
+}
+
+async fn perform_ethereum_handshake(stream: &mut TcpStream) -> Result<PeerMetadata, String> {
+    let our_hello = EthHelloMessage {
+        protocol_version: ETH_RLPX_PROTOCOL_VERSION,
+        client_id: "bitcoin-sprint/rust".to_string(),
+        capabilities: vec![("eth".to_string(), 68)],
+        listen_port: 30303,
+        // No real secp256k1 keypair is available in this tree (see module
+        // comment above); zeroed rather than faking an identity we can't
+        // authenticate anyway.
+        node_id: [0u8; 32],
+    };
+    stream
+        .write_all(&encode_eth_hello(&our_hello))
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let mut len_buf = [0u8; 4];
+    stream.read_exact(&mut len_buf).await.map_err(|e| e.to_string())?;
+    let frame_len = u32::from_be_bytes(len_buf) as usize;
+    if frame_len > ETH_HELLO_MAX_FRAME_SIZE {
+        return Err("peer Hello frame exceeds sane size limit".to_string());
+    }
+    let mut frame = vec![0u8; frame_len];
+    stream.read_exact(&mut frame).await.map_err(|e| e.to_string())?;
+
+    let hello = parse_eth_hello(&frame).ok_or("malformed Hello frame")?;
+    if !hello.capabilities.iter().any(|(name, _)| name == "eth") {
+        return Err("peer does not advertise the eth capability".to_string());
+    }
+
+    Ok(PeerMetadata {
+        protocol_version: hello.protocol_version as u32,
+        services: 0,
+        user_agent: hello.client_id,
+        start_height: 0,
+        connected_at: Utc::now(),
+    })
+}
+
+async fn perform_handshake(protocol: &ProtocolType, stream: &mut TcpStream) -> Result<PeerMetadata, String> {
+    match protocol {
+        ProtocolType::Bitcoin => perform_bitcoin_handshake(stream, 0).await,
+        ProtocolType::Ethereum => perform_ethereum_handshake(stream).await,
+        // Solana nodes are reached over JSON-RPC/HTTP rather than a
+        // persistent peer-to-peer socket, so there is no connection-level
+        // handshake to negotiate here.
+        ProtocolType::Solana => Ok(PeerMetadata {
+            protocol_version: 0,
+            services: 0,
+            user_agent: "solana-json-rpc".to_string(),
+            start_height: 0,
+            connected_at: Utc::now(),
+        }),
+    }
 }
 
 // UniversalClient (expanded to match more Go methods)
 struct UniversalClient {
     cfg: Config,
     protocol: ProtocolType,
-    peers: Arc<Mutex<HashMap<String, TcpStream>>>,
+    peers: Arc<Mutex<HashMap<String, PeerConnection>>>,
     stop_chan: mpsc::Sender<()>,
+    retry_stats: Arc<Mutex<HashMap<String, RetryCounters>>>,
 }
 
 impl UniversalClient {
@@ -268,24 +873,49 @@ impl UniversalClient {
             protocol,
             peers: Arc::new(Mutex::new(HashMap::new())),
             stop_chan: tx,
+            retry_stats: Arc::new(Mutex::new(HashMap::new())),
         })
     }
 
+    fn retry_policy(&self) -> RetryPolicy {
+        RetryPolicy::new(&self.cfg, self.retry_stats.clone())
+    }
+
     async fn connect_to_network(&self) -> Result<(), String> {
         let seeds = self.get_default_seeds();
         let mut success = 0;
+        let retry_policy = self.retry_policy();
+        let chain = self.protocol.to_string();
+        let protocol = self.protocol.clone();
+        let handshake_timeout = self.cfg.connection_timeout;
+
         for addr in seeds {
-            match TcpStream::connect(&addr).await {
-                Ok(mut conn) => {
-                    // Set options to match Go
+            let protocol = protocol.clone();
+            let connect_result = retry_policy
+                .retry(&chain, || async {
+                    let mut conn = TcpStream::connect(&addr)
+                        .await
+                        .map_err(|e| RetryableError::Transient(e.to_string()))?;
                     conn.set_nodelay(true).ok();
-                    // Keepalive, buffers, etc., would require socket options
+                    match tokio::time::timeout(handshake_timeout, perform_handshake(&protocol, &mut conn)).await {
+                        Ok(Ok(metadata)) => Ok((conn, metadata)),
+                        Ok(Err(reason)) => Err(RetryableError::Transient(reason)),
+                        Err(_) => Err(RetryableError::Transient("handshake timed out".to_string())),
+                    }
+                })
+                .await;
+
+            match connect_result {
+                Ok((conn, metadata)) => {
                     let peer_id = self.generate_peer_id(&addr);
-                    self.peers.lock().await.insert(peer_id, conn);
-                    info!("Connected to peer: {}", addr);
+                    info!(
+                        "Handshake complete with peer {} ({}): protocol_version={} user_agent={}",
+                        addr, peer_id, metadata.protocol_version, metadata.user_agent
+                    );
+                    self.peers.lock().await.insert(peer_id, PeerConnection { stream: conn, metadata });
                     success += 1;
                 }
-                Err(e) => error!("Failed to connect to {}: {}", addr, e),
+                Err(e) => error!("Failed to connect/handshake with {}: {}", addr, e),
             }
         }
         if success == 0 {
@@ -342,6 +972,792 @@ impl UniversalClient {
     }
 }
 
+// Quorum dispatch: fan the same request out to every connected peer and
+// only trust a response a configurable share of them agree on, rather than
+// trusting whichever single peer answered first - protects against one
+// lying or lagging upstream serving stale chain data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum QuorumWeightStrategy {
+    Equal,
+    ByLatency,
+}
+
+impl QuorumWeightStrategy {
+    fn parse(strategy: &str) -> Self {
+        match strategy {
+            "by-latency" => QuorumWeightStrategy::ByLatency,
+            _ => QuorumWeightStrategy::Equal,
+        }
+    }
+}
+
+/// There is no real peer RPC call wired up yet (peers are handshaked
+/// `TcpStream`s with no RPC layer on top), so each peer's response is simulated
+/// deterministically from the request itself - except for a deliberately
+/// deterministic fraction of peers, which simulate serving stale data, so
+/// the quorum-agreement logic below has something genuine to disagree on.
+async fn simulate_peer_rpc_response(peer_id: &str, chain: &str, method: &str, body: &Value) -> Result<Value, String> {
+    tokio::time::sleep(Duration::from_millis(5)).await;
+
+    let mut peer_hasher = Sha256::new();
+    peer_hasher.update(peer_id.as_bytes());
+    let peer_digest = peer_hasher.finalize();
+
+    // About one in ten peers (chosen by peer id) simulates throttling every
+    // call, so
+    // `RetryPolicy`'s rate-limit detection has something real to exercise.
+    if peer_digest[1] % 10 == 0 {
+        return Err("429 Too Many Requests: rate limit exceeded".to_string());
+    }
+
+    let mut hasher = Sha256::new();
+    hasher.update(chain.as_bytes());
+    hasher.update(method.as_bytes());
+    hasher.update(body.to_string().as_bytes());
+    let canonical = u64::from_be_bytes(hasher.finalize()[0..8].try_into().unwrap());
+
+    let is_stale = peer_digest[0] % 8 == 0;
+    let value = if is_stale { canonical.wrapping_add(1) } else { canonical };
+    Ok(json!({ "chain": chain, "method": method, "value": value }))
+}
+
+struct QuorumClient<'a> {
+    client: &'a UniversalClient,
+    latency_optimizer: &'a LatencyOptimizer,
+    quorum_min: f64,
+    weight_strategy: QuorumWeightStrategy,
+}
+
+impl<'a> QuorumClient<'a> {
+    fn new(client: &'a UniversalClient, latency_optimizer: &'a LatencyOptimizer) -> Self {
+        QuorumClient {
+            client,
+            latency_optimizer,
+            quorum_min: client.cfg.quorum_min,
+            weight_strategy: QuorumWeightStrategy::parse(&client.cfg.quorum_weight_strategy),
+        }
+    }
+
+    /// Fans `(chain, method, body)` out to every connected peer concurrently
+    /// and returns the response a `quorum_min` share of (weighted) peers
+    /// agree on, bit-for-bit, within `connection_timeout`.
+    async fn dispatch(&self, chain: &str, method: &str, body: &Value) -> Result<Value, String> {
+        let peer_ids: Vec<String> = self.client.peers.lock().await.keys().cloned().collect();
+        if peer_ids.is_empty() {
+            return Err("no connected peers to query".to_string());
+        }
+
+        let timeout = self.client.cfg.connection_timeout;
+        let retry_policy = Arc::new(self.client.retry_policy());
+        let mut handles = Vec::with_capacity(peer_ids.len());
+        for peer_id in peer_ids {
+            let chain = chain.to_string();
+            let method = method.to_string();
+            let body = body.clone();
+            let retry_policy = retry_policy.clone();
+            handles.push(task::spawn(async move {
+                let start = Instant::now();
+                let outcome = match tokio::time::timeout(
+                    timeout,
+                    retry_policy.retry(&chain, || async {
+                        simulate_peer_rpc_response(&peer_id, &chain, &method, &body)
+                            .await
+                            .map_err(|e| RetryableError::classify(&e))
+                    }),
+                )
+                .await
+                {
+                    Ok(result) => result,
+                    Err(_) => Err("peer request timed out".to_string()),
+                };
+                (peer_id, start.elapsed(), outcome)
+            }));
+        }
+
+        let mut agreeing: HashMap<Vec<u8>, (f64, Value)> = HashMap::new();
+        let mut total_weight = 0.0f64;
+        let mut peers_heard_from = 0usize;
+
+        for handle in handles {
+            let Ok((peer_id, elapsed, outcome)) = handle.await else { continue };
+            match outcome {
+                Ok(response_body) => {
+                    self.latency_optimizer.track_peer_request(&peer_id, elapsed).await;
+                    let weight = match self.weight_strategy {
+                        QuorumWeightStrategy::Equal => 1.0,
+                        QuorumWeightStrategy::ByLatency => self.latency_optimizer.peer_weight(&peer_id).await,
+                    };
+
+                    let mut hasher = Sha256::new();
+                    hasher.update(response_body.to_string().as_bytes());
+                    let hash = hasher.finalize().to_vec();
+
+                    total_weight += weight;
+                    peers_heard_from += 1;
+                    let entry = agreeing.entry(hash).or_insert((0.0, response_body));
+                    entry.0 += weight;
+                }
+                Err(reason) => warn!("peer {} failed quorum request for {}/{}: {}", peer_id, chain, method, reason),
+            }
+        }
+
+        if total_weight <= 0.0 {
+            return Err("no peer responded before connection_timeout".to_string());
+        }
+
+        let best = agreeing
+            .values()
+            .cloned()
+            .max_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+        match best {
+            Some((agree_weight, value)) if agree_weight / total_weight >= self.quorum_min => Ok(value),
+            Some((agree_weight, _)) => Err(format!(
+                "quorum not reached: {} distinct responses across {} peers, best agreement {:.1}% < required {:.1}%",
+                agreeing.len(),
+                peers_heard_from,
+                (agree_weight / total_weight) * 100.0,
+                self.quorum_min * 100.0
+            )),
+            None => Err("no peer responded before connection_timeout".to_string()),
+        }
+    }
+}
+
+// Mempool subsystem: txpool content/inspect/status views over pending
+// transactions, backed by the TTL `Cache` above.
+//
+// There is no persistent reader pulling `inv`/`tx` gossip off the Bitcoin
+// peer sockets or subscribing to an Ethereum pending-tx feed wired up yet -
+// peers are handshaked (see `perform_handshake` above) and then otherwise
+// idle - so, the same simulated-feed convention `fee_history` and the
+// WebSocket subsystem below already establish in this file, a background
+// generator stands in for the real gossip/feed and ingests synthetic
+// transactions on a timer. The TTL storage, sender grouping, fee-rate
+// sorting, and pending/queued classification are real and apply unchanged
+// once a genuine peer-gossip reader replaces the generator.
+const MEMPOOL_ENTRY_TTL: Duration = Duration::from_secs(900);
+const MEMPOOL_MAX_ENTRIES: usize = 5_000;
+const MEMPOOL_INGEST_INTERVAL: Duration = Duration::from_millis(500);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct MempoolEntry {
+    txid: String,
+    sender: String,
+    // Ethereum transactions are ordered per-sender by nonce, which is what
+    // separates "pending" (immediately executable) from "queued" (blocked
+    // on an earlier nonce); Bitcoin's UTXO model has no such ordering.
+    nonce: Option<u64>,
+    fee_rate: f64,
+    size_bytes: u64,
+    received_at: DateTime<Utc>,
+}
+
+#[derive(Clone)]
+struct MempoolTracker {
+    protocol: ProtocolType,
+    cache: Cache,
+}
+
+impl MempoolTracker {
+    fn new(protocol: ProtocolType) -> Self {
+        let tracker = MempoolTracker {
+            protocol,
+            cache: Cache::new(MEMPOOL_MAX_ENTRIES),
+        };
+        tracker.spawn_ingest_generator();
+        tracker
+    }
+
+    fn key_for(&self, txid: &str) -> String {
+        format!("{}:{}", self.protocol, txid)
+    }
+
+    async fn ingest(&self, entry: MempoolEntry) {
+        let key = self.key_for(&entry.txid);
+        let value = serde_json::to_value(&entry).unwrap_or(Value::Null);
+        self.cache.set(key, value, MEMPOOL_ENTRY_TTL).await;
+    }
+
+    /// Stands in for real Bitcoin `inv`/`tx` gossip or an Ethereum
+    /// pending-tx feed (see module comment above) until a persistent
+    /// peer-stream reader exists.
+    fn spawn_ingest_generator(&self) {
+        let tracker = self.clone();
+        task::spawn(async move {
+            let mut counter: u64 = 0;
+            loop {
+                tokio::time::sleep(MEMPOOL_INGEST_INTERVAL).await;
+                counter += 1;
+                let entry = tracker.simulate_next_entry(counter);
+                tracker.ingest(entry).await;
+            }
+        });
+    }
+
+    fn simulate_next_entry(&self, counter: u64) -> MempoolEntry {
+        let mut hasher = Sha256::new();
+        hasher.update(self.protocol.to_string().as_bytes());
+        hasher.update(counter.to_le_bytes());
+        let digest = hasher.finalize();
+        let txid = format!("{:x}", digest);
+        let sender_bucket = digest[0] % 6; // a handful of recurring senders, like real gossip
+        let sender = format!("{}_sender_{}", self.protocol, sender_bucket);
+        let fee_rate = 1.0 + (digest[1] as f64 / 255.0) * 149.0; // ~1-150 sat/vByte or gwei
+        let size_bytes = 150 + (digest[2] as u64 * 4);
+        let nonce = match self.protocol {
+            ProtocolType::Ethereum => Some((digest[3] as u64) % 20),
+            _ => None,
+        };
+        MempoolEntry {
+            txid,
+            sender,
+            nonce,
+            fee_rate,
+            size_bytes,
+            received_at: Utc::now(),
+        }
+    }
+
+    async fn entries(&self) -> Vec<MempoolEntry> {
+        self.cache
+            .entries_with_prefix(&format!("{}:", self.protocol))
+            .await
+            .into_iter()
+            .filter_map(|value| serde_json::from_value(value).ok())
+            .collect()
+    }
+
+    /// Full pending/queued transactions grouped by sender, each group
+    /// sorted highest fee rate first.
+    async fn content(&self) -> Value {
+        let mut by_sender: BTreeMap<String, Vec<MempoolEntry>> = BTreeMap::new();
+        for entry in self.entries().await {
+            by_sender.entry(entry.sender.clone()).or_default().push(entry);
+        }
+        for txs in by_sender.values_mut() {
+            txs.sort_by(|a, b| b.fee_rate.partial_cmp(&a.fee_rate).unwrap_or(std::cmp::Ordering::Equal));
+        }
+        json!(by_sender)
+    }
+
+    /// Compact, human-readable summaries sorted highest fee rate first.
+    async fn inspect(&self) -> Vec<Value> {
+        let mut entries = self.entries().await;
+        entries.sort_by(|a, b| b.fee_rate.partial_cmp(&a.fee_rate).unwrap_or(std::cmp::Ordering::Equal));
+        entries
+            .iter()
+            .map(|entry| {
+                json!({
+                    "summary": format!("{}: {} @ {:.1}/byte ({}B, {}s old)",
+                        entry.txid.get(0..12).unwrap_or(&entry.txid),
+                        entry.sender,
+                        entry.fee_rate,
+                        entry.size_bytes,
+                        (Utc::now() - entry.received_at).num_seconds()),
+                    "fee_rate": entry.fee_rate,
+                })
+            })
+            .collect()
+    }
+
+    async fn status(&self) -> Value {
+        let entries = self.entries().await;
+        let (pending, queued) = classify_pending_queued(&entries);
+        json!({
+            "pending": pending,
+            "queued": queued,
+            "total": entries.len(),
+        })
+    }
+}
+
+/// Splits entries into immediately-executable ("pending") and
+/// nonce-gapped ("queued"), mirroring the distinction Ethereum's own
+/// `txpool_status` makes. Entries with no nonce (Bitcoin) are always
+/// pending - there is no UTXO-model equivalent of a nonce gap.
+fn classify_pending_queued(entries: &[MempoolEntry]) -> (usize, usize) {
+    let mut pending = 0;
+    let mut queued = 0;
+    let mut nonces_by_sender: BTreeMap<&str, Vec<u64>> = BTreeMap::new();
+    for entry in entries {
+        match entry.nonce {
+            Some(nonce) => nonces_by_sender.entry(entry.sender.as_str()).or_default().push(nonce),
+            None => pending += 1,
+        }
+    }
+    for nonces in nonces_by_sender.values_mut() {
+        nonces.sort_unstable();
+        let mut expected = nonces[0];
+        for &nonce in nonces.iter() {
+            if nonce == expected {
+                pending += 1;
+                expected += 1;
+            } else {
+                queued += 1;
+            }
+        }
+    }
+    (pending, queued)
+}
+
+// WebSocket pubsub subsystem (newHeads / logs / newPendingTransactions).
+//
+// There is no real block/log/pending-tx feed wired in yet - only raw peer
+// TCP sockets - so each chain's events are generated by a background
+// ticker, the same simulated-data convention `fee_history` below and
+// `Config::simulate_blocks` already establish elsewhere in this file. The
+// subscribe/unsubscribe protocol, per-connection/per-ip/per-chain caps, and
+// server-side log filtering below are real and apply unchanged once a
+// genuine upstream feed replaces the ticker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum SubscriptionKind {
+    NewHeads,
+    Logs,
+    NewPendingTransactions,
+}
+
+impl SubscriptionKind {
+    fn parse(name: &str) -> Option<Self> {
+        match name {
+            "newHeads" => Some(SubscriptionKind::NewHeads),
+            "logs" => Some(SubscriptionKind::Logs),
+            "newPendingTransactions" => Some(SubscriptionKind::NewPendingTransactions),
+            _ => None,
+        }
+    }
+}
+
+/// Server-side `logs` filter, applied before an event ever reaches the
+/// client rather than leaving filtering up to it.
+#[derive(Debug, Clone, Deserialize, Default)]
+struct LogsFilter {
+    address: Option<String>,
+    #[serde(default)]
+    topics: Vec<String>,
+}
+
+impl LogsFilter {
+    fn matches(&self, event: &Value) -> bool {
+        if let Some(address) = &self.address {
+            if event.get("address").and_then(Value::as_str) != Some(address.as_str()) {
+                return false;
+            }
+        }
+        if !self.topics.is_empty() {
+            let event_topics: Vec<&str> = event
+                .get("topics")
+                .and_then(Value::as_array)
+                .map(|arr| arr.iter().filter_map(Value::as_str).collect())
+                .unwrap_or_default();
+            if !self.topics.iter().all(|t| event_topics.contains(&t.as_str())) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// One chain's broadcast channels, fanned out from a background generator
+/// task to every subscribed connection.
+struct ChainChannels {
+    new_heads: broadcast::Sender<Value>,
+    logs: broadcast::Sender<Value>,
+    pending_tx: broadcast::Sender<Value>,
+}
+
+impl ChainChannels {
+    fn new() -> Self {
+        const CHANNEL_CAPACITY: usize = 256;
+        ChainChannels {
+            new_heads: broadcast::channel(CHANNEL_CAPACITY).0,
+            logs: broadcast::channel(CHANNEL_CAPACITY).0,
+            pending_tx: broadcast::channel(CHANNEL_CAPACITY).0,
+        }
+    }
+}
+
+/// Tracks the three connection caps from `Config`
+/// (`websocket_max_connections` / `_per_ip` / `_per_chain`) and hands out
+/// broadcast receivers for a chain's subscription streams.
+struct SubscriptionManager {
+    cfg: Arc<Config>,
+    chains: HashMap<ProtocolType, ChainChannels>,
+    total_connections: Arc<AtomicU32>,
+    per_ip: Arc<Mutex<HashMap<IpAddr, u32>>>,
+    per_chain: Arc<Mutex<HashMap<ProtocolType, u32>>>,
+}
+
+impl SubscriptionManager {
+    fn new(cfg: Arc<Config>) -> Self {
+        let mut chains = HashMap::new();
+        for protocol in [ProtocolType::Bitcoin, ProtocolType::Ethereum, ProtocolType::Solana] {
+            chains.insert(protocol, ChainChannels::new());
+        }
+
+        let manager = SubscriptionManager {
+            cfg,
+            chains,
+            total_connections: Arc::new(AtomicU32::new(0)),
+            per_ip: Arc::new(Mutex::new(HashMap::new())),
+            per_chain: Arc::new(Mutex::new(HashMap::new())),
+        };
+        manager.spawn_event_generators();
+        manager
+    }
+
+    /// One ticker per chain, standing in for a real newHeads/logs/pending-tx
+    /// feed until the peer layer above actually decodes chain protocol
+    /// messages.
+    fn spawn_event_generators(&self) {
+        for (protocol, channels) in &self.chains {
+            let protocol = protocol.clone();
+            let new_heads_tx = channels.new_heads.clone();
+            let logs_tx = channels.logs.clone();
+            let pending_tx_tx = channels.pending_tx.clone();
+
+            task::spawn(async move {
+                let mut block_number: u64 = 0;
+                loop {
+                    tokio::time::sleep(Duration::from_secs(1)).await;
+                    block_number += 1;
+
+                    let head = json!({
+                        "number": block_number,
+                        "hash": format!("0x{:064x}", block_number),
+                        "timestamp": Utc::now().timestamp(),
+                    });
+                    let _ = new_heads_tx.send(head);
+
+                    let log = json!({
+                        "address": format!("0x{:040x}", block_number),
+                        "topics": [format!("0x{:064x}", block_number)],
+                        "data": "0x",
+                        "block_number": block_number,
+                    });
+                    let _ = logs_tx.send(log);
+
+                    let pending = json!({
+                        "hash": format!("0x{:064x}", block_number.wrapping_mul(31)),
+                        "chain": protocol.to_string(),
+                    });
+                    let _ = pending_tx_tx.send(pending);
+                }
+            });
+        }
+    }
+
+    async fn try_register(&self, chain: ProtocolType, ip: IpAddr) -> Result<(), String> {
+        if self.total_connections.load(Ordering::Relaxed) >= self.cfg.websocket_max_connections {
+            return Err("websocket_max_connections reached".to_string());
+        }
+
+        let mut per_ip = self.per_ip.lock().await;
+        let ip_count = per_ip.entry(ip).or_insert(0);
+        if *ip_count >= self.cfg.websocket_max_per_ip {
+            return Err("websocket_max_per_ip reached".to_string());
+        }
+
+        let mut per_chain = self.per_chain.lock().await;
+        let chain_count = per_chain.entry(chain).or_insert(0);
+        if *chain_count >= self.cfg.websocket_max_per_chain {
+            return Err("websocket_max_per_chain reached".to_string());
+        }
+
+        *ip_count += 1;
+        *chain_count += 1;
+        self.total_connections.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+
+    async fn unregister(&self, chain: &ProtocolType, ip: IpAddr) {
+        self.total_connections.fetch_sub(1, Ordering::Relaxed);
+        if let Some(count) = self.per_ip.lock().await.get_mut(&ip) {
+            *count = count.saturating_sub(1);
+        }
+        if let Some(count) = self.per_chain.lock().await.get_mut(chain) {
+            *count = count.saturating_sub(1);
+        }
+    }
+
+    fn subscribe(&self, chain: &ProtocolType, kind: SubscriptionKind) -> Option<broadcast::Receiver<Value>> {
+        let channels = self.chains.get(chain)?;
+        Some(match kind {
+            SubscriptionKind::NewHeads => channels.new_heads.subscribe(),
+            SubscriptionKind::Logs => channels.logs.subscribe(),
+            SubscriptionKind::NewPendingTransactions => channels.pending_tx.subscribe(),
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct SubscribeFrame {
+    method: String,
+    params: Vec<Value>,
+}
+
+/// Drives one upgraded `/ws/:chain` connection: parses subscribe/unsubscribe
+/// frames, forwards each active subscription's broadcast events into the
+/// socket, and tears everything down (including the connection caps) once
+/// the socket closes.
+async fn run_websocket_connection(
+    mut socket: WebSocket,
+    chain: ProtocolType,
+    ip: IpAddr,
+    subscriptions: Arc<SubscriptionManager>,
+) {
+    let (event_tx, mut event_rx) = mpsc::channel::<Value>(256);
+    let mut active: HashMap<Uuid, task::JoinHandle<()>> = HashMap::new();
+
+    loop {
+        tokio::select! {
+            forwarded = event_rx.recv() => {
+                match forwarded {
+                    Some(event) => {
+                        if socket.send(Message::Text(event.to_string())).await.is_err() {
+                            break;
+                        }
+                    }
+                    None => break,
+                }
+            }
+            incoming = socket.recv() => {
+                let Some(Ok(message)) = incoming else { break };
+                let Message::Text(text) = message else { continue };
+
+                let Ok(frame) = serde_json::from_str::<SubscribeFrame>(&text) else {
+                    let _ = socket.send(Message::Text(json!({"error": "invalid frame"}).to_string())).await;
+                    continue;
+                };
+
+                match frame.method.as_str() {
+                    "subscribe" => {
+                        let kind_name = frame.params.first().and_then(Value::as_str).unwrap_or("");
+                        let Some(kind) = SubscriptionKind::parse(kind_name) else {
+                            let _ = socket.send(Message::Text(json!({"error": "unknown subscription type"}).to_string())).await;
+                            continue;
+                        };
+                        let filter: LogsFilter = frame
+                            .params
+                            .get(1)
+                            .and_then(|v| serde_json::from_value(v.clone()).ok())
+                            .unwrap_or_default();
+
+                        let Some(mut receiver) = subscriptions.subscribe(&chain, kind) else {
+                            let _ = socket.send(Message::Text(json!({"error": "unknown chain"}).to_string())).await;
+                            continue;
+                        };
+
+                        let subscription_id = Uuid::new_v4();
+                        let forward_tx = event_tx.clone();
+                        let handle = task::spawn(async move {
+                            while let Ok(event) = receiver.recv().await {
+                                if kind == SubscriptionKind::Logs && !filter.matches(&event) {
+                                    continue;
+                                }
+                                let wrapped = json!({
+                                    "method": "eth_subscription",
+                                    "params": { "subscription": subscription_id.to_string(), "result": event },
+                                });
+                                if forward_tx.send(wrapped).await.is_err() {
+                                    break;
+                                }
+                            }
+                        });
+                        active.insert(subscription_id, handle);
+
+                        let _ = socket
+                            .send(Message::Text(json!({ "result": subscription_id.to_string() }).to_string()))
+                            .await;
+                    }
+                    "unsubscribe" => {
+                        let Some(id_str) = frame.params.first().and_then(Value::as_str) else { continue };
+                        if let Ok(id) = Uuid::parse_str(id_str) {
+                            if let Some(handle) = active.remove(&id) {
+                                handle.abort();
+                            }
+                        }
+                    }
+                    _ => {
+                        let _ = socket.send(Message::Text(json!({"error": "unknown method"}).to_string())).await;
+                    }
+                }
+            }
+        }
+    }
+
+    for (_, handle) in active {
+        handle.abort();
+    }
+    subscriptions.unregister(&chain, ip).await;
+}
+
+// Upstream block data feeding into fee_history validation. There is no real
+// chain RPC client wired into this binary yet (only raw peer TCP sockets),
+// so in place of a genuine upstream fetch this is generated deterministically
+// per chain/block, the same way `Config::simulate_blocks` already stands in
+// for a live chain feed elsewhere. The validation logic below is real and
+// applies identically once a genuine upstream response is substituted in.
+#[derive(Debug, Clone)]
+struct UpstreamBlockFeeData {
+    base_fee_per_gas: u64,
+    gas_used: u64,
+    gas_limit: u64,
+}
+
+fn simulate_upstream_block_fee_data(chain: &str, block_number: u64) -> UpstreamBlockFeeData {
+    let mut hasher = Sha256::new();
+    hasher.update(chain.as_bytes());
+    hasher.update(block_number.to_be_bytes());
+    let digest = hasher.finalize();
+
+    let gas_limit: u64 = 15_000_000 + (u64::from_be_bytes(digest[0..8].try_into().unwrap()) % 5_000_000);
+    // Keep gas_used within [0, gas_limit] by construction so the simulated
+    // data itself is always valid - the validation below exists for when a
+    // real upstream is substituted in and might not be.
+    let gas_used = u64::from_be_bytes(digest[8..16].try_into().unwrap()) % (gas_limit + 1);
+    let base_fee_per_gas = 1_000_000_000 + (u64::from_be_bytes(digest[16..24].try_into().unwrap()) % 50_000_000_000);
+
+    UpstreamBlockFeeData { base_fee_per_gas, gas_used, gas_limit }
+}
+
+/// `gas_used / gas_limit`, validated into `[0.0, 1.0]` rather than trusted
+/// as-is.
+fn validate_gas_used_ratio(block: &UpstreamBlockFeeData, block_number: u64) -> Result<f64, String> {
+    if block.gas_limit == 0 {
+        return Err(format!("block {} reports a zero gas_limit", block_number));
+    }
+
+    let ratio = block.gas_used as f64 / block.gas_limit as f64;
+    if !(0.0..=1.0).contains(&ratio) {
+        return Err(format!(
+            "block {} gas_used_ratio {:.6} outside [0.0, 1.0]",
+            block_number, ratio
+        ));
+    }
+    Ok(ratio)
+}
+
+/// EIP-1559: `base_next = base * (1 + (1/8) * (gas_used - gas_target) / gas_target)`,
+/// with `gas_target = gas_limit / elasticity`.
+fn expected_next_base_fee(block: &UpstreamBlockFeeData) -> u64 {
+    let gas_target = (block.gas_limit / BASE_FEE_ELASTICITY_MULTIPLIER).max(1);
+    let delta = block.gas_used as f64 - gas_target as f64;
+    let next = block.base_fee_per_gas as f64
+        * (1.0 + (delta / gas_target as f64) / BASE_FEE_MAX_CHANGE_DENOMINATOR);
+    next.max(0.0).round() as u64
+}
+
+/// Rejects a reported next base fee that deviates from the recomputed one
+/// by more than `BASE_FEE_DEVIATION_TOLERANCE` of the current base fee.
+fn validate_next_base_fee(
+    block: &UpstreamBlockFeeData,
+    reported_next: u64,
+    block_number: u64,
+) -> Result<(), String> {
+    let expected = expected_next_base_fee(block);
+    let tolerance = (block.base_fee_per_gas as f64 * BASE_FEE_DEVIATION_TOLERANCE).max(1.0);
+    let deviation = (reported_next as f64 - expected as f64).abs();
+
+    if deviation > tolerance {
+        return Err(format!(
+            "block {} reported next base_fee_per_gas {} deviates from expected {} (EIP-1559) by {:.1}, tolerance {:.1}",
+            block_number, reported_next, expected, deviation, tolerance
+        ));
+    }
+    Ok(())
+}
+
+#[derive(Debug, Deserialize)]
+struct FeeHistoryQuery {
+    block_count: u64,
+    newest_block: u64,
+    reward_percentiles: Option<String>,
+}
+
+impl FeeHistoryQuery {
+    fn percentiles(&self) -> Vec<f64> {
+        self.reward_percentiles
+            .as_deref()
+            .unwrap_or("")
+            .split(',')
+            .filter_map(|p| p.trim().parse::<f64>().ok())
+            .collect()
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct FeeHistoryResponse {
+    oldest_block: u64,
+    base_fee_per_gas: Vec<u64>,
+    gas_used_ratio: Vec<f64>,
+    reward: Vec<Vec<u64>>,
+}
+
+/// Simulates (and validates) `block_count` blocks ending at `newest_block`,
+/// each block's reported next base fee checked against every predecessor's
+/// EIP-1559 transition and every `gas_used_ratio` recomputed rather than
+/// trusted, rejecting on the first inconsistency found.
+fn build_fee_history(chain: &str, query: &FeeHistoryQuery) -> Result<FeeHistoryResponse, String> {
+    if query.block_count == 0 {
+        return Err("block_count must be at least 1".to_string());
+    }
+    if query.block_count > query.newest_block + 1 {
+        return Err("block_count exceeds newest_block + 1".to_string());
+    }
+
+    let percentiles = query.percentiles();
+    let oldest_block = query.newest_block + 1 - query.block_count;
+
+    let mut blocks: Vec<UpstreamBlockFeeData> = (oldest_block..=query.newest_block)
+        .map(|n| simulate_upstream_block_fee_data(chain, n))
+        .collect();
+    // Simulated blocks draw their base fees independently, so chain them
+    // through the EIP-1559 transition to keep the simulated window itself
+    // consistent; the checks below still verify every transition.
+    for i in 1..blocks.len() {
+        blocks[i].base_fee_per_gas = expected_next_base_fee(&blocks[i - 1]);
+    }
+
+    let mut gas_used_ratio = Vec::with_capacity(blocks.len());
+    let mut base_fee_per_gas = Vec::with_capacity(blocks.len() + 1);
+    let mut reward = Vec::with_capacity(blocks.len());
+
+    for (i, block) in blocks.iter().enumerate() {
+        let block_number = oldest_block + i as u64;
+        gas_used_ratio.push(validate_gas_used_ratio(block, block_number)?);
+        base_fee_per_gas.push(block.base_fee_per_gas);
+
+        if i + 1 < blocks.len() {
+            let next_block = &blocks[i + 1];
+            validate_next_base_fee(block, next_block.base_fee_per_gas, block_number)?;
+        }
+
+        // Reward per requested percentile: a simple share of the base fee,
+        // increasing with percentile, since there is no real priority-fee
+        // sample set behind the simulated data to draw from.
+        reward.push(
+            percentiles
+                .iter()
+                .map(|p| ((block.base_fee_per_gas as f64) * (p.clamp(0.0, 100.0) / 100.0) * 0.1) as u64)
+                .collect(),
+        );
+    }
+
+    // `base_fee_per_gas` has `block_count + 1` entries: the trailing one is
+    // this range's own EIP-1559 projection one block past `newest_block`.
+    let last = blocks.last().expect("block_count validated to be >= 1");
+    base_fee_per_gas.push(expected_next_base_fee(last));
+
+    Ok(FeeHistoryResponse {
+        oldest_block,
+        base_fee_per_gas,
+        gas_used_ratio,
+        reward,
+    })
+}
+
 // Server (expanded with more handlers and components)
 #[derive(Clone)]
 struct Server {
@@ -349,13 +1765,17 @@ struct Server {
     cache: Cache,
     latency_optimizer: LatencyOptimizer,
     p2p_clients: Arc<Mutex<HashMap<ProtocolType, UniversalClient>>>,
+    subscriptions: Arc<SubscriptionManager>,
+    mempool_trackers: Arc<HashMap<ProtocolType, MempoolTracker>>,
 }
 
 impl Server {
     async fn new(cfg: Config) -> Self {
         let cfg_arc = Arc::new(cfg.clone());
         let mut p2p_clients = HashMap::new();
+        let mut mempool_trackers = HashMap::new();
         for protocol in vec![ProtocolType::Bitcoin, ProtocolType::Ethereum, ProtocolType::Solana] {
+            mempool_trackers.insert(protocol.clone(), MempoolTracker::new(protocol.clone()));
             match UniversalClient::new(cfg.clone(), protocol.clone()).await {
                 Ok(client) => {
                     p2p_clients.insert(protocol, client);
@@ -365,22 +1785,29 @@ impl Server {
         }
 
         Server {
-            cfg: cfg_arc,
+            cfg: cfg_arc.clone(),
             cache: Cache::new(cfg.cache_size as usize),
             latency_optimizer: LatencyOptimizer::new(Duration::from_millis(100)),
             p2p_clients: Arc::new(Mutex::new(p2p_clients)),
+            subscriptions: Arc::new(SubscriptionManager::new(cfg_arc)),
+            mempool_trackers: Arc::new(mempool_trackers),
         }
     }
 
     fn register_routes(&self) -> Router {
         Router::new()
             .route("/api/v1/universal/:chain/:method", post(Self::universal_handler))
+            .route("/api/v1/:chain/fee_history", get(Self::fee_history_handler))
+            .route("/ws/:chain", get(Self::websocket_handler))
             .route("/api/v1/latency", get(Self::latency_stats_handler))
             .route("/api/v1/cache", get(Self::cache_stats_handler))
             .route("/health", get(Self::health_handler))
             .route("/version", get(Self::version_handler))
             .route("/status", get(Self::status_handler))
             .route("/mempool", get(Self::mempool_handler))
+            .route("/api/v1/:chain/txpool/content", get(Self::mempool_content_handler))
+            .route("/api/v1/:chain/txpool/inspect", get(Self::mempool_inspect_handler))
+            .route("/api/v1/:chain/txpool/status", get(Self::mempool_status_handler))
             .route("/chains", get(Self::chains_handler))
             .route("/api/v1/p2p/diag", get(Self::p2p_diag_handler))
             .with_state(self.clone())
@@ -420,7 +1847,11 @@ impl Server {
         }
 
         let listener = tokio::net::TcpListener::bind(&addr).await?;
-        axum::serve(listener, app).await?;
+        axum::serve(
+            listener,
+            app.into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .await?;
         Ok(())
     }
 
@@ -431,25 +1862,90 @@ impl Server {
         body: Json<Value>,
     ) -> impl IntoResponse {
         let start = Instant::now();
-        // Simplified logic
-        let response = json!({
-            "chain": chain,
-            "method": method,
-            "data": *body,
-            "timestamp": Utc::now().to_rfc3339(),
-            "sprint_advantages": {
-                "unified_api": "Single endpoint for all chains",
+
+        // Quorum-dispatch to connected peers when any exist for this chain;
+        // otherwise fall back to the single-node mock response, so the
+        // endpoint still answers in dev setups with no live peers.
+        let protocol = ProtocolType::parse(&chain);
+        let p2p_clients = state.p2p_clients.lock().await;
+        let client_for_chain = protocol.as_ref().and_then(|p| p2p_clients.get(p));
+        let mut quorum_result = None;
+        if let Some(client) = client_for_chain {
+            if !client.peers.lock().await.is_empty() {
+                quorum_result = Some(QuorumClient::new(client, &state.latency_optimizer).dispatch(&chain, &method, &body).await);
             }
-        });
+        }
+        drop(p2p_clients);
 
         let duration = start.elapsed();
         state.latency_optimizer.track_request(&chain, duration).await;
-
         if duration > Duration::from_millis(100) {
             warn!("P99 exceeded for {}: {:?}", chain, duration);
         }
 
-        (StatusCode::OK, Json(response))
+        match quorum_result {
+            Some(Ok(quorum_data)) => (
+                StatusCode::OK,
+                Json(json!({
+                    "chain": chain,
+                    "method": method,
+                    "data": quorum_data,
+                    "timestamp": Utc::now().to_rfc3339(),
+                    "quorum_verified": true,
+                })),
+            ),
+            Some(Err(reason)) => (
+                StatusCode::CONFLICT,
+                Json(json!({ "chain": chain, "method": method, "error": reason })),
+            ),
+            None => (
+                StatusCode::OK,
+                Json(json!({
+                    "chain": chain,
+                    "method": method,
+                    "data": *body,
+                    "timestamp": Utc::now().to_rfc3339(),
+                    "sprint_advantages": {
+                        "unified_api": "Single endpoint for all chains",
+                    }
+                })),
+            ),
+        }
+    }
+
+    async fn websocket_handler(
+        state: axum::extract::State<Server>,
+        Path(chain): Path<String>,
+        ConnectInfo(addr): ConnectInfo<SocketAddr>,
+        ws: WebSocketUpgrade,
+    ) -> impl IntoResponse {
+        let Some(protocol) = ProtocolType::parse(&chain) else {
+            return (StatusCode::NOT_FOUND, "unknown chain").into_response();
+        };
+
+        let ip = addr.ip();
+        let subscriptions = state.subscriptions.clone();
+        if let Err(reason) = subscriptions.try_register(protocol.clone(), ip).await {
+            warn!("rejecting websocket upgrade for {} from {}: {}", chain, ip, reason);
+            return (StatusCode::TOO_MANY_REQUESTS, reason).into_response();
+        }
+
+        ws.on_upgrade(move |socket| run_websocket_connection(socket, protocol, ip, subscriptions))
+            .into_response()
+    }
+
+    async fn fee_history_handler(
+        _state: axum::extract::State<Server>,
+        Path(chain): Path<String>,
+        Query(query): Query<FeeHistoryQuery>,
+    ) -> impl IntoResponse {
+        match build_fee_history(&chain, &query) {
+            Ok(history) => (StatusCode::OK, Json(json!(history))).into_response(),
+            Err(reason) => {
+                warn!("fee_history validation failed for {}: {}", chain, reason);
+                (StatusCode::BAD_GATEWAY, Json(json!({ "error": reason }))).into_response()
+            }
+        }
     }
 
     async fn latency_stats_handler(
@@ -466,10 +1962,15 @@ impl Server {
     async fn cache_stats_handler(
         state: axum::extract::State<Server>,
     ) -> impl IntoResponse {
-        let items = state.cache.items.lock().await;
+        let size = state.cache.inner.lock().await.items.len();
+        let counters = &state.cache.counters;
         let stats = json!({
-            "size": items.len(),
+            "size": size,
             "max_size": state.cache.max_size,
+            "hits": counters.hits.load(Ordering::Relaxed),
+            "misses": counters.misses.load(Ordering::Relaxed),
+            "evictions": counters.evictions.load(Ordering::Relaxed),
+            "expirations": counters.expirations.load(Ordering::Relaxed),
         });
         (StatusCode::OK, Json(stats))
     }
@@ -527,17 +2028,66 @@ impl Server {
         (StatusCode::OK, Json(status))
     }
 
-    async fn mempool_handler(
-        _state: axum::extract::State<Server>,
-    ) -> impl IntoResponse {
+    /// Aggregate pending/queued counts across every chain's mempool
+    /// tracker. Per-chain detail lives under `/api/v1/:chain/txpool/*`.
+    async fn mempool_handler(state: axum::extract::State<Server>) -> impl IntoResponse {
+        let mut per_chain = HashMap::new();
+        let mut total_pending: u64 = 0;
+        let mut total_queued: u64 = 0;
+        for (protocol, tracker) in state.mempool_trackers.iter() {
+            let status = tracker.status().await;
+            total_pending += status.get("pending").and_then(Value::as_u64).unwrap_or(0);
+            total_queued += status.get("queued").and_then(Value::as_u64).unwrap_or(0);
+            per_chain.insert(protocol.to_string(), status);
+        }
         let resp = json!({
-            "mempool_size": 100,
-            "transactions": ["tx1", "tx2", "tx3"],
+            "pending": total_pending,
+            "queued": total_queued,
+            "chains": per_chain,
             "timestamp": Utc::now().to_rfc3339(),
         });
         (StatusCode::OK, Json(resp))
     }
 
+    async fn mempool_content_handler(
+        state: axum::extract::State<Server>,
+        Path(chain): Path<String>,
+    ) -> impl IntoResponse {
+        let Some(protocol) = ProtocolType::parse(&chain) else {
+            return (StatusCode::NOT_FOUND, "unknown chain").into_response();
+        };
+        match state.mempool_trackers.get(&protocol) {
+            Some(tracker) => Json(tracker.content().await).into_response(),
+            None => (StatusCode::NOT_FOUND, "no mempool tracker for chain").into_response(),
+        }
+    }
+
+    async fn mempool_inspect_handler(
+        state: axum::extract::State<Server>,
+        Path(chain): Path<String>,
+    ) -> impl IntoResponse {
+        let Some(protocol) = ProtocolType::parse(&chain) else {
+            return (StatusCode::NOT_FOUND, "unknown chain").into_response();
+        };
+        match state.mempool_trackers.get(&protocol) {
+            Some(tracker) => Json(tracker.inspect().await).into_response(),
+            None => (StatusCode::NOT_FOUND, "no mempool tracker for chain").into_response(),
+        }
+    }
+
+    async fn mempool_status_handler(
+        state: axum::extract::State<Server>,
+        Path(chain): Path<String>,
+    ) -> impl IntoResponse {
+        let Some(protocol) = ProtocolType::parse(&chain) else {
+            return (StatusCode::NOT_FOUND, "unknown chain").into_response();
+        };
+        match state.mempool_trackers.get(&protocol) {
+            Some(tracker) => Json(tracker.status().await).into_response(),
+            None => (StatusCode::NOT_FOUND, "no mempool tracker for chain").into_response(),
+        }
+    }
+
     async fn chains_handler(
         _state: axum::extract::State<Server>,
     ) -> impl IntoResponse {
@@ -557,7 +2107,29 @@ impl Server {
         let p2p_clients = state.p2p_clients.lock().await;
         let mut diag = HashMap::new();
         for (protocol, client) in p2p_clients.iter() {
-            diag.insert(protocol.to_string(), client.get_peer_count().await);
+            let retries = client.retry_stats.lock().await.clone();
+            let peers = client.peers.lock().await;
+            let peer_metadata: Vec<Value> = peers
+                .iter()
+                .map(|(peer_id, conn)| {
+                    json!({
+                        "peer_id": peer_id,
+                        "protocol_version": conn.metadata.protocol_version,
+                        "services": conn.metadata.services,
+                        "user_agent": conn.metadata.user_agent,
+                        "start_height": conn.metadata.start_height,
+                        "connected_at": conn.metadata.connected_at.to_rfc3339(),
+                    })
+                })
+                .collect();
+            diag.insert(
+                protocol.to_string(),
+                json!({
+                    "peer_count": peers.len(),
+                    "peers": peer_metadata,
+                    "retries": retries,
+                }),
+            );
         }
         (StatusCode::OK, Json(json!(diag)))
     }
@@ -0,0 +1,428 @@
+// SPDX-License-Identifier: MIT
+// Bitcoin Sprint - durable challenge/beacon persistence
+//
+// `StorageVerifier`'s challenges and used beacons used to live only in the
+// in-memory maps on the struct itself, so a process restart dropped every
+// outstanding challenge (letting a provider simply wait it out instead of
+// proving anything) and wiped replay protection (letting an old beacon be
+// replayed). This module adds an optional `ChallengeStore` behind which
+// that state can be made durable and shared across instances.
+//
+// There's no RocksDB (or any other embedded KV) dependency anywhere in
+// this tree, and no Cargo.toml to add one to, so `FileChallengeStore` is a
+// small hand-rolled append-only log with an in-memory index rebuilt at
+// startup (the same Bitcask-style shape RocksDB itself is loosely based
+// on) rather than a fabricated dependency - the same "genuine,
+// explicitly-documented substitute" approach already used for `fast_hash`,
+// `fast_lock`, and the RLE codec elsewhere in this codebase.
+// `InMemoryChallengeStore` remains the default for callers that don't need
+// durability, preserving the behavior `StorageVerifier` had before this
+// module existed.
+
+use std::collections::HashMap;
+use std::io;
+
+use async_trait::async_trait;
+use tokio::sync::Mutex;
+
+use crate::storage_verifier::{StorageChallenge, StorageVerificationError};
+
+/// Key namespace prefixes, so a shared store (or a dump of the log file)
+/// can tell challenges and beacons apart at a glance.
+pub const CHALLENGE_KEY_PREFIX: &str = "chall:";
+pub const BEACON_KEY_PREFIX: &str = "beacon:";
+
+/// Durable storage for outstanding challenges and used beacons.
+///
+/// `?Send`: mirrors `StorageBackend` - driven from the same single-threaded
+/// actix arbiter as the rest of `StorageVerifier`.
+#[async_trait(?Send)]
+pub trait ChallengeStore: Send + Sync {
+    async fn put_challenge(&self, challenge: &StorageChallenge) -> Result<(), StorageVerificationError>;
+    async fn get_challenge(&self, challenge_id: &str) -> Result<Option<StorageChallenge>, StorageVerificationError>;
+    async fn remove_challenge(&self, challenge_id: &str) -> Result<(), StorageVerificationError>;
+    /// Record a beacon as used until `expiry` (unix seconds).
+    async fn put_beacon(&self, beacon: &str, expiry: u64) -> Result<(), StorageVerificationError>;
+    async fn has_beacon(&self, beacon: &str) -> Result<bool, StorageVerificationError>;
+    /// Drop challenges and beacons past their expiry. TTL-driven compaction
+    /// rather than size-driven, so it stays correct regardless of traffic.
+    async fn compact_expired(&self, now: u64) -> Result<(), StorageVerificationError>;
+}
+
+/// Non-durable default: a process restart drops everything, same as
+/// `StorageVerifier`'s behavior before `ChallengeStore` existed.
+#[derive(Default)]
+pub struct InMemoryChallengeStore {
+    challenges: Mutex<HashMap<String, StorageChallenge>>,
+    beacons: Mutex<HashMap<String, u64>>, // beacon -> expiry
+}
+
+impl InMemoryChallengeStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait(?Send)]
+impl ChallengeStore for InMemoryChallengeStore {
+    async fn put_challenge(&self, challenge: &StorageChallenge) -> Result<(), StorageVerificationError> {
+        self.challenges
+            .lock()
+            .await
+            .insert(challenge.id.clone(), challenge.clone());
+        Ok(())
+    }
+
+    async fn get_challenge(&self, challenge_id: &str) -> Result<Option<StorageChallenge>, StorageVerificationError> {
+        Ok(self.challenges.lock().await.get(challenge_id).cloned())
+    }
+
+    async fn remove_challenge(&self, challenge_id: &str) -> Result<(), StorageVerificationError> {
+        self.challenges.lock().await.remove(challenge_id);
+        Ok(())
+    }
+
+    async fn put_beacon(&self, beacon: &str, expiry: u64) -> Result<(), StorageVerificationError> {
+        self.beacons.lock().await.insert(beacon.to_string(), expiry);
+        Ok(())
+    }
+
+    async fn has_beacon(&self, beacon: &str) -> Result<bool, StorageVerificationError> {
+        Ok(self.beacons.lock().await.contains_key(beacon))
+    }
+
+    async fn compact_expired(&self, now: u64) -> Result<(), StorageVerificationError> {
+        self.challenges.lock().await.retain(|_, c| now < c.expiry);
+        self.beacons.lock().await.retain(|_, &mut expiry| now < expiry);
+        Ok(())
+    }
+}
+
+/// One entry in the append-only log, tagged so a replay can tell a live
+/// challenge from a tombstone (a removed one) from a beacon.
+#[cfg(feature = "durable")]
+#[repr(u8)]
+enum RecordTag {
+    Challenge = 0,
+    ChallengeTombstone = 1,
+    Beacon = 2,
+}
+
+/// Durable `ChallengeStore` backed by a single append-only log file plus an
+/// in-memory index/cache rebuilt from it at `open()` time. Reads are served
+/// from the cache; writes append a record to the log and update the cache
+/// before returning, so a crash immediately after a successful write still
+/// has that write on disk.
+#[cfg(feature = "durable")]
+pub struct FileChallengeStore {
+    log: Mutex<std::fs::File>,
+    log_path: std::path::PathBuf,
+    challenges: Mutex<HashMap<String, StorageChallenge>>,
+    beacons: Mutex<HashMap<String, u64>>,
+}
+
+#[cfg(feature = "durable")]
+impl FileChallengeStore {
+    /// Open (creating if necessary) the log at `path` and replay it to
+    /// rebuild the in-memory cache. Call once at startup.
+    pub fn open(path: impl AsRef<std::path::Path>) -> io::Result<Self> {
+        use std::io::{Read, Seek, SeekFrom};
+
+        let log_path = path.as_ref().to_path_buf();
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .read(true)
+            .append(true)
+            .open(&log_path)?;
+
+        let mut contents = Vec::new();
+        file.seek(SeekFrom::Start(0))?;
+        file.read_to_end(&mut contents)?;
+
+        let mut challenges = HashMap::new();
+        let mut beacons = HashMap::new();
+        replay_log(&contents, &mut challenges, &mut beacons);
+
+        Ok(Self {
+            log: Mutex::new(file),
+            log_path,
+            challenges: Mutex::new(challenges),
+            beacons: Mutex::new(beacons),
+        })
+    }
+
+    fn to_io_error(reason: String) -> StorageVerificationError {
+        StorageVerificationError::PersistenceError { reason }
+    }
+
+    async fn append(&self, tag: RecordTag, key: &str, value: &[u8], expiry: u64) -> Result<(), StorageVerificationError> {
+        use std::io::Write;
+
+        let mut record = Vec::with_capacity(1 + 4 + key.len() + 4 + value.len() + 8);
+        record.push(tag as u8);
+        record.extend_from_slice(&(key.len() as u32).to_le_bytes());
+        record.extend_from_slice(key.as_bytes());
+        record.extend_from_slice(&(value.len() as u32).to_le_bytes());
+        record.extend_from_slice(value);
+        record.extend_from_slice(&expiry.to_le_bytes());
+
+        let mut log = self.log.lock().await;
+        log.write_all(&record)
+            .and_then(|_| log.flush())
+            .map_err(|e| Self::to_io_error(e.to_string()))
+    }
+
+    /// Rewrite the log so it holds only what's currently in the cache,
+    /// dropping tombstones and anything already past its expiry. Run
+    /// opportunistically from `compact_expired` rather than on every write.
+    async fn rewrite_log(&self, now: u64) -> Result<(), StorageVerificationError> {
+        use std::io::Write;
+
+        let challenges = self.challenges.lock().await;
+        let beacons = self.beacons.lock().await;
+
+        let tmp_path = self.log_path.with_extension("compact.tmp");
+        let mut tmp = std::fs::File::create(&tmp_path).map_err(|e| Self::to_io_error(e.to_string()))?;
+
+        for challenge in challenges.values() {
+            if now >= challenge.expiry {
+                continue;
+            }
+            let value = serde_json::to_vec(challenge)
+                .map_err(|e| Self::to_io_error(format!("failed to encode challenge: {}", e)))?;
+            write_record(&mut tmp, RecordTag::Challenge, &challenge.id, &value, challenge.expiry)
+                .map_err(|e| Self::to_io_error(e.to_string()))?;
+        }
+        for (beacon, &expiry) in beacons.iter() {
+            if now >= expiry {
+                continue;
+            }
+            write_record(&mut tmp, RecordTag::Beacon, beacon, &[], expiry)
+                .map_err(|e| Self::to_io_error(e.to_string()))?;
+        }
+        tmp.flush().map_err(|e| Self::to_io_error(e.to_string()))?;
+        drop(tmp);
+
+        std::fs::rename(&tmp_path, &self.log_path).map_err(|e| Self::to_io_error(e.to_string()))?;
+
+        let reopened = std::fs::OpenOptions::new()
+            .create(true)
+            .read(true)
+            .append(true)
+            .open(&self.log_path)
+            .map_err(|e| Self::to_io_error(e.to_string()))?;
+        *self.log.lock().await = reopened;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "durable")]
+fn write_record(
+    file: &mut std::fs::File,
+    tag: RecordTag,
+    key: &str,
+    value: &[u8],
+    expiry: u64,
+) -> io::Result<()> {
+    use std::io::Write;
+    file.write_all(&[tag as u8])?;
+    file.write_all(&(key.len() as u32).to_le_bytes())?;
+    file.write_all(key.as_bytes())?;
+    file.write_all(&(value.len() as u32).to_le_bytes())?;
+    file.write_all(value)?;
+    file.write_all(&expiry.to_le_bytes())?;
+    Ok(())
+}
+
+/// Replays the log format written by `append`/`write_record`. A truncated
+/// trailing record (e.g. a crash mid-write) just stops the replay there
+/// rather than erroring, so a torn write loses at most its own record.
+#[cfg(feature = "durable")]
+fn replay_log(buf: &[u8], challenges: &mut HashMap<String, StorageChallenge>, beacons: &mut HashMap<String, u64>) {
+    let mut i = 0;
+    while i < buf.len() {
+        if i + 1 > buf.len() {
+            break;
+        }
+        let tag = buf[i];
+        i += 1;
+
+        if i + 4 > buf.len() {
+            break;
+        }
+        let key_len = u32::from_le_bytes(buf[i..i + 4].try_into().unwrap()) as usize;
+        i += 4;
+        if i + key_len > buf.len() {
+            break;
+        }
+        let key = String::from_utf8_lossy(&buf[i..i + key_len]).into_owned();
+        i += key_len;
+
+        if i + 4 > buf.len() {
+            break;
+        }
+        let value_len = u32::from_le_bytes(buf[i..i + 4].try_into().unwrap()) as usize;
+        i += 4;
+        if i + value_len > buf.len() {
+            break;
+        }
+        let value = &buf[i..i + value_len];
+        i += value_len;
+
+        if i + 8 > buf.len() {
+            break;
+        }
+        let expiry = u64::from_le_bytes(buf[i..i + 8].try_into().unwrap());
+        i += 8;
+
+        match tag {
+            t if t == RecordTag::Challenge as u8 => {
+                if let Ok(challenge) = serde_json::from_slice::<StorageChallenge>(value) {
+                    challenges.insert(key, challenge);
+                }
+            }
+            t if t == RecordTag::ChallengeTombstone as u8 => {
+                challenges.remove(&key);
+            }
+            t if t == RecordTag::Beacon as u8 => {
+                beacons.insert(key, expiry);
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(feature = "durable")]
+#[async_trait(?Send)]
+impl ChallengeStore for FileChallengeStore {
+    async fn put_challenge(&self, challenge: &StorageChallenge) -> Result<(), StorageVerificationError> {
+        let value = serde_json::to_vec(challenge)
+            .map_err(|e| Self::to_io_error(format!("failed to encode challenge: {}", e)))?;
+        self.append(RecordTag::Challenge, &challenge.id, &value, challenge.expiry)
+            .await?;
+        self.challenges
+            .lock()
+            .await
+            .insert(challenge.id.clone(), challenge.clone());
+        Ok(())
+    }
+
+    async fn get_challenge(&self, challenge_id: &str) -> Result<Option<StorageChallenge>, StorageVerificationError> {
+        Ok(self.challenges.lock().await.get(challenge_id).cloned())
+    }
+
+    async fn remove_challenge(&self, challenge_id: &str) -> Result<(), StorageVerificationError> {
+        self.append(RecordTag::ChallengeTombstone, challenge_id, &[], 0).await?;
+        self.challenges.lock().await.remove(challenge_id);
+        Ok(())
+    }
+
+    async fn put_beacon(&self, beacon: &str, expiry: u64) -> Result<(), StorageVerificationError> {
+        self.append(RecordTag::Beacon, beacon, &[], expiry).await?;
+        self.beacons.lock().await.insert(beacon.to_string(), expiry);
+        Ok(())
+    }
+
+    async fn has_beacon(&self, beacon: &str) -> Result<bool, StorageVerificationError> {
+        Ok(self.beacons.lock().await.contains_key(beacon))
+    }
+
+    async fn compact_expired(&self, now: u64) -> Result<(), StorageVerificationError> {
+        self.challenges.lock().await.retain(|_, c| now < c.expiry);
+        self.beacons.lock().await.retain(|_, &mut expiry| now < expiry);
+        self.rewrite_log(now).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_challenge(id: &str, expiry: u64) -> StorageChallenge {
+        StorageChallenge {
+            id: id.to_string(),
+            file_id: "file1".to_string(),
+            provider: "provider1".to_string(),
+            nonce: 1,
+            timestamp: 0,
+            expiry,
+            beacon: "beacon1".to_string(),
+            difficulty: 1,
+            expected_hash: "deadbeef".to_string(),
+            challenge_data: vec![0u8; 32],
+            sample_offset: 0,
+            sample_size: 512,
+            merkle_root: [0u8; 32],
+            merkle_chunk_count: 8,
+            merkle_leaf_indices: vec![0, 1, 2],
+        }
+    }
+
+    #[tokio::test]
+    async fn in_memory_store_round_trips_challenges_and_beacons() {
+        let store = InMemoryChallengeStore::new();
+        let challenge = sample_challenge("chall-1", 1_000);
+        store.put_challenge(&challenge).await.unwrap();
+        assert_eq!(store.get_challenge("chall-1").await.unwrap().unwrap().id, "chall-1");
+
+        store.put_beacon("beacon-1", 1_000).await.unwrap();
+        assert!(store.has_beacon("beacon-1").await.unwrap());
+
+        store.remove_challenge("chall-1").await.unwrap();
+        assert!(store.get_challenge("chall-1").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn in_memory_store_compacts_expired_entries() {
+        let store = InMemoryChallengeStore::new();
+        store.put_challenge(&sample_challenge("chall-1", 100)).await.unwrap();
+        store.put_beacon("beacon-1", 100).await.unwrap();
+
+        store.compact_expired(200).await.unwrap();
+
+        assert!(store.get_challenge("chall-1").await.unwrap().is_none());
+        assert!(!store.has_beacon("beacon-1").await.unwrap());
+    }
+
+    #[cfg(feature = "durable")]
+    #[tokio::test]
+    async fn file_store_survives_reopen() {
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("challenge_store_test_{}.log", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        {
+            let store = FileChallengeStore::open(&path).unwrap();
+            store.put_challenge(&sample_challenge("chall-1", 1_000)).await.unwrap();
+            store.put_beacon("beacon-1", 1_000).await.unwrap();
+        }
+
+        let reopened = FileChallengeStore::open(&path).unwrap();
+        assert_eq!(
+            reopened.get_challenge("chall-1").await.unwrap().unwrap().id,
+            "chall-1"
+        );
+        assert!(reopened.has_beacon("beacon-1").await.unwrap());
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[cfg(feature = "durable")]
+    #[tokio::test]
+    async fn file_store_tombstone_removes_after_reopen() {
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("challenge_store_test_tombstone_{}.log", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        {
+            let store = FileChallengeStore::open(&path).unwrap();
+            store.put_challenge(&sample_challenge("chall-1", 1_000)).await.unwrap();
+            store.remove_challenge("chall-1").await.unwrap();
+        }
+
+        let reopened = FileChallengeStore::open(&path).unwrap();
+        assert!(reopened.get_challenge("chall-1").await.unwrap().is_none());
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -4,6 +4,7 @@
 use std::time::{SystemTime, UNIX_EPOCH};
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::collections::VecDeque;
+use sha2::{Sha256, Digest};
 
 #[cfg(unix)]
 use std::fs::File;
@@ -118,59 +119,143 @@ impl EntropyCollector {
         Ok(())
     }
 
-    /// Extract entropy from Bitcoin block headers
-    fn extract_block_entropy(&mut self, headers: &[Vec<u8>]) -> [u8; 32] {
-        use std::collections::hash_map::DefaultHasher;
-        use std::hash::{Hash, Hasher};
-
-        let mut combined_entropy = [0u8; 32];
-        
+    /// Extract entropy from Bitcoin block headers.
+    ///
+    /// Only headers carrying real proof-of-work contribute entropy: each
+    /// header's chain linkage and PoW target are validated first, so an
+    /// attacker cannot steer the mixed entropy with fabricated headers.
+    /// Rejects the whole batch with `EntropyError::InvalidBlockHeaders` if
+    /// any header fails validation, leaving `last_block_entropy` unchanged
+    /// so that no unverified data is mixed in; falling back is up to the
+    /// caller.
+    fn extract_block_entropy(&mut self, headers: &[Vec<u8>]) -> Result<[u8; 32], EntropyError> {
         if headers.is_empty() {
             // Use last known block entropy if no headers provided
-            return self.last_block_entropy;
+            return Ok(self.last_block_entropy);
         }
 
-        let mut hasher = DefaultHasher::new();
-        
+        validate_header_chain(headers)?;
+
+        // SHA-256d over the validated headers binds the mixed entropy to
+        // verified chain work instead of a non-cryptographic DefaultHasher.
+        let mut hasher = Sha256::new();
         for header in headers {
-            // Hash each header
-            header.hash(&mut hasher);
-            
-            // Extract nonce and timestamp fields (if present in 80-byte header)
-            if header.len() >= 80 {
-                // Bitcoin header structure: nonce at bytes 76-80, timestamp at 68-72
-                let nonce = &header[76..80];
-                let timestamp = &header[68..72];
-                
-                nonce.hash(&mut hasher);
-                timestamp.hash(&mut hasher);
-            }
+            hasher.update(header);
         }
-        
+
         // Add current timing jitter
         let jitter = self.collect_jitter();
-        jitter.hash(&mut hasher);
-        
+        hasher.update(jitter.to_le_bytes());
+
         // Add global jitter state
         let global_jitter = JITTER_COUNTER.load(Ordering::Relaxed);
-        global_jitter.hash(&mut hasher);
-        
-        let hash_result = hasher.finish();
-        let hash_bytes = hash_result.to_le_bytes();
-        
-        // Expand hash to 32 bytes using a simple key derivation
-        for i in 0..32 {
-            combined_entropy[i] = hash_bytes[i % 8] ^ (i as u8);
-        }
-        
+        hasher.update(global_jitter.to_le_bytes());
+
+        let first_pass = hasher.finalize();
+        let digest = Sha256::digest(first_pass);
+
+        let mut combined_entropy = [0u8; 32];
+        combined_entropy.copy_from_slice(&digest);
+
         // XOR with previous block entropy for accumulation
         for i in 0..32 {
             combined_entropy[i] ^= self.last_block_entropy[i];
         }
-        
+
         self.last_block_entropy = combined_entropy;
-        combined_entropy
+        Ok(combined_entropy)
+    }
+}
+
+/// Double-SHA256, as used throughout Bitcoin for header and transaction hashing.
+pub(crate) fn double_sha256(data: &[u8]) -> [u8; 32] {
+    let first_pass = Sha256::digest(data);
+    let second_pass = Sha256::digest(first_pass);
+    let mut out = [0u8; 32];
+    out.copy_from_slice(&second_pass);
+    out
+}
+
+/// Expand a compact `nBits` field into a 256-bit PoW target, represented as
+/// a little-endian byte array (index 0 is the least-significant byte),
+/// matching how Bitcoin Core's `arith_uint256::SetCompact` represents it.
+///
+/// `nBits` packs exponent (high byte) and mantissa (low 3 bytes); the
+/// mantissa's sign bit (bit 23) must be clear, and the resulting target must
+/// fit in 256 bits.
+fn expand_compact_target(n_bits: u32) -> Result<[u8; 32], EntropyError> {
+    let exponent = (n_bits >> 24) as i32;
+    let mantissa = n_bits & 0x00ff_ffff;
+
+    if mantissa & 0x0080_0000 != 0 {
+        return Err(EntropyError::InvalidBlockHeaders); // negative target rejected
+    }
+
+    let mantissa_bytes = mantissa.to_be_bytes(); // [0, m_hi, m_mid, m_lo]
+    let mut target = [0u8; 32];
+
+    for (i, &byte) in mantissa_bytes[1..4].iter().enumerate() {
+        // i = 0 is the mantissa's most significant byte, i = 2 the least.
+        let offset_from_lsb = (exponent - 3) + (2 - i as i32);
+        if offset_from_lsb < 0 {
+            continue; // shifted out below the bottom, contributes nothing
+        }
+        if offset_from_lsb >= 32 {
+            return Err(EntropyError::InvalidBlockHeaders); // overflow beyond 256 bits
+        }
+        target[offset_from_lsb as usize] = byte;
+    }
+
+    Ok(target)
+}
+
+/// Compares two 256-bit values stored as little-endian byte arrays
+/// (index 0 = least significant byte).
+fn compare_le_256(a: &[u8; 32], b: &[u8; 32]) -> std::cmp::Ordering {
+    for i in (0..32).rev() {
+        match a[i].cmp(&b[i]) {
+            std::cmp::Ordering::Equal => continue,
+            other => return other,
+        }
     }
+    std::cmp::Ordering::Equal
+}
+
+/// Validates the canonical 80-byte Bitcoin header layout for a slice of
+/// headers: chain linkage (`prev-hash` matches the double-SHA256 of the
+/// previous header in the slice) and genuine proof-of-work (`hash <= target`
+/// derived from the header's compact `nBits`).
+fn validate_header_chain(headers: &[Vec<u8>]) -> Result<(), EntropyError> {
+    let mut expected_prev_hash: Option<[u8; 32]> = None;
+
+    for header in headers {
+        if header.len() != 80 {
+            return Err(EntropyError::InvalidBlockHeaders);
+        }
+
+        let prev_hash_field = &header[4..36];
+        if let Some(expected) = expected_prev_hash {
+            if prev_hash_field != expected.as_slice() {
+                return Err(EntropyError::InvalidBlockHeaders);
+            }
+        }
+
+        let n_bits = u32::from_le_bytes(
+            header[72..76]
+                .try_into()
+                .map_err(|_| EntropyError::InvalidBlockHeaders)?,
+        );
+        let target = expand_compact_target(n_bits)?;
+
+        let header_hash = double_sha256(header);
+        if compare_le_256(&header_hash, &target) == std::cmp::Ordering::Greater {
+            return Err(EntropyError::InvalidBlockHeaders);
+        }
+
+        expected_prev_hash = Some(header_hash);
+    }
+
+    Ok(())
 }
 
 /// Generate fast, high-quality entropy (32 bytes)
@@ -208,10 +293,12 @@ pub fn hybrid_entropy(headers: &[Vec<u8>]) -> [u8; 32] {
     // Start with OS entropy
     let _ = collector.get_os_entropy(&mut output);
     
-    // Mix in blockchain entropy
-    let block_entropy = collector.extract_block_entropy(headers);
-    for i in 0..32 {
-        output[i] ^= block_entropy[i];
+    // Mix in blockchain entropy; an invalid batch is rejected and simply
+    // doesn't contribute rather than steering the output.
+    if let Ok(block_entropy) = collector.extract_block_entropy(headers) {
+        for i in 0..32 {
+            output[i] ^= block_entropy[i];
+        }
     }
     
     // Add final jitter layer
@@ -236,9 +323,12 @@ pub fn enterprise_entropy(headers: &[Vec<u8>], additional_data: &[u8]) -> [u8; 3
         // OS entropy with round-specific offset
         let _ = collector.get_os_entropy(&mut round_output);
         
-        // Blockchain entropy
-        let block_entropy = collector.extract_block_entropy(headers);
-        
+        // Blockchain entropy; fall back to the last known good value if this
+        // batch fails header validation rather than mixing in unverified data.
+        let block_entropy = collector
+            .extract_block_entropy(headers)
+            .unwrap_or(collector.last_block_entropy);
+
         // Additional data incorporation
         if !additional_data.is_empty() {
             use std::collections::hash_map::DefaultHasher;
@@ -330,4 +420,73 @@ mod tests {
         // Should not be all zeros (very unlikely)
         assert_ne!(buffer, [0u8; 16]);
     }
+
+    #[test]
+    fn test_expand_compact_target_genesis() {
+        // Genesis block's nBits (0x1d00ffff) should expand to the well-known
+        // 0x00000000ffff0000000000000000000000000000000000000000000000000000
+        // target (big-endian), which as a little-endian array has 0xff, 0xff
+        // at offsets 26 and 27 and zeros everywhere else.
+        let target = expand_compact_target(0x1d00ffff).unwrap();
+        let mut expected = [0u8; 32];
+        expected[26] = 0xff;
+        expected[27] = 0xff;
+        assert_eq!(target, expected);
+    }
+
+    #[test]
+    fn test_expand_compact_target_rejects_negative() {
+        // Mantissa sign bit set => negative target, not representable.
+        assert!(expand_compact_target(0x01800000).is_err());
+    }
+
+    #[test]
+    fn test_validate_header_chain_rejects_impossible_pow() {
+        // All-zero headers carry a zero nBits field, which expands to a
+        // zero target that no real hash can satisfy.
+        let headers = vec![vec![0u8; 80]];
+        assert!(validate_header_chain(&headers).is_err());
+    }
+
+    #[test]
+    fn test_validate_header_chain_rejects_broken_linkage() {
+        // A near-maximum target (0x207fffff) accepts about half of all hashes, so a
+        // mined header with a deliberately wrong prev-hash isolates the
+        // linkage check rather than the PoW check.
+        let target = expand_compact_target(0x207fffff).unwrap();
+        let header = mine_header([0u8; 32], 0x207fffff, &target);
+
+        let mut second = header.clone();
+        second[4..36].copy_from_slice(&[0xaa; 32]); // wrong prev-hash
+
+        assert!(validate_header_chain(&[header, second]).is_err());
+    }
+
+    #[test]
+    fn test_validate_header_chain_accepts_linked_headers() {
+        let target = expand_compact_target(0x207fffff).unwrap();
+        let first = mine_header([0u8; 32], 0x207fffff, &target);
+        let first_hash = double_sha256(&first);
+        let second = mine_header(first_hash, 0x207fffff, &target);
+
+        assert!(validate_header_chain(&[first, second]).is_ok());
+    }
+
+    /// Builds an 80-byte header with the given prev-hash and nBits, trying
+    /// nonces until the header's hash satisfies the target.
+    fn mine_header(prev_hash: [u8; 32], n_bits: u32, target: &[u8; 32]) -> Vec<u8> {
+        for nonce in 0u32..100_000 {
+            let mut header = vec![0u8; 80];
+            header[0..4].copy_from_slice(&1u32.to_le_bytes());
+            header[4..36].copy_from_slice(&prev_hash);
+            header[72..76].copy_from_slice(&n_bits.to_le_bytes());
+            header[76..80].copy_from_slice(&nonce.to_le_bytes());
+
+            let hash = double_sha256(&header);
+            if compare_le_256(&hash, target) != std::cmp::Ordering::Greater {
+                return header;
+            }
+        }
+        panic!("failed to mine a header satisfying the test target");
+    }
 }
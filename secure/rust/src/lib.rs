@@ -1,7 +1,6 @@
 // SPDX-License-Identifier: MIT
 // BitcoinCab.inc - SecureBuffer core with thread-safety and production hardening
 
-use std::alloc::{alloc, dealloc, Layout};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::io;
 use std::ffi::{CStr, c_char};
@@ -9,14 +8,33 @@ use std::time::{SystemTime, UNIX_EPOCH};
 use thiserror::Error;
 // Import the bloom filter module and its traits
 pub mod bloom_filter;
-use bloom_filter::{BlockchainHash, TransactionId, UniversalBloomFilter, NetworkConfig, BloomConfig, BlockData};
+use bloom_filter::{BlockchainHash, TransactionId, UniversalBloomFilter, NetworkConfig, BloomConfig, BlockData, UniversalRollingBloomFilter, BloomFilterError};
+
+// MuHash3072 incremental multiset hash, used by the bloom filter's set_hash
+pub mod muhash;
 
 // Storage verification module (optional IPFS support)
 pub mod storage_verifier;
 
+// Pluggable storage fetch backends (in-memory, S3/Garage, IPFS gateways)
+pub mod storage_backend;
+
+// Durable challenge/beacon persistence (in-memory by default, file-backed
+// behind the `durable` feature)
+pub mod challenge_store;
+
+// Pluggable per-protocol verification backends
+pub mod protocol_verifier;
+
+// GCRA rate limiting with layered per-key/per-provider/global tiers
+pub mod rate_limiter;
+
 // Web server module for REST API
 pub mod web_server;
 
+// BIP-340 Schnorr signing for verification responses
+pub mod signing;
+
 #[cfg(unix)]
 extern crate libc;
 
@@ -26,9 +44,19 @@ extern crate winapi;
 // Entropy module for hybrid Bitcoin + OS + jitter randomness
 pub mod entropy;
 
+// Merkle inclusion-proof verification for storage challenges
+pub mod merkle;
+
 // SecureBuffer entropy integration
 pub mod securebuffer_entropy;
 
+// Zero-copy SecureBytes/SecureCursor views over a locked allocation
+pub mod securebuffer;
+
+// Pluggable-transport connection pool (circuit breaker, background
+// cleanup/metrics/health-check loops, transparent payload compression)
+pub mod secure_channel_pool;
+
 // High-performance Universal Bloom Filter
 
 mod memory {
@@ -128,6 +156,194 @@ mod memory {
             }
         }
     }
+
+    #[cfg(unix)]
+    fn page_size() -> usize {
+        let size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+        if size > 0 {
+            size as usize
+        } else {
+            4096
+        }
+    }
+
+    /// Reserve `capacity` rounded up to whole pages, with one inaccessible
+    /// guard page mapped immediately before and after the data region, so a
+    /// linear over/under-run off the buffer faults instead of reading or
+    /// corrupting whatever an adjacent heap allocation holds. Returns
+    /// `(mapping_base, mapping_len, data, committed_len)`: the true
+    /// reservation base/size (guard pages included) and the data pointer /
+    /// page-rounded length actually readable and writable.
+    #[cfg(unix)]
+    pub fn map_guarded(capacity: usize) -> Result<(*mut u8, usize, *mut u8, usize), io::Error> {
+        let page = page_size();
+        let committed_len = ((capacity + page - 1) / page) * page;
+        let mapping_len = committed_len + 2 * page;
+
+        unsafe {
+            // Map the whole reservation PROT_NONE first - the leading and
+            // trailing pages are never upgraded, so they stay guard pages
+            // for the life of the mapping.
+            let base = libc::mmap(
+                std::ptr::null_mut(),
+                mapping_len,
+                libc::PROT_NONE,
+                libc::MAP_PRIVATE | libc::MAP_ANONYMOUS,
+                -1,
+                0,
+            );
+            if base == libc::MAP_FAILED {
+                return Err(io::Error::last_os_error());
+            }
+            let base = base as *mut u8;
+            let data = base.add(page);
+
+            if libc::mprotect(data as *mut libc::c_void, committed_len, libc::PROT_READ | libc::PROT_WRITE) != 0 {
+                let err = io::Error::last_os_error();
+                libc::munmap(base as *mut libc::c_void, mapping_len);
+                return Err(err);
+            }
+
+            Ok((base, mapping_len, data, committed_len))
+        }
+    }
+
+    /// Re-seals the data region as inaccessible and releases the whole
+    /// reservation (guard pages included) in one call. The re-seal happens
+    /// first so nothing can observe stale contents through a dangling
+    /// reference in the window before `munmap` takes effect.
+    #[cfg(unix)]
+    pub fn unmap_guarded(mapping_base: *mut u8, mapping_len: usize, data: *mut u8, committed_len: usize) -> Result<(), io::Error> {
+        unsafe {
+            let _ = libc::mprotect(data as *mut libc::c_void, committed_len, libc::PROT_NONE);
+            if libc::munmap(mapping_base as *mut libc::c_void, mapping_len) == 0 {
+                Ok(())
+            } else {
+                Err(io::Error::last_os_error())
+            }
+        }
+    }
+
+    /// Best-effort exclusion of the data pages from core dumps, plus
+    /// `MADV_DONTFORK` so the secret isn't duplicated into a child process's
+    /// address space across `fork`. Returns whether dump exclusion was
+    /// applied.
+    #[cfg(target_os = "linux")]
+    pub fn mark_dontdump(ptr: *mut u8, len: usize) -> bool {
+        unsafe {
+            let excluded = libc::madvise(ptr as *mut libc::c_void, len, libc::MADV_DONTDUMP) == 0;
+            let _ = libc::madvise(ptr as *mut libc::c_void, len, libc::MADV_DONTFORK);
+            excluded
+        }
+    }
+
+    #[cfg(all(unix, not(target_os = "linux")))]
+    pub fn mark_dontdump(_ptr: *mut u8, _len: usize) -> bool {
+        // MADV_DONTDUMP has no equivalent outside Linux - core-dump
+        // exclusion isn't available here, so callers fall back to relying
+        // on explicit_bzero-on-drop instead.
+        false
+    }
+
+    #[cfg(windows)]
+    fn page_size() -> usize {
+        use std::mem::MaybeUninit;
+        unsafe {
+            let mut info = MaybeUninit::<winapi::um::sysinfoapi::SYSTEM_INFO>::zeroed();
+            winapi::um::sysinfoapi::GetSystemInfo(info.as_mut_ptr());
+            info.assume_init().dwPageSize as usize
+        }
+    }
+
+    #[cfg(windows)]
+    pub fn map_guarded(capacity: usize) -> Result<(*mut u8, usize, *mut u8, usize), io::Error> {
+        use winapi::um::memoryapi::{VirtualAlloc, VirtualFree};
+        use winapi::um::winnt::{MEM_COMMIT, MEM_RELEASE, MEM_RESERVE, PAGE_NOACCESS, PAGE_READWRITE};
+
+        let page = page_size();
+        let committed_len = ((capacity + page - 1) / page) * page;
+        let mapping_len = committed_len + 2 * page;
+
+        unsafe {
+            // Reserve the whole region uncommitted (costs no physical
+            // memory) and only commit the middle pages; the leading and
+            // trailing reserved-but-uncommitted pages fault on any access,
+            // acting as guard pages.
+            let base = VirtualAlloc(std::ptr::null_mut(), mapping_len, MEM_RESERVE, PAGE_NOACCESS);
+            if base.is_null() {
+                return Err(io::Error::last_os_error());
+            }
+            let base = base as *mut u8;
+            let data = base.add(page);
+
+            if VirtualAlloc(data as *mut _, committed_len, MEM_COMMIT, PAGE_READWRITE).is_null() {
+                let err = io::Error::last_os_error();
+                VirtualFree(base as *mut _, 0, MEM_RELEASE);
+                return Err(err);
+            }
+
+            Ok((base, mapping_len, data, committed_len))
+        }
+    }
+
+    #[cfg(windows)]
+    pub fn unmap_guarded(mapping_base: *mut u8, _mapping_len: usize, data: *mut u8, committed_len: usize) -> Result<(), io::Error> {
+        use winapi::um::memoryapi::{VirtualFree, VirtualProtect};
+        use winapi::um::winnt::{MEM_RELEASE, PAGE_NOACCESS};
+
+        unsafe {
+            let mut old_protect = 0u32;
+            let _ = VirtualProtect(data as *mut _, committed_len, PAGE_NOACCESS, &mut old_protect);
+            // VirtualFree(MEM_RELEASE) must be called with the original
+            // reservation's base address and size 0 - it releases the
+            // whole reservation (guard pages and data region together) in
+            // one call.
+            if VirtualFree(mapping_base as *mut _, 0, MEM_RELEASE) != 0 {
+                Ok(())
+            } else {
+                Err(io::Error::last_os_error())
+            }
+        }
+    }
+
+    #[cfg(windows)]
+    pub fn mark_dontdump(_ptr: *mut u8, _len: usize) -> bool {
+        // No VirtualAlloc-level equivalent of MADV_DONTDUMP; Windows
+        // minidump exclusion is a process-wide MiniDumpWriteDump callback,
+        // not something a single allocation can opt into.
+        false
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    pub fn map_guarded(capacity: usize) -> Result<(*mut u8, usize, *mut u8, usize), io::Error> {
+        // No portable guard-page primitive here - fall back to a plain
+        // allocation so the buffer still works, just without the
+        // fault-on-overrun protection `SecureBuffer::guard_pages()` reports
+        // on unix/windows.
+        use std::alloc::{alloc, Layout};
+        let layout = Layout::from_size_align(capacity, 32)
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "invalid layout"))?;
+        let data = unsafe { alloc(layout) };
+        if data.is_null() {
+            return Err(io::Error::new(io::ErrorKind::OutOfMemory, "allocation failed"));
+        }
+        Ok((data, capacity, data, capacity))
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    pub fn unmap_guarded(mapping_base: *mut u8, mapping_len: usize, _data: *mut u8, _committed_len: usize) -> Result<(), io::Error> {
+        use std::alloc::{dealloc, Layout};
+        unsafe {
+            let layout = Layout::from_size_align_unchecked(mapping_len, 32);
+            dealloc(mapping_base, layout);
+        }
+        Ok(())
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    pub fn mark_dontdump(_ptr: *mut u8, _len: usize) -> bool {
+        false
+    }
 }
 
 #[derive(Error, Debug)]
@@ -144,13 +360,24 @@ pub enum SecureBufferError {
     InvalidState,
 }
 
-/// Thread-safe secure buffer with memory locking and hardened zeroization
+/// Thread-safe secure buffer with memory locking and hardened zeroization.
+///
+/// `data`/`capacity` stay the user-visible view (exactly the bytes the
+/// caller asked for); `mapping_base`/`mapping_len` track the true
+/// reservation underneath, which is page-rounded and flanked by one
+/// inaccessible guard page on either side so a linear over/under-run off
+/// `data` faults instead of reading or corrupting an adjacent allocation.
 pub struct SecureBuffer {
+    mapping_base: *mut u8,
+    mapping_len: usize,
     data: *mut u8,
     capacity: usize,
+    committed_len: usize,
     length: usize,
     is_valid: AtomicBool,
     is_locked: AtomicBool,
+    has_guard_pages: bool,
+    is_dontdump: bool,
 }
 
 impl SecureBuffer {
@@ -159,30 +386,36 @@ impl SecureBuffer {
         if capacity == 0 {
             return Err("Capacity must be greater than 0".to_string());
         }
-        
-        // Use aligned allocation for better security and performance
-        let layout = Layout::from_size_align(capacity, 32)
-            .map_err(|_| "Invalid layout for allocation".to_string())?;
-        
-        let data = unsafe { alloc(layout) };
-        if data.is_null() {
-            return Err("Failed to allocate memory".to_string());
-        }
+
+        // Guard-paged, page-aligned allocation in place of a plain
+        // alloc/Layout one - see `memory::map_guarded`.
+        let (mapping_base, mapping_len, data, committed_len) = memory::map_guarded(capacity)
+            .map_err(|_| "Failed to allocate memory".to_string())?;
+        let has_guard_pages = cfg!(any(unix, windows));
 
         // Immediately zero the allocated memory
         unsafe {
-            memory::explicit_bzero(data, capacity);
+            memory::explicit_bzero(data, committed_len);
         }
 
         // Attempt to lock memory (non-fatal if it fails)
-        let is_locked = memory::lock_memory(data, capacity).is_ok();
+        let is_locked = memory::lock_memory(data, committed_len).is_ok();
+
+        // Exclude the data pages from core dumps / fork() duplication;
+        // best-effort, non-fatal where the platform doesn't support it.
+        let is_dontdump = memory::mark_dontdump(data, committed_len);
 
         let buffer = SecureBuffer {
+            mapping_base,
+            mapping_len,
             data,
             capacity,
+            committed_len,
             length: 0,
             is_valid: AtomicBool::new(true),
             is_locked: AtomicBool::new(is_locked),
+            has_guard_pages,
+            is_dontdump,
         };
 
         Ok(buffer)
@@ -280,30 +513,48 @@ impl SecureBuffer {
         self.is_locked.load(Ordering::SeqCst)
     }
 
+    /// Whether this allocation's data pages are flanked by inaccessible
+    /// guard pages immediately before and after them. True on unix/windows;
+    /// false only on platforms with no guard-page primitive, where
+    /// `memory::map_guarded` falls back to a plain allocation.
+    pub fn guard_pages(&self) -> bool {
+        self.has_guard_pages
+    }
+
+    /// Whether the data pages were successfully excluded from core dumps
+    /// (and fork() duplication, where supported) - best-effort, since not
+    /// every platform/kernel exposes the primitive this relies on.
+    pub fn is_dontdump(&self) -> bool {
+        self.is_dontdump
+    }
+
     /// Safely destroy the buffer, ensuring all data is zeroed
     pub fn destroy(&mut self) {
         // Mark as invalid first to prevent concurrent access
         self.is_valid.store(false, Ordering::SeqCst);
-        
+
         if !self.data.is_null() {
             unsafe {
                 // Multiple-pass zeroization for extra security
-                memory::explicit_bzero(self.data, self.capacity);
-                memory::explicit_bzero(self.data, self.capacity);
-                
+                memory::explicit_bzero(self.data, self.committed_len);
+                memory::explicit_bzero(self.data, self.committed_len);
+
                 // Unlock memory if it was locked (prevent double-unlock)
                 if self.is_locked.swap(false, Ordering::SeqCst) {
-                    let _ = memory::unlock_memory(self.data, self.capacity);
+                    let _ = memory::unlock_memory(self.data, self.committed_len);
                 }
-                
-                // Deallocate
-                let layout = Layout::from_size_align_unchecked(self.capacity, 32);
-                dealloc(self.data, layout);
+
+                // Re-marks the data region as inaccessible and releases the
+                // whole reservation - guard pages included - in one call.
+                let _ = memory::unmap_guarded(self.mapping_base, self.mapping_len, self.data, self.committed_len);
             }
-            
+
             // Clear pointers and sizes
+            self.mapping_base = std::ptr::null_mut();
+            self.mapping_len = 0;
             self.data = std::ptr::null_mut();
             self.capacity = 0;
+            self.committed_len = 0;
             self.length = 0;
         }
     }
@@ -484,6 +735,25 @@ mod tests {
         let large_data = vec![0u8; 20];
         assert!(buffer.write(&large_data).is_err());
     }
+
+    #[test]
+    fn test_guard_pages_are_reported() {
+        let buffer = SecureBuffer::new(1024).unwrap();
+        assert!(buffer.guard_pages());
+    }
+
+    #[test]
+    fn test_destroy_is_idempotent_with_guarded_allocation() {
+        let mut buffer = SecureBuffer::new(64).unwrap();
+        buffer.write(b"secret").unwrap();
+
+        buffer.destroy();
+        assert!(!buffer.is_valid());
+
+        // Must not double-unmap/double-unlock the underlying mapping.
+        buffer.destroy();
+        assert!(!buffer.is_valid());
+    }
 }
 
 // === Universal Bloom Filter FFI Bindings ===
@@ -506,6 +776,8 @@ pub enum UniversalBloomFilterError {
     ConcurrencyError = -6,
     NullPointer = -7,
     InvalidSize = -8,
+    Truncated = -9,
+    ChecksumMismatch = -10,
 }
 
 /// Create new Universal Bloom Filter with custom configuration
@@ -785,6 +1057,156 @@ pub extern "C" fn universal_bloom_filter_false_positive_rate(filter: UniversalBl
     filter_ref.false_positive_rate()
 }
 
+/// Serialize the filter's parameters, bit array, and aging state into
+/// `out_buf` so a long-lived service can persist a warm filter across
+/// restarts. Follows the query-then-fill convention: call once with
+/// `out_buf` null (or `*out_len` smaller than required) to learn the
+/// required size via `*out_len`, then call again with a buffer that large.
+#[no_mangle]
+pub extern "C" fn universal_bloom_filter_serialize(
+    filter: UniversalBloomFilterHandle,
+    out_buf: *mut u8,
+    out_len: *mut usize,
+) -> c_int {
+    if filter.is_null() || out_len.is_null() {
+        return UniversalBloomFilterError::NullPointer as c_int;
+    }
+
+    let filter_ref = unsafe { &*(filter as *const UniversalBloomFilter) };
+    let blob = match filter_ref.serialize() {
+        Ok(blob) => blob,
+        Err(_) => return UniversalBloomFilterError::MemoryError as c_int,
+    };
+
+    let required = blob.len();
+    let capacity = unsafe { *out_len };
+    if out_buf.is_null() || capacity < required {
+        unsafe { *out_len = required };
+        return UniversalBloomFilterError::InvalidSize as c_int;
+    }
+
+    let out_slice = unsafe { std::slice::from_raw_parts_mut(out_buf, required) };
+    out_slice.copy_from_slice(&blob);
+    unsafe { *out_len = required };
+    UniversalBloomFilterError::Success as c_int
+}
+
+/// Rebuild a filter from a blob produced by [`universal_bloom_filter_serialize`].
+/// Returns a null handle on failure and, if `out_error` is non-null, writes a
+/// [`UniversalBloomFilterError`] distinguishing a truncated blob
+/// (`Truncated`) from a corrupt one (`ChecksumMismatch`) instead of handing
+/// back a filter that looks valid but silently lost entries.
+#[no_mangle]
+pub extern "C" fn universal_bloom_filter_deserialize(
+    bytes: *const u8,
+    len: usize,
+    out_error: *mut c_int,
+) -> UniversalBloomFilterHandle {
+    let set_error = |code: UniversalBloomFilterError| {
+        if !out_error.is_null() {
+            unsafe { *out_error = code as c_int };
+        }
+    };
+
+    if bytes.is_null() || len == 0 {
+        set_error(UniversalBloomFilterError::NullPointer);
+        return std::ptr::null_mut();
+    }
+
+    let data = unsafe { std::slice::from_raw_parts(bytes, len) };
+    match UniversalBloomFilter::deserialize(data) {
+        Ok(filter) => {
+            set_error(UniversalBloomFilterError::Success);
+            Box::into_raw(Box::new(filter)) as UniversalBloomFilterHandle
+        }
+        Err(BloomFilterError::Truncated) => {
+            set_error(UniversalBloomFilterError::Truncated);
+            std::ptr::null_mut()
+        }
+        Err(BloomFilterError::ChecksumMismatch) => {
+            set_error(UniversalBloomFilterError::ChecksumMismatch);
+            std::ptr::null_mut()
+        }
+        Err(_) => {
+            set_error(UniversalBloomFilterError::InvalidConfiguration);
+            std::ptr::null_mut()
+        }
+    }
+}
+
+/// Empirically measure the false positive rate by probing `trials`
+/// deterministic, known-absent keys derived from `seed` and writing the
+/// observed hit ratio into `out_rate`. Complements
+/// `universal_bloom_filter_false_positive_rate`'s theoretical estimate,
+/// which diverges once aging/cleanup has partially cleared the filter or
+/// the load factor exceeds the design point.
+#[no_mangle]
+pub extern "C" fn universal_bloom_filter_measured_false_positive_rate(
+    filter: UniversalBloomFilterHandle,
+    trials: u64,
+    seed: u64,
+    out_rate: *mut c_double,
+) -> c_int {
+    if filter.is_null() || out_rate.is_null() {
+        return UniversalBloomFilterError::NullPointer as c_int;
+    }
+
+    let filter_ref = unsafe { &*(filter as *const UniversalBloomFilter) };
+    match filter_ref.measured_false_positive_rate(trials, seed) {
+        Ok(rate) => {
+            unsafe { *out_rate = rate };
+            UniversalBloomFilterError::Success as c_int
+        }
+        Err(BloomFilterError::InvalidInput(_)) => UniversalBloomFilterError::InvalidInput as c_int,
+        Err(_) => UniversalBloomFilterError::MemoryError as c_int,
+    }
+}
+
+/// Write the fraction of set bits into `out_fill_ratio` - the load factor
+/// that drives the gap between the theoretical and measured false positive
+/// rates, so operators can detect a filter that needs `cleanup` or resizing.
+#[no_mangle]
+pub extern "C" fn universal_bloom_filter_saturation(
+    filter: UniversalBloomFilterHandle,
+    out_fill_ratio: *mut c_double,
+) -> c_int {
+    if filter.is_null() || out_fill_ratio.is_null() {
+        return UniversalBloomFilterError::NullPointer as c_int;
+    }
+
+    let filter_ref = unsafe { &*(filter as *const UniversalBloomFilter) };
+    match filter_ref.saturation() {
+        Ok(ratio) => {
+            unsafe { *out_fill_ratio = ratio };
+            UniversalBloomFilterError::Success as c_int
+        }
+        Err(_) => UniversalBloomFilterError::MemoryError as c_int,
+    }
+}
+
+/// Write the filter's 32-byte MuHash set commitment into `out_32_bytes`, so
+/// two peers can compare digests instead of transmitting the whole filter
+/// to check whether they hold the same inserted set
+#[no_mangle]
+pub extern "C" fn universal_bloom_filter_set_hash(
+    filter: UniversalBloomFilterHandle,
+    out_32_bytes: *mut u8,
+) -> c_int {
+    if filter.is_null() || out_32_bytes.is_null() {
+        return UniversalBloomFilterError::NullPointer as c_int;
+    }
+
+    let filter_ref = unsafe { &*(filter as *const UniversalBloomFilter) };
+    match filter_ref.set_hash() {
+        Ok(digest) => {
+            let out_slice = unsafe { std::slice::from_raw_parts_mut(out_32_bytes, 32) };
+            out_slice.copy_from_slice(&digest);
+            UniversalBloomFilterError::Success as c_int
+        }
+        Err(_) => UniversalBloomFilterError::MemoryError as c_int,
+    }
+}
+
 /// Cleanup old entries to maintain performance
 #[no_mangle]
 pub extern "C" fn universal_bloom_filter_cleanup(filter: UniversalBloomFilterHandle) -> c_int {
@@ -813,3 +1235,89 @@ pub extern "C" fn universal_bloom_filter_auto_cleanup(filter: UniversalBloomFilt
         Err(_) => UniversalBloomFilterError::MemoryError as c_int,
     }
 }
+
+// === Universal Rolling Bloom Filter FFI Bindings ===
+// Generation-based variant of the above with no per-entry timestamps and no
+// full-scan cleanup; see `bloom_filter::UniversalRollingBloomFilter`.
+
+/// Opaque type for the rolling Bloom filter
+pub type UniversalRollingBloomFilterHandle = *mut c_void;
+
+/// Create a new rolling Bloom filter sized for `n_elements` live entries at
+/// false positive rate `fp_rate`
+#[no_mangle]
+pub extern "C" fn universal_rolling_bloom_filter_new(
+    n_elements: usize,
+    fp_rate: c_double,
+) -> UniversalRollingBloomFilterHandle {
+    match UniversalRollingBloomFilter::new(n_elements, fp_rate) {
+        Ok(filter) => Box::into_raw(Box::new(filter)) as UniversalRollingBloomFilterHandle,
+        Err(_) => std::ptr::null_mut(),
+    }
+}
+
+/// Destroy a rolling Bloom filter and free its memory
+#[no_mangle]
+pub extern "C" fn universal_rolling_bloom_filter_destroy(filter: UniversalRollingBloomFilterHandle) {
+    if !filter.is_null() {
+        unsafe {
+            let _ = Box::from_raw(filter as *mut UniversalRollingBloomFilter);
+        }
+    }
+}
+
+/// Insert `data` into the rolling Bloom filter, rotating its generation if
+/// the current cohort has filled up
+#[no_mangle]
+pub extern "C" fn universal_rolling_bloom_filter_insert(
+    filter: UniversalRollingBloomFilterHandle,
+    data: *const u8,
+    len: usize,
+) -> c_int {
+    if filter.is_null() || data.is_null() || len == 0 {
+        return UniversalBloomFilterError::NullPointer as c_int;
+    }
+
+    let filter_ref = unsafe { &*(filter as *const UniversalRollingBloomFilter) };
+    let data_slice = unsafe { std::slice::from_raw_parts(data, len) };
+
+    match filter_ref.insert(data_slice) {
+        Ok(_) => UniversalBloomFilterError::Success as c_int,
+        Err(_) => UniversalBloomFilterError::InvalidInput as c_int,
+    }
+}
+
+/// Check whether `data` is (probably) present in the rolling Bloom filter
+#[no_mangle]
+pub extern "C" fn universal_rolling_bloom_filter_contains(
+    filter: UniversalRollingBloomFilterHandle,
+    data: *const u8,
+    len: usize,
+) -> c_int {
+    if filter.is_null() || data.is_null() || len == 0 {
+        return UniversalBloomFilterError::NullPointer as c_int;
+    }
+
+    let filter_ref = unsafe { &*(filter as *const UniversalRollingBloomFilter) };
+    let data_slice = unsafe { std::slice::from_raw_parts(data, len) };
+
+    match filter_ref.contains(data_slice) {
+        Ok(true) => 1,
+        Ok(false) => 0,
+        Err(_) => UniversalBloomFilterError::InvalidInput as c_int,
+    }
+}
+
+/// Clear every cell and restart the rolling Bloom filter at generation 1
+#[no_mangle]
+pub extern "C" fn universal_rolling_bloom_filter_reset(filter: UniversalRollingBloomFilterHandle) -> c_int {
+    if filter.is_null() {
+        return UniversalBloomFilterError::NullPointer as c_int;
+    }
+
+    let filter_ref = unsafe { &*(filter as *const UniversalRollingBloomFilter) };
+    match filter_ref.reset() {
+        Ok(_) => UniversalBloomFilterError::Success as c_int,
+        Err(_) => UniversalBloomFilterError::MemoryError as c_int,
+    }
+}
@@ -0,0 +1,298 @@
+// SPDX-License-Identifier: MIT
+// Bitcoin Sprint - MuHash3072 incremental multiset hash
+//
+// Maintains a commitment to a *set* of byte strings that's O(1) to update
+// per insert/remove and order-independent, so two peers that inserted the
+// same elements in any order land on the same accumulator - exactly what's
+// needed to let `UniversalBloomFilter` expose a cheap 32-byte "do we hold
+// the same set" check without transmitting its whole bit array. This
+// follows the MuHash3072 construction Bitcoin Core uses for UTXO set
+// commitments: each element is mapped into Z*_p (p = 2^3072 - 1103717) by
+// expanding a SHA512 seed, and the accumulator is the product of all
+// mapped elements modulo p.
+
+use sha2::{Digest, Sha256, Sha512};
+
+/// 3072 bits, little-endian, one `u64` per limb.
+const LIMBS: usize = 48;
+
+type Limbs = [u64; LIMBS];
+
+/// `p = 2^3072 - 1103717`, the fixed modulus the accumulator lives in.
+fn prime() -> Limbs {
+    let mut p = [u64::MAX; LIMBS]; // 2^3072 - 1
+    sub_small(&mut p, 1103716); // (2^3072 - 1) - 1103716 == 2^3072 - 1103717
+    p
+}
+
+fn sub_small(a: &mut Limbs, mut small: u64) {
+    let mut borrow = 0u128;
+    for limb in a.iter_mut() {
+        let sub = small as u128 + borrow;
+        small = 0;
+        let (value, b) = (*limb as u128).overflowing_sub(sub);
+        if b {
+            *limb = (value.wrapping_add(1u128 << 64)) as u64;
+            borrow = 1;
+        } else {
+            *limb = value as u64;
+            borrow = 0;
+        }
+    }
+}
+
+fn cmp(a: &Limbs, b: &Limbs) -> std::cmp::Ordering {
+    cmp_slice(a, b)
+}
+
+fn cmp_slice(a: &[u64], b: &[u64]) -> std::cmp::Ordering {
+    for i in (0..a.len()).rev() {
+        match a[i].cmp(&b[i]) {
+            std::cmp::Ordering::Equal => continue,
+            other => return other,
+        }
+    }
+    std::cmp::Ordering::Equal
+}
+
+/// `a -= b`, assuming `a >= b`.
+fn sub_assign(a: &mut Limbs, b: &Limbs) {
+    sub_assign_slice(a, b)
+}
+
+fn sub_assign_slice(a: &mut [u64], b: &[u64]) {
+    let mut borrow = 0i128;
+    for i in 0..a.len() {
+        let diff = a[i] as i128 - b[i] as i128 - borrow;
+        if diff < 0 {
+            a[i] = (diff + (1i128 << 64)) as u64;
+            borrow = 1;
+        } else {
+            a[i] = diff as u64;
+            borrow = 0;
+        }
+    }
+}
+
+/// Multiplies two 3072-bit numbers into a 6144-bit wide product.
+fn mul_wide(a: &Limbs, b: &Limbs) -> [u64; 2 * LIMBS] {
+    let mut wide = [0u64; 2 * LIMBS];
+    for i in 0..LIMBS {
+        let mut carry = 0u128;
+        for j in 0..LIMBS {
+            let product = a[i] as u128 * b[j] as u128 + wide[i + j] as u128 + carry;
+            wide[i + j] = product as u64;
+            carry = product >> 64;
+        }
+        let mut k = i + LIMBS;
+        while carry > 0 {
+            let sum = wide[k] as u128 + carry;
+            wide[k] = sum as u64;
+            carry = sum >> 64;
+            k += 1;
+        }
+    }
+    wide
+}
+
+/// Reduces a 6144-bit wide value modulo `p` via schoolbook binary long
+/// division: shift the running remainder left one bit at a time, feeding in
+/// the next bit of `wide`, and subtract `p` whenever the remainder exceeds
+/// it. Not performance-critical - `p`'s width is fixed and this only runs
+/// once per inserted/removed element.
+///
+/// The running remainder is kept in `LIMBS + 1` limbs rather than `LIMBS`:
+/// it's only guaranteed `< p` *before* a step, so after doubling and adding
+/// a bit it can briefly need one more bit than `p` itself - a fixed
+/// `LIMBS`-wide shift would silently drop that overflow bit off the top
+/// limb whenever it was set.
+fn reduce_wide(wide: &[u64; 2 * LIMBS], p: &Limbs) -> Limbs {
+    let mut remainder = [0u64; LIMBS + 1];
+    let mut p_ext = [0u64; LIMBS + 1];
+    p_ext[..LIMBS].copy_from_slice(p);
+
+    for bit_index in (0..2 * LIMBS * 64).rev() {
+        let limb = bit_index / 64;
+        let bit = (wide[limb] >> (bit_index % 64)) & 1;
+
+        // remainder <<= 1, feeding in `bit`
+        let mut carry = bit;
+        for word in remainder.iter_mut() {
+            let shifted = (*word << 1) | carry;
+            carry = *word >> 63;
+            *word = shifted;
+        }
+
+        if cmp_slice(&remainder, &p_ext) != std::cmp::Ordering::Less {
+            sub_assign_slice(&mut remainder, &p_ext);
+        }
+    }
+
+    let mut result = [0u64; LIMBS];
+    result.copy_from_slice(&remainder[..LIMBS]);
+    result
+}
+
+fn mul_mod(a: &Limbs, b: &Limbs, p: &Limbs) -> Limbs {
+    reduce_wide(&mul_wide(a, b), p)
+}
+
+/// `a^(p-2) mod p`, the multiplicative inverse since `p` is prime
+/// (Fermat's little theorem).
+fn inv_mod(a: &Limbs, p: &Limbs) -> Limbs {
+    let mut exponent = *p;
+    sub_small(&mut exponent, 2);
+
+    let mut result = one();
+    let mut base = *a;
+    for bit_index in 0..LIMBS * 64 {
+        let limb = bit_index / 64;
+        let bit = (exponent[limb] >> (bit_index % 64)) & 1;
+        if bit == 1 {
+            result = mul_mod(&result, &base, p);
+        }
+        base = mul_mod(&base, &base, p);
+    }
+    result
+}
+
+fn one() -> Limbs {
+    let mut v = [0u64; LIMBS];
+    v[0] = 1;
+    v
+}
+
+fn to_bytes(a: &Limbs) -> [u8; LIMBS * 8] {
+    let mut bytes = [0u8; LIMBS * 8];
+    for (i, limb) in a.iter().enumerate() {
+        bytes[i * 8..i * 8 + 8].copy_from_slice(&limb.to_le_bytes());
+    }
+    bytes
+}
+
+/// Maps an arbitrary byte string into `Z*_p` by expanding `SHA512(data)`
+/// into 3072 bits (six SHA512 blocks keyed by a counter, since one SHA512
+/// digest is only 512 bits) and reducing the result mod `p` - always at
+/// most one subtraction, since the raw value is under `2^3072 < 2p`.
+fn hash_to_group(data: &[u8], p: &Limbs) -> Limbs {
+    let mut seed_hasher = Sha512::new();
+    seed_hasher.update(data);
+    let seed = seed_hasher.finalize();
+
+    let mut raw = [0u8; LIMBS * 8];
+    for (i, chunk) in raw.chunks_mut(64).enumerate() {
+        let mut hasher = Sha512::new();
+        hasher.update(seed);
+        hasher.update([i as u8]);
+        chunk.copy_from_slice(&hasher.finalize());
+    }
+
+    let mut limbs = [0u64; LIMBS];
+    for (i, limb) in limbs.iter_mut().enumerate() {
+        *limb = u64::from_le_bytes(raw[i * 8..i * 8 + 8].try_into().unwrap());
+    }
+
+    if cmp(&limbs, p) != std::cmp::Ordering::Less {
+        sub_assign(&mut limbs, p);
+    }
+    limbs
+}
+
+/// Incremental multiset hash: `state` is the product, mod `p`, of every
+/// inserted element's group representative. Order-independent by
+/// construction (multiplication commutes), so two accumulators built from
+/// the same set of elements - in any insertion order - compare equal.
+#[derive(Debug, Clone)]
+pub struct MuHashAccumulator {
+    state: Limbs,
+}
+
+impl MuHashAccumulator {
+    pub fn new() -> Self {
+        Self { state: one() }
+    }
+
+    pub fn insert(&mut self, data: &[u8]) {
+        let p = prime();
+        let element = hash_to_group(data, &p);
+        self.state = mul_mod(&self.state, &element, &p);
+    }
+
+    /// Removes a previously-inserted element by multiplying by its modular
+    /// inverse. Not currently called by `UniversalBloomFilter` (which has no
+    /// delete operation of its own), but kept available since the
+    /// accumulator itself supports it.
+    #[allow(dead_code)]
+    pub fn remove(&mut self, data: &[u8]) {
+        let p = prime();
+        let element = hash_to_group(data, &p);
+        let inverse = inv_mod(&element, &p);
+        self.state = mul_mod(&self.state, &inverse, &p);
+    }
+
+    /// Finalizes the accumulator into a fixed-size 32-byte digest.
+    pub fn digest(&self) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(to_bytes(&self.state));
+        hasher.finalize().into()
+    }
+}
+
+impl Default for MuHashAccumulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insertion_order_does_not_affect_the_digest() {
+        let mut forward = MuHashAccumulator::new();
+        forward.insert(b"alpha");
+        forward.insert(b"beta");
+
+        let mut backward = MuHashAccumulator::new();
+        backward.insert(b"beta");
+        backward.insert(b"alpha");
+
+        assert_eq!(forward.digest(), backward.digest());
+    }
+
+    #[test]
+    fn insert_then_remove_returns_to_the_empty_identity() {
+        let empty = MuHashAccumulator::new();
+
+        let mut acc = MuHashAccumulator::new();
+        acc.insert(b"alpha");
+        acc.insert(b"beta");
+        acc.remove(b"alpha");
+        acc.remove(b"beta");
+
+        assert_eq!(acc.digest(), empty.digest());
+    }
+
+    #[test]
+    fn inv_mod_is_the_true_multiplicative_inverse() {
+        let p = prime();
+        let element = hash_to_group(b"inv-check", &p);
+        let inverse = inv_mod(&element, &p);
+        assert_eq!(mul_mod(&element, &inverse, &p), one());
+    }
+
+    #[test]
+    fn digest_matches_a_known_answer_vector() {
+        // Computed independently (Python, `pow(e, p-2, p)` reference
+        // arithmetic over the same `hash_to_group`/modulus construction)
+        // rather than by running this code - a regression guard against
+        // an accidental change to the accumulator math, not a spec.
+        let mut acc = MuHashAccumulator::new();
+        acc.insert(b"muhash-known-answer-vector");
+        assert_eq!(
+            hex::encode(acc.digest()),
+            "2228dcee5db85542c634f62dda2aa5f6560b6d4642ebe429e7326be9f7a6f5e8"
+        );
+    }
+}
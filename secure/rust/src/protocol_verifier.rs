@@ -0,0 +1,180 @@
+// SPDX-License-Identifier: MIT
+// Bitcoin Sprint - Pluggable storage-protocol verification
+//
+// Generalizes the hardcoded `["ipfs","arweave","filecoin","bitcoin"]` list
+// and inline per-protocol scoring bonuses into a `ProtocolVerifier` trait, so
+// adding a storage backend means registering a new implementation instead of
+// editing `validate_request`/`calculate_verification_score` directly. Mirrors
+// how a generalized engine trait lets each consensus/backend implementation
+// plug in without the core knowing the concrete set.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::storage_verifier::{StorageChallenge, StorageProof, StorageVerificationError, StorageVerifier};
+
+/// A pluggable storage-protocol backend. Each implementation owns its own
+/// validation rules and scoring bonus; the registry dispatches to whichever
+/// one matches the request's `protocol` field.
+///
+/// `?Send`: `StorageVerifier::generate_challenge` holds a `ThreadRng` across
+/// an `.await` internally, same as it always has, so the returned future
+/// isn't `Send`. actix-web runs each worker's handlers on their own
+/// single-threaded arbiter and doesn't require that, unlike `tokio::spawn`.
+#[async_trait(?Send)]
+pub trait ProtocolVerifier: Send + Sync {
+    /// Protocol identifier as it appears in `VerifyRequest::protocol` (e.g. `"ipfs"`).
+    fn name(&self) -> &'static str;
+
+    /// Protocol-specific request validation beyond the generic checks
+    /// (non-empty file_id/provider, file_size bounds) already applied by the caller.
+    fn validate(&self, file_id: &str, provider: &str, file_size: u64) -> Result<(), String>;
+
+    async fn generate_challenge(&self, file_id: &str, provider: &str) -> Result<StorageChallenge, StorageVerificationError>;
+
+    async fn verify_proof(&self, proof: StorageProof) -> Result<bool, StorageVerificationError>;
+
+    /// Verification score contribution for this protocol (0.0-1.0), combined
+    /// by the caller with the base verified/file-size factors.
+    fn score(&self, verified: bool, file_size: u64) -> f64;
+}
+
+/// Shared scoring logic every backend below starts from: a base 0.6 if
+/// verified, plus a size factor, plus the protocol's own bonus.
+fn base_score(verified: bool, file_size: u64, protocol_bonus: f64) -> f64 {
+    let mut score = 0.0;
+    if verified {
+        score += 0.6;
+    }
+    score += protocol_bonus;
+
+    let size_factor = (file_size as f64).log10() / 10.0;
+    score += size_factor.min(0.15);
+
+    score.max(0.0).min(1.0)
+}
+
+macro_rules! storage_backed_verifier {
+    ($struct_name:ident, $protocol_name:expr, $bonus:expr) => {
+        pub struct $struct_name {
+            verifier: Arc<StorageVerifier>,
+        }
+
+        impl $struct_name {
+            pub fn new(verifier: Arc<StorageVerifier>) -> Self {
+                Self { verifier }
+            }
+        }
+
+        #[async_trait(?Send)]
+        impl ProtocolVerifier for $struct_name {
+            fn name(&self) -> &'static str {
+                $protocol_name
+            }
+
+            fn validate(&self, _file_id: &str, _provider: &str, _file_size: u64) -> Result<(), String> {
+                Ok(())
+            }
+
+            async fn generate_challenge(&self, file_id: &str, provider: &str) -> Result<StorageChallenge, StorageVerificationError> {
+                self.verifier.generate_challenge(file_id, provider).await
+            }
+
+            async fn verify_proof(&self, proof: StorageProof) -> Result<bool, StorageVerificationError> {
+                self.verifier.verify_proof(proof).await
+            }
+
+            fn score(&self, verified: bool, file_size: u64) -> f64 {
+                base_score(verified, file_size, $bonus)
+            }
+        }
+    };
+}
+
+storage_backed_verifier!(IpfsVerifier, "ipfs", 0.2);
+storage_backed_verifier!(ArweaveVerifier, "arweave", 0.25);
+storage_backed_verifier!(FilecoinVerifier, "filecoin", 0.3);
+storage_backed_verifier!(BitcoinVerifier, "bitcoin", 0.35);
+
+/// Registry of protocol backends, keyed by `ProtocolVerifier::name()`.
+/// `AppState` holds one of these instead of a bare `StorageVerifier`, so
+/// registering a new protocol at startup doesn't require touching the
+/// `/verify` handler.
+#[derive(Default)]
+pub struct ProtocolRegistry {
+    verifiers: HashMap<String, Box<dyn ProtocolVerifier>>,
+}
+
+impl ProtocolRegistry {
+    pub fn new() -> Self {
+        Self {
+            verifiers: HashMap::new(),
+        }
+    }
+
+    pub fn register(&mut self, verifier: Box<dyn ProtocolVerifier>) {
+        self.verifiers.insert(verifier.name().to_string(), verifier);
+    }
+
+    pub fn get(&self, protocol: &str) -> Option<&dyn ProtocolVerifier> {
+        self.verifiers.get(&protocol.to_lowercase()).map(|v| v.as_ref())
+    }
+
+    /// Builds the registry with the four backends this repo has always
+    /// supported, all sharing the same underlying `StorageVerifier` engine.
+    pub fn with_defaults(verifier: Arc<StorageVerifier>) -> Self {
+        let mut registry = Self::new();
+        registry.register(Box::new(IpfsVerifier::new(verifier.clone())));
+        registry.register(Box::new(ArweaveVerifier::new(verifier.clone())));
+        registry.register(Box::new(FilecoinVerifier::new(verifier.clone())));
+        registry.register(Box::new(BitcoinVerifier::new(verifier)));
+        registry
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage_verifier::TokenBucketConfig;
+
+    fn test_registry() -> ProtocolRegistry {
+        let verifier = Arc::new(StorageVerifier::with_config(TokenBucketConfig {
+            cap: 100,
+            window_secs: 60,
+            burst_pct: 1.0,
+            duration_overhead_secs: 0,
+            retries: 0,
+        }));
+        ProtocolRegistry::with_defaults(verifier)
+    }
+
+    #[test]
+    fn registry_dispatches_known_protocols() {
+        let registry = test_registry();
+        for protocol in ["ipfs", "arweave", "filecoin", "bitcoin"] {
+            assert_eq!(registry.get(protocol).unwrap().name(), protocol);
+        }
+    }
+
+    #[test]
+    fn registry_is_case_insensitive() {
+        let registry = test_registry();
+        assert!(registry.get("IPFS").is_some());
+    }
+
+    #[test]
+    fn registry_rejects_unknown_protocol() {
+        let registry = test_registry();
+        assert!(registry.get("swarm").is_none());
+    }
+
+    #[test]
+    fn scores_are_ordered_by_protocol_bonus() {
+        let registry = test_registry();
+        let ipfs = registry.get("ipfs").unwrap().score(true, 1024 * 1024);
+        let bitcoin = registry.get("bitcoin").unwrap().score(true, 1024 * 1024);
+        assert!(bitcoin > ipfs);
+    }
+}
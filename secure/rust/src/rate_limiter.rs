@@ -0,0 +1,231 @@
+// SPDX-License-Identifier: MIT
+// Bitcoin Sprint - GCRA (generic cell rate algorithm) rate limiting
+//
+// Replaces the fixed-window limiter (bursts allowed at window boundaries,
+// and an O(n) `retain` scan of every tracked key on every single request)
+// with GCRA: each key holds one `theoretical_arrival_time` (TAT) instant,
+// checked and updated in O(1). Idle keys are swept lazily, only once a
+// tier's map grows past a threshold, instead of on every call.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use crate::storage_verifier::RateLimitConfig;
+
+/// A request is allowed once every `emission_interval`, with up to
+/// `burst_tolerance` worth of slack so a key isn't penalized for bursting
+/// briefly as long as its long-run average stays under the rate.
+#[derive(Debug, Clone, Copy)]
+pub struct GcraLimit {
+    emission_interval: Duration,
+    burst_tolerance: Duration,
+}
+
+impl GcraLimit {
+    /// `max_requests` over `window`, with `burst` extra requests worth of
+    /// tolerance allowed in a tight cluster.
+    pub fn new(max_requests: u32, window: Duration, burst: u32) -> Self {
+        let max_requests = max_requests.max(1);
+        let emission_interval = window / max_requests;
+        Self {
+            emission_interval,
+            burst_tolerance: emission_interval * burst.max(1),
+        }
+    }
+}
+
+pub enum Decision {
+    Allow,
+    Deny { retry_after: Duration },
+}
+
+/// Per-key GCRA state: just the theoretical arrival time, not a sliding
+/// window of timestamps.
+#[derive(Debug, Clone, Copy)]
+struct GcraBucket {
+    tat: Option<Instant>,
+}
+
+impl GcraBucket {
+    fn new() -> Self {
+        Self { tat: None }
+    }
+
+    fn check(&mut self, now: Instant, limit: &GcraLimit) -> Decision {
+        let tat = self.tat.unwrap_or(now);
+        // Allowed iff now >= tat - burst_tolerance, rearranged to avoid
+        // subtracting a Duration from an Instant that might underflow.
+        let allow_until = now + limit.burst_tolerance;
+
+        if tat <= allow_until {
+            self.tat = Some(std::cmp::max(tat, now) + limit.emission_interval);
+            Decision::Allow
+        } else {
+            Decision::Deny {
+                retry_after: tat.duration_since(allow_until),
+            }
+        }
+    }
+}
+
+struct BucketTier {
+    limit: GcraLimit,
+    buckets: HashMap<String, (GcraBucket, Instant)>, // (state, last-touched)
+    idle_after: Duration,
+    sweep_threshold: usize,
+}
+
+impl BucketTier {
+    fn new(limit: GcraLimit, idle_after: Duration) -> Self {
+        Self {
+            limit,
+            buckets: HashMap::new(),
+            idle_after,
+            sweep_threshold: 10_000,
+        }
+    }
+
+    fn check(&mut self, key: &str, now: Instant) -> Decision {
+        // Sweep idle entries lazily: only pay the O(n) scan once the map has
+        // grown large enough that leaving it unbounded would matter, not on
+        // every request.
+        if self.buckets.len() > self.sweep_threshold {
+            let idle_after = self.idle_after;
+            self.buckets.retain(|_, (_, last_touched)| now.duration_since(*last_touched) < idle_after);
+        }
+
+        let entry = self.buckets.entry(key.to_string()).or_insert_with(|| (GcraBucket::new(), now));
+        entry.1 = now;
+        entry.0.check(now, &self.limit)
+    }
+}
+
+/// Three independent GCRA tiers: per `provider:file_id` key, per provider,
+/// and one global ceiling. A request is only admitted if all three agree;
+/// the longest `retry_after` among the tiers that denied is surfaced.
+pub struct TieredRateLimiter {
+    key_tier: BucketTier,
+    provider_tier: BucketTier,
+    global_tier: BucketTier,
+}
+
+impl TieredRateLimiter {
+    pub fn new(config: &RateLimitConfig) -> Self {
+        let minute = Duration::from_secs(60);
+        let hour = Duration::from_secs(3600);
+
+        // Per provider:file_id key: the existing per-minute budget.
+        let key_limit = GcraLimit::new(config.max_requests_per_minute, minute, 2);
+        // Per provider: the existing per-hour budget, aggregating across
+        // all of that provider's keys.
+        let provider_limit = GcraLimit::new(config.max_requests_per_hour, hour, 4);
+        // Global ceiling: a generous multiple of the per-provider hourly
+        // budget so one provider can't starve everyone else, without
+        // otherwise constraining normal multi-provider traffic.
+        let global_limit = GcraLimit::new(config.max_requests_per_hour.saturating_mul(50), hour, 8);
+
+        Self {
+            key_tier: BucketTier::new(key_limit, minute * 10),
+            provider_tier: BucketTier::new(provider_limit, hour * 2),
+            global_tier: BucketTier::new(global_limit, hour * 2),
+        }
+    }
+
+    pub fn check(&mut self, key: &str, provider: &str) -> Decision {
+        let now = Instant::now();
+
+        // Evaluate every tier (not short-circuiting) so a later-denying tier
+        // still records this attempt against its TAT.
+        let key_decision = self.key_tier.check(key, now);
+        let provider_decision = self.provider_tier.check(provider, now);
+        let global_decision = self.global_tier.check("__global__", now);
+
+        let mut retry_after = Duration::ZERO;
+        let mut denied = false;
+        for decision in [key_decision, provider_decision, global_decision] {
+            if let Decision::Deny { retry_after: r } = decision {
+                denied = true;
+                retry_after = retry_after.max(r);
+            }
+        }
+
+        if denied {
+            Decision::Deny { retry_after }
+        } else {
+            Decision::Allow
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(per_minute: u32, per_hour: u32) -> RateLimitConfig {
+        RateLimitConfig {
+            max_requests_per_minute: per_minute,
+            max_requests_per_hour: per_hour,
+            cleanup_interval_secs: 60,
+        }
+    }
+
+    #[test]
+    fn burst_tolerance_is_a_fraction_of_the_window_not_the_whole_window() {
+        let limit = GcraLimit::new(10, Duration::from_secs(10), 1);
+        assert!(limit.burst_tolerance > Duration::ZERO);
+        assert!(limit.burst_tolerance < Duration::from_secs(10));
+    }
+
+    #[test]
+    fn single_bucket_allows_burst_then_throttles() {
+        let limit = GcraLimit::new(2, Duration::from_millis(100), 1);
+        let mut bucket = GcraBucket::new();
+        let t0 = Instant::now();
+
+        assert!(matches!(bucket.check(t0, &limit), Decision::Allow));
+        // Immediate second request should still be within burst tolerance.
+        assert!(matches!(bucket.check(t0, &limit), Decision::Allow));
+        // A third request with no elapsed time should exceed tolerance.
+        assert!(matches!(bucket.check(t0, &limit), Decision::Deny { .. }));
+    }
+
+    #[test]
+    fn bucket_recovers_after_waiting_the_emission_interval() {
+        let limit = GcraLimit::new(1, Duration::from_millis(100), 1);
+        let mut bucket = GcraBucket::new();
+        let t0 = Instant::now();
+
+        assert!(matches!(bucket.check(t0, &limit), Decision::Allow));
+        let t1 = t0 + Duration::from_millis(200);
+        assert!(matches!(bucket.check(t1, &limit), Decision::Allow));
+    }
+
+    #[test]
+    fn tiered_limiter_denies_when_key_tier_is_exhausted() {
+        let mut limiter = TieredRateLimiter::new(&config(1, 1_000_000));
+        // Same key hammered immediately should eventually be denied by the
+        // key tier even though the provider/global tiers have plenty of room.
+        let denied = (0..10)
+            .map(|_| limiter.check("providerA:file1", "providerA"))
+            .any(|d| matches!(d, Decision::Deny { .. }));
+        assert!(denied);
+    }
+
+    #[test]
+    fn tiered_limiter_denies_when_provider_tier_is_exhausted_across_keys() {
+        let mut limiter = TieredRateLimiter::new(&config(1_000_000, 1));
+        // Different key each time, same provider: provider tier should
+        // still eventually deny since it aggregates across keys.
+        let denied = (0..10)
+            .map(|i| limiter.check(&format!("providerA:file{i}"), "providerA"))
+            .any(|d| matches!(d, Decision::Deny { .. }));
+        assert!(denied);
+    }
+
+    #[test]
+    fn different_providers_do_not_share_a_key_bucket() {
+        let mut limiter = TieredRateLimiter::new(&config(1, 1000));
+        assert!(matches!(limiter.check("providerA:file1", "providerA"), Decision::Allow));
+        assert!(matches!(limiter.check("providerB:file1", "providerB"), Decision::Allow));
+    }
+}
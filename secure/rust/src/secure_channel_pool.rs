@@ -0,0 +1,2211 @@
+// SPDX-License-Identifier: MIT
+// BitcoinCab.inc - SecureChannelPool: a pluggable-transport connection pool
+// with circuit breaking, background cleanup/metrics/health-check loops, and
+// transparent payload compression.
+
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use std::collections::HashMap;
+
+// Hand-rolled parking_lot-style locks.
+//
+// The real `parking_lot` crate would be the natural fit here (smaller,
+// faster guards, no poisoning on panic) but this file has no Cargo.toml
+// anywhere in its tree to add a dependency to, so this module reimplements
+// just the slice of its API the pool below needs: `read`/`write`/`lock`
+// that never propagate a poisoned lock, plus `try_read_for`/`try_write_for`
+// timed variants std's locks don't offer at all.
+mod fast_lock {
+    use std::sync::{self, MutexGuard, RwLockReadGuard, RwLockWriteGuard};
+    use std::time::{Duration, Instant};
+
+    pub struct RwLock<T>(sync::RwLock<T>);
+
+    impl<T> RwLock<T> {
+        pub fn new(value: T) -> Self {
+            RwLock(sync::RwLock::new(value))
+        }
+
+        /// Never returns a poison error: one reader/writer panicking
+        /// mid-update still leaves the data in *some* state, and for this
+        /// pool's counters and connection table that's preferable to
+        /// poisoning every future acquisition over it.
+        pub fn read(&self) -> RwLockReadGuard<'_, T> {
+            self.0.read().unwrap_or_else(sync::PoisonError::into_inner)
+        }
+
+        pub fn write(&self) -> RwLockWriteGuard<'_, T> {
+            self.0.write().unwrap_or_else(sync::PoisonError::into_inner)
+        }
+
+        /// Polls for up to `timeout` for a read guard instead of blocking
+        /// indefinitely.
+        pub fn try_read_for(&self, timeout: Duration) -> Option<RwLockReadGuard<'_, T>> {
+            let deadline = Instant::now() + timeout;
+            loop {
+                match self.0.try_read() {
+                    Ok(guard) => return Some(guard),
+                    Err(sync::TryLockError::Poisoned(poisoned)) => return Some(poisoned.into_inner()),
+                    Err(sync::TryLockError::WouldBlock) => {
+                        if Instant::now() >= deadline {
+                            return None;
+                        }
+                        std::thread::yield_now();
+                    }
+                }
+            }
+        }
+
+        /// Polls for up to `timeout` for a write guard instead of blocking
+        /// indefinitely.
+        pub fn try_write_for(&self, timeout: Duration) -> Option<RwLockWriteGuard<'_, T>> {
+            let deadline = Instant::now() + timeout;
+            loop {
+                match self.0.try_write() {
+                    Ok(guard) => return Some(guard),
+                    Err(sync::TryLockError::Poisoned(poisoned)) => return Some(poisoned.into_inner()),
+                    Err(sync::TryLockError::WouldBlock) => {
+                        if Instant::now() >= deadline {
+                            return None;
+                        }
+                        std::thread::yield_now();
+                    }
+                }
+            }
+        }
+    }
+
+    pub struct Mutex<T>(sync::Mutex<T>);
+
+    impl<T> Mutex<T> {
+        pub fn new(value: T) -> Self {
+            Mutex(sync::Mutex::new(value))
+        }
+
+        pub fn lock(&self) -> MutexGuard<'_, T> {
+            self.0.lock().unwrap_or_else(sync::PoisonError::into_inner)
+        }
+
+        pub fn try_lock_for(&self, timeout: Duration) -> Option<MutexGuard<'_, T>> {
+            let deadline = Instant::now() + timeout;
+            loop {
+                match self.0.try_lock() {
+                    Ok(guard) => return Some(guard),
+                    Err(sync::TryLockError::Poisoned(poisoned)) => return Some(poisoned.into_inner()),
+                    Err(sync::TryLockError::WouldBlock) => {
+                        if Instant::now() >= deadline {
+                            return None;
+                        }
+                        std::thread::yield_now();
+                    }
+                }
+            }
+        }
+    }
+}
+
+use fast_lock::{Mutex, RwLock};
+
+// Fast hasher for internal u64-keyed registries.
+//
+// `ahash::AHashMap`/`HashMap<u64, _, ahash::RandomState>` would be the
+// natural fit here - a SipHash replacement traded for raw speed - but this
+// tree has no manifest to add it to. Connection ids are generated
+// internally and never attacker-supplied, so SipHash's DoS resistance buys
+// nothing here and a cheaper finish is a straight win under churn. This
+// reimplements just enough of a `Hasher`/`BuildHasher` pair to drop in for
+// `HashMap`'s default, finalizing `u64` keys through SplitMix64's mixing
+// step.
+mod fast_hash {
+    use std::hash::{BuildHasher, Hasher};
+
+    #[derive(Default)]
+    pub struct FastIdHasher(u64);
+
+    impl Hasher for FastIdHasher {
+        fn finish(&self) -> u64 {
+            let mut z = self.0;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            z ^ (z >> 31)
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            // Only exercised if a non-u64-keyed map ever reuses this hasher;
+            // folds the bytes in rather than panicking, so it stays a valid
+            // general-purpose `Hasher` either way.
+            for chunk in bytes.chunks(8) {
+                let mut buf = [0u8; 8];
+                buf[..chunk.len()].copy_from_slice(chunk);
+                self.0 ^= u64::from_le_bytes(buf);
+                self.0 = self.0.wrapping_mul(0x9E37_79B9_7F4A_7C15);
+            }
+        }
+
+        fn write_u64(&mut self, i: u64) {
+            self.0 ^= i;
+        }
+    }
+
+    #[derive(Default, Clone, Copy)]
+    pub struct FastIdBuildHasher;
+
+    impl BuildHasher for FastIdBuildHasher {
+        type Hasher = FastIdHasher;
+        fn build_hasher(&self) -> FastIdHasher {
+            FastIdHasher::default()
+        }
+    }
+
+    pub type FastIdMap<V> = std::collections::HashMap<u64, V, FastIdBuildHasher>;
+}
+use fast_hash::FastIdMap;
+// === SecureChannelPool Configuration ===
+#[allow(dead_code)]
+#[derive(Clone)]
+struct PoolConfig {
+    max_connections: usize,
+    min_idle: usize,
+    max_lifetime: Duration,
+    max_latency_ms: u64,
+    cleanup_interval: Duration,
+    metrics_port: u16,
+    namespace: String,
+    circuit_breaker_failure_threshold: u64,
+    circuit_breaker_cooldown: Duration,
+    enterprise_features_enabled: bool,
+    audit_logging_enabled: bool,
+    compliance_mode: bool,
+    // `send_request` only compresses a payload once it's at least this
+    // many bytes - below that, the codec-id framing overhead isn't worth it.
+    compression_min_size_bytes: usize,
+    // Whether `get_connection` runs `ManageConnection::is_valid` against a
+    // reused idle connection before handing it out - off trades a stale
+    // connection slipping through for one less round-trip on the hot path.
+    test_on_checkout: bool,
+    // How many additional `ManageConnection::connect` attempts
+    // `connect_with_retries` makes (with backoff) after the first fails or
+    // after a stale reused connection is discarded on checkout.
+    max_checkout_retries: usize,
+}
+
+// === Connection Types ===
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+enum ConnectionState {
+    Idle,
+    Active,
+    Degraded,
+    Failed,
+    Reconnecting,
+}
+
+// A bounded, mergeable latency histogram with fixed logarithmic buckets:
+// bucket `i` covers `[base * ratio^i, base * ratio^(i+1))` milliseconds, so
+// ~160 buckets at base=1ms/ratio=1.1 cover latencies up to roughly 70
+// minutes. Unlike a raw `Vec<u64>` of samples, this is fixed-size
+// regardless of sample count and merges exactly via element-wise addition,
+// which is what lets `run_metrics` aggregate every connection's histogram
+// into one pool-wide quantile cheaply.
+const HISTOGRAM_BUCKET_COUNT: usize = 160;
+const HISTOGRAM_BASE_MS: f64 = 1.0;
+const HISTOGRAM_RATIO: f64 = 1.1;
+
+#[derive(Clone)]
+struct LatencyHistogram {
+    buckets: [u64; HISTOGRAM_BUCKET_COUNT],
+}
+
+impl LatencyHistogram {
+    fn new() -> Self {
+        LatencyHistogram {
+            buckets: [0; HISTOGRAM_BUCKET_COUNT],
+        }
+    }
+
+    fn bucket_index(latency_ms: f64) -> usize {
+        if latency_ms <= HISTOGRAM_BASE_MS {
+            return 0;
+        }
+        let index = ((latency_ms / HISTOGRAM_BASE_MS).ln() / HISTOGRAM_RATIO.ln()).floor();
+        (index as usize).min(HISTOGRAM_BUCKET_COUNT - 1)
+    }
+
+    fn record(&mut self, latency_ms: f64) {
+        self.buckets[Self::bucket_index(latency_ms)] += 1;
+    }
+
+    fn bucket_bounds(index: usize) -> (f64, f64) {
+        let lower = HISTOGRAM_BASE_MS * HISTOGRAM_RATIO.powi(index as i32);
+        let upper = HISTOGRAM_BASE_MS * HISTOGRAM_RATIO.powi(index as i32 + 1);
+        (lower, upper)
+    }
+
+    /// The geometric midpoint of whichever bucket holds the `q`-th
+    /// quantile (e.g. `q = 0.95` for p95), in milliseconds, or `0.0` if no
+    /// samples have been recorded.
+    fn quantile(&self, q: f64) -> f64 {
+        let total: u64 = self.buckets.iter().sum();
+        if total == 0 {
+            return 0.0;
+        }
+        let target = (q * total as f64).ceil() as u64;
+        let mut cumulative = 0u64;
+        for (index, &count) in self.buckets.iter().enumerate() {
+            cumulative += count;
+            if cumulative >= target {
+                let (lower, upper) = Self::bucket_bounds(index);
+                return (lower * upper).sqrt();
+            }
+        }
+        let (lower, upper) = Self::bucket_bounds(HISTOGRAM_BUCKET_COUNT - 1);
+        (lower * upper).sqrt()
+    }
+
+    fn merge(&self, other: &Self) -> Self {
+        let mut merged = self.clone();
+        for (dst, src) in merged.buckets.iter_mut().zip(other.buckets.iter()) {
+            *dst += src;
+        }
+        merged
+    }
+}
+
+#[allow(dead_code)]
+struct SecureConnection {
+    id: u64,
+    state: ConnectionState,
+    created_at: SystemTime,
+    last_activity: SystemTime,
+    bytes_sent: u64,
+    bytes_received: u64,
+    latency_histogram: LatencyHistogram,
+    security_context: SecurityContext,
+}
+
+#[allow(dead_code)]
+struct SecurityContext {
+    authenticated: bool,
+    session_key_rotated: SystemTime,
+    encryption_active: bool,
+    tls_version: String,
+    cipher_suite: String,
+}
+
+// === Circuit Breaker ===
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+enum CircuitBreakerState {
+    Closed,
+    HalfOpen,
+    Open,
+}
+
+#[allow(dead_code)]
+struct CircuitBreaker {
+    state: CircuitBreakerState,
+    failure_count: u64,
+    // Consecutive successes seen while `HalfOpen`; reaching
+    // `success_threshold` closes the breaker, any failure reopens it.
+    half_open_successes: u64,
+    failure_threshold: u64,
+    success_threshold: u64,
+    timeout: Duration,
+    last_failure_time: Option<SystemTime>,
+}
+
+impl CircuitBreaker {
+    /// Whether a caller may proceed right now. `Open` past `timeout` since
+    /// `last_failure_time` performs the `Open -> HalfOpen` transition and
+    /// lets the caller through as the trial request.
+    fn allow_request(&mut self) -> bool {
+        match self.state {
+            CircuitBreakerState::Closed | CircuitBreakerState::HalfOpen => true,
+            CircuitBreakerState::Open => {
+                let cooled_down = self
+                    .last_failure_time
+                    .and_then(|t| SystemTime::now().duration_since(t).ok())
+                    .map(|elapsed| elapsed >= self.timeout)
+                    .unwrap_or(false);
+                if cooled_down {
+                    self.state = CircuitBreakerState::HalfOpen;
+                    self.half_open_successes = 0;
+                    true
+                } else {
+                    false
+                }
+            }
+        }
+    }
+
+    /// Records a successful request. Resets the failure streak when
+    /// `Closed`; counts toward `success_threshold` when `HalfOpen`, closing
+    /// the breaker once reached.
+    fn record_success(&mut self) {
+        match self.state {
+            CircuitBreakerState::Closed => self.failure_count = 0,
+            CircuitBreakerState::HalfOpen => {
+                self.half_open_successes += 1;
+                if self.half_open_successes >= self.success_threshold {
+                    self.state = CircuitBreakerState::Closed;
+                    self.failure_count = 0;
+                    self.half_open_successes = 0;
+                }
+            }
+            CircuitBreakerState::Open => {}
+        }
+    }
+
+    /// Records a failed request, stamping `last_failure_time`. Trips
+    /// `Closed -> Open` once `failure_threshold` is crossed, or
+    /// immediately reopens from `HalfOpen` since the trial request failed.
+    fn record_failure(&mut self) {
+        self.last_failure_time = Some(SystemTime::now());
+        match self.state {
+            CircuitBreakerState::Closed => {
+                self.failure_count += 1;
+                if self.failure_count >= self.failure_threshold {
+                    self.state = CircuitBreakerState::Open;
+                }
+            }
+            CircuitBreakerState::HalfOpen => {
+                self.state = CircuitBreakerState::Open;
+                self.half_open_successes = 0;
+            }
+            CircuitBreakerState::Open => {}
+        }
+    }
+}
+
+// === Metrics and Monitoring ===
+#[allow(dead_code)]
+struct PoolMetrics {
+    total_connections: u64,
+    active_connections: u64,
+    idle_connections: u64,
+    total_requests: u64,
+    successful_requests: u64,
+    failed_requests: u64,
+    avg_latency_ms: f64,
+    p95_latency_ms: f64,
+    p99_latency_ms: f64,
+    throughput_ops_per_sec: f64,
+    error_rate_percent: f64,
+    uptime_seconds: u64,
+    // Cumulative pre-/post-framing byte counts behind the `compression_ratio`
+    // gauge `get_metrics_json` reports (original bytes / compressed bytes,
+    // where the "compressed" side includes uncompressed sends too).
+    total_original_bytes: u64,
+    total_compressed_bytes: u64,
+    compression_ratio: f64,
+    // Lifetime counters behind `get_prometheus_metrics`' per-endpoint
+    // `_total` series: a fresh `ManageConnection::connect` succeeding, an
+    // idle connection being handed out again instead, one aged out by
+    // `run_cleanup`, and `connect_with_retries` exhausting its retries.
+    connections_created: u64,
+    connections_reused: u64,
+    connections_evicted: u64,
+    connections_failed: u64,
+    // How many `get_connection` calls took longer than `max_latency_ms` to
+    // resolve (success or failure) - a starvation signal operators can
+    // alert on directly, rather than having to infer it from latency
+    // histograms.
+    checkout_threshold_exceeded: u64,
+}
+
+// === Background task lifecycle ===
+//
+// `run_cleanup`/`run_metrics`/`run_health_check` used to be standalone
+// `async fn`s nobody owned or called. They now live on `BackgroundContext`,
+// an owned bundle of the `Arc`-shared pool state cloned out of
+// `SecureChannelPool::start`, so each loop can be handed to
+// `tokio::task::spawn` as a `'static` future independent of the pool
+// reference that spawned it, and torn down deterministically by
+// `SecureChannelPool::shutdown`.
+struct BackgroundContext {
+    config: PoolConfig,
+    connections: Arc<RwLock<FastIdMap<SecureConnection>>>,
+    metrics: Arc<RwLock<PoolMetrics>>,
+    lifetime_at_eviction_histogram: Arc<RwLock<LatencyHistogram>>,
+    health_score: Arc<RwLock<f64>>,
+    is_running: Arc<RwLock<bool>>,
+    started_at: SystemTime,
+}
+
+impl BackgroundContext {
+    /// Waits for the next change on `shutdown_rx`, returning once `shutdown`
+    /// has actually been requested (`true`) rather than on every watch tick,
+    /// since a `watch` fires `changed()` for any send - not just the one
+    /// that matters to a loop that only cares about shutting down. Takes its
+    /// own receiver per loop (rather than one shared on `BackgroundContext`)
+    /// because `changed()` needs `&mut self` and `BackgroundContext` is
+    /// handed around as an `Arc`.
+    async fn wait_for_shutdown(shutdown_rx: &mut tokio::sync::watch::Receiver<bool>) {
+        loop {
+            if *shutdown_rx.borrow() {
+                return;
+            }
+            if shutdown_rx.changed().await.is_err() {
+                return;
+            }
+        }
+    }
+}
+
+impl BackgroundContext {
+    async fn run_cleanup(self: Arc<Self>, mut shutdown_rx: tokio::sync::watch::Receiver<bool>) {
+        let mut ticker = tokio::time::interval(self.config.cleanup_interval);
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => {
+                    if !*self.is_running.read() {
+                        break;
+                    }
+                    let max_lifetime = self.config.max_lifetime;
+                    let now = SystemTime::now();
+                    let mut evicted_lifetimes_ms = Vec::new();
+                    {
+                        let mut connections = self.connections.write();
+                        connections.retain(|_, conn| {
+                            let keep = matches!(conn.state, ConnectionState::Active)
+                                || now.duration_since(conn.created_at).map(|age| age < max_lifetime).unwrap_or(true);
+                            if !keep {
+                                if let Ok(age) = now.duration_since(conn.created_at) {
+                                    evicted_lifetimes_ms.push(age.as_secs_f64() * 1000.0);
+                                }
+                            }
+                            keep
+                        });
+                    }
+                    if !evicted_lifetimes_ms.is_empty() {
+                        self.metrics.write().connections_evicted += evicted_lifetimes_ms.len() as u64;
+                        let mut hist = self.lifetime_at_eviction_histogram.write();
+                        for age_ms in evicted_lifetimes_ms {
+                            hist.record(age_ms);
+                        }
+                    }
+                }
+                _ = Self::wait_for_shutdown(&mut shutdown_rx) => break,
+            }
+        }
+    }
+
+    /// `ready_tx`, when given, fires once after this loop's first tick -
+    /// this file's `run_metrics` only aggregates in-process rather than
+    /// binding a listener on `metrics_port` (no HTTP server crate in this
+    /// tree's manifest, same disclosed gap as `TcpTlsTransport` skipping
+    /// real TLS), so a completed first tick is the closest honest proxy
+    /// `start` has for "the metrics endpoint is accepting requests".
+    async fn run_metrics(
+        self: Arc<Self>,
+        mut shutdown_rx: tokio::sync::watch::Receiver<bool>,
+        mut ready_tx: Option<tokio::sync::oneshot::Sender<()>>,
+    ) {
+        let mut ticker = tokio::time::interval(self.config.cleanup_interval);
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => {
+                    if !*self.is_running.read() {
+                        break;
+                    }
+                    let (total, active, idle, aggregate_histogram) = {
+                        let connections = self.connections.read();
+                        let total = connections.len() as u64;
+                        let active = connections
+                            .values()
+                            .filter(|c| matches!(c.state, ConnectionState::Active))
+                            .count() as u64;
+                        let idle = connections
+                            .values()
+                            .filter(|c| matches!(c.state, ConnectionState::Idle))
+                            .count() as u64;
+                        let mut aggregate = LatencyHistogram::new();
+                        for conn in connections.values() {
+                            aggregate = aggregate.merge(&conn.latency_histogram);
+                        }
+                        (total, active, idle, aggregate)
+                    };
+                    let mut metrics = self.metrics.write();
+                    metrics.total_connections = total;
+                    metrics.active_connections = active;
+                    metrics.idle_connections = idle;
+                    metrics.p95_latency_ms = aggregate_histogram.quantile(0.95);
+                    metrics.p99_latency_ms = aggregate_histogram.quantile(0.99);
+                    metrics.uptime_seconds = SystemTime::now()
+                        .duration_since(self.started_at)
+                        .unwrap_or_default()
+                        .as_secs();
+                    drop(metrics);
+                    if let Some(tx) = ready_tx.take() {
+                        let _ = tx.send(());
+                    }
+                }
+                _ = Self::wait_for_shutdown(&mut shutdown_rx) => break,
+            }
+        }
+    }
+
+    async fn run_health_check(self: Arc<Self>, mut shutdown_rx: tokio::sync::watch::Receiver<bool>) {
+        let mut ticker = tokio::time::interval(self.config.cleanup_interval);
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => {
+                    if !*self.is_running.read() {
+                        break;
+                    }
+                    let error_rate = self.metrics.read().error_rate_percent;
+                    let mut health = self.health_score.write();
+                    *health = (100.0 - error_rate).clamp(0.0, 100.0);
+                }
+                _ = Self::wait_for_shutdown(&mut shutdown_rx) => break,
+            }
+        }
+    }
+}
+
+// === Pluggable Transport ===
+//
+// `SecureChannelPool` used to hardcode a single opaque `endpoint: String`
+// with `get_connection`/`send_request` stubbed out (`Ok(1)`/`Ok(vec![])`).
+// Making the pool generic over a `Transport` lets the same
+// circuit-breaker/metrics/health-check machinery above front different
+// backends - a raw relay socket, a JSON-RPC-over-HTTP Bitcoin node, or an
+// in-memory mock for tests - instead of being a stub, and makes
+// `get_connection`/`send_request`'s async signatures testable without a
+// live network.
+#[async_trait::async_trait]
+trait Transport: Send + Sync {
+    /// Whatever a concrete transport needs to remember per logical
+    /// connection - a socket, an HTTP client plus base URL, and so on.
+    type Conn: Send + Sync;
+
+    async fn connect(&self, endpoint: &str) -> Result<Self::Conn, Box<dyn std::error::Error>>;
+    async fn send(&self, conn: &Self::Conn, data: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>>;
+    async fn healthcheck(&self, conn: &Self::Conn) -> Result<(), Box<dyn std::error::Error>>;
+}
+
+/// Raw-socket transport for a TLS-fronted relay, and `SecureChannelPool`'s
+/// default. Actually negotiating TLS needs a crate (rustls/native-tls) this
+/// tree has no manifest to add, so `connect` opens a plain TCP socket and
+/// documents the gap rather than faking encryption - same disclosed-gap
+/// approach as `perform_ethereum_handshake`'s skipped ECIES step.
+#[allow(dead_code)]
+struct TcpTlsTransport;
+
+#[async_trait::async_trait]
+impl Transport for TcpTlsTransport {
+    type Conn = tokio::net::TcpStream;
+
+    async fn connect(&self, endpoint: &str) -> Result<Self::Conn, Box<dyn std::error::Error>> {
+        Ok(tokio::net::TcpStream::connect(endpoint).await?)
+    }
+
+    async fn send(&self, conn: &Self::Conn, data: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        conn.writable().await?;
+        conn.try_write(data)?;
+        conn.readable().await?;
+        let mut buf = vec![0u8; 4096];
+        let n = conn.try_read(&mut buf)?;
+        buf.truncate(n);
+        Ok(buf)
+    }
+
+    async fn healthcheck(&self, conn: &Self::Conn) -> Result<(), Box<dyn std::error::Error>> {
+        conn.writable().await?;
+        Ok(())
+    }
+}
+
+/// JSON-RPC-over-HTTP transport for a Bitcoin Core node, matching how
+/// `bitcoincore-rpc`-style clients talk to `bitcoind`: POST a
+/// `{"jsonrpc": "1.0", "method": ...}` body to the node's RPC port over
+/// HTTP basic auth and read back the JSON response body.
+#[allow(dead_code)]
+struct BitcoinRpcHttpTransport {
+    rpc_user: String,
+    rpc_password: String,
+}
+
+#[allow(dead_code)]
+struct BitcoinRpcConn {
+    client: reqwest::Client,
+    url: String,
+}
+
+#[async_trait::async_trait]
+impl Transport for BitcoinRpcHttpTransport {
+    type Conn = BitcoinRpcConn;
+
+    async fn connect(&self, endpoint: &str) -> Result<Self::Conn, Box<dyn std::error::Error>> {
+        Ok(BitcoinRpcConn {
+            client: reqwest::Client::new(),
+            url: format!("http://{}", endpoint),
+        })
+    }
+
+    async fn send(&self, conn: &Self::Conn, data: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        let response = conn
+            .client
+            .post(&conn.url)
+            .basic_auth(&self.rpc_user, Some(&self.rpc_password))
+            .header("content-type", "application/json")
+            .body(data.to_vec())
+            .send()
+            .await?;
+        Ok(response.bytes().await?.to_vec())
+    }
+
+    async fn healthcheck(&self, conn: &Self::Conn) -> Result<(), Box<dyn std::error::Error>> {
+        let probe = br#"{"jsonrpc":"1.0","id":"healthcheck","method":"getblockcount","params":[]}"#;
+        self.send(conn, probe).await?;
+        Ok(())
+    }
+}
+
+/// QUIC transport over one shared `quinn::Connection` per endpoint: unlike
+/// `TcpTlsTransport`/`BitcoinRpcHttpTransport`, `connect` doesn't dial a
+/// fresh socket per pooled "connection" - it opens a new independent
+/// bidirectional stream on the (lazily-dialed, cached) QUIC connection,
+/// since streams are what avoid per-connection handshakes and head-of-line
+/// blocking over QUIC. That also means `min_idle`/`max_connections` count
+/// streams rather than sockets for this transport, with no other pool code
+/// needing to change. Actually negotiating QUIC needs `quinn`/`rustls`,
+/// crates this tree has no manifest to add, so this documents the
+/// connection/stream/0-RTT shape rather than a working handshake - same
+/// disclosed-gap approach as `TcpTlsTransport` skipping real TLS.
+#[allow(dead_code)]
+struct QuicTransport {
+    // Cached across `connect` calls so every pooled "connection" reuses the
+    // one underlying QUIC connection instead of re-handshaking per stream.
+    connection: tokio::sync::Mutex<Option<quinn::Connection>>,
+    client_config: quinn::ClientConfig,
+    // Whether `connect` attempts 0-RTT resumption on the first stream of a
+    // fresh connection, falling back to a full handshake if the server
+    // doesn't accept it.
+    zero_rtt: bool,
+}
+
+impl QuicTransport {
+    /// Builds the transport with `keep_alive_interval` folded into
+    /// `client_config`'s `TransportConfig`, mirroring how
+    /// `BitcoinRpcHttpTransport` is built as a plain struct literal rather
+    /// than going through `PoolBuilder` - these knobs are transport-specific,
+    /// not generic pool configuration.
+    fn new(mut client_config: quinn::ClientConfig, zero_rtt: bool, keep_alive_interval: Duration) -> Self {
+        let mut transport_config = quinn::TransportConfig::default();
+        transport_config.keep_alive_interval(Some(keep_alive_interval));
+        client_config.transport_config(Arc::new(transport_config));
+        QuicTransport {
+            connection: tokio::sync::Mutex::new(None),
+            client_config,
+            zero_rtt,
+        }
+    }
+
+    /// RTT estimate, lost packet count, and congestion window off the
+    /// shared QUIC connection, if `connect` has dialed one yet - `None`
+    /// before the first stream is opened.
+    async fn connection_stats(&self) -> Option<QuicConnectionStats> {
+        let cached = self.connection.lock().await;
+        cached.as_ref().map(|connection| {
+            let stats = connection.stats();
+            QuicConnectionStats {
+                rtt_ms: stats.path.rtt.as_secs_f64() * 1000.0,
+                lost_packets: stats.path.lost_packets,
+                congestion_window: stats.path.cwnd,
+            }
+        })
+    }
+}
+
+/// Snapshot returned by `QuicTransport::connection_stats`, surfaced on the
+/// Prometheus endpoint by `SecureChannelPool::get_quic_prometheus_metrics`.
+#[allow(dead_code)]
+struct QuicConnectionStats {
+    rtt_ms: f64,
+    lost_packets: u64,
+    congestion_window: u64,
+}
+
+/// One independent bidirectional stream off `QuicTransport`'s shared
+/// connection - what this transport hands the pool as a pooled
+/// "connection". Both halves sit behind a `Mutex` since `Transport::send`
+/// takes `&Self::Conn`, not `&mut`, matching every other `Transport` impl.
+#[allow(dead_code)]
+struct QuicStream {
+    send: tokio::sync::Mutex<quinn::SendStream>,
+    recv: tokio::sync::Mutex<quinn::RecvStream>,
+}
+
+#[async_trait::async_trait]
+impl Transport for QuicTransport {
+    type Conn = QuicStream;
+
+    async fn connect(&self, endpoint: &str) -> Result<Self::Conn, Box<dyn std::error::Error>> {
+        let connection = {
+            let mut cached = self.connection.lock().await;
+            if cached.is_none() {
+                let addr: std::net::SocketAddr = endpoint.parse()?;
+                let server_name = endpoint.split(':').next().unwrap_or(endpoint).to_string();
+                let mut quic_endpoint = quinn::Endpoint::client("0.0.0.0:0".parse()?)?;
+                quic_endpoint.set_default_client_config(self.client_config.clone());
+                let connecting = quic_endpoint.connect(addr, &server_name)?;
+                let connection = if self.zero_rtt {
+                    match connecting.into_0rtt() {
+                        Ok((connection, _accepted)) => connection,
+                        Err(connecting) => connecting.await?,
+                    }
+                } else {
+                    connecting.await?
+                };
+                *cached = Some(connection);
+            }
+            cached.clone().unwrap()
+        };
+
+        let (send, recv) = connection.open_bi().await?;
+        Ok(QuicStream {
+            send: tokio::sync::Mutex::new(send),
+            recv: tokio::sync::Mutex::new(recv),
+        })
+    }
+
+    async fn send(&self, conn: &Self::Conn, data: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        conn.send.lock().await.write_all(data).await?;
+        let mut recv = conn.recv.lock().await;
+        let mut buf = vec![0u8; 4096];
+        let n = recv.read(&mut buf).await?.unwrap_or(0);
+        buf.truncate(n);
+        Ok(buf)
+    }
+
+    async fn healthcheck(&self, conn: &Self::Conn) -> Result<(), Box<dyn std::error::Error>> {
+        // Neither stream half exposes a cheap synchronous liveness signal
+        // over QUIC; a zero-length write is the closest probe without
+        // actually round-tripping a healthcheck payload.
+        conn.send.lock().await.write_all(&[]).await?;
+        Ok(())
+    }
+}
+
+// === Pluggable connection management ===
+//
+// `Transport` (above) says how to dial and talk to one backend. This layer
+// sits a level higher, modeled on bb8's `ManageConnection`: it owns minting
+// a connection and judging whether one already checked into the pool is
+// still good, decoupling that lifecycle decision from how the connection is
+// actually used. Making `SecureChannelPool` generic over `ManageConnection`
+// instead of `Transport` directly means it can pool something that isn't a
+// `Transport` at all (a database client, a set of QUIC streams) as long as
+// it can answer "connect" and "is this one still good", while every
+// existing `Transport` impl keeps working unchanged through the default
+// `SecureChannelManager` below.
+#[async_trait::async_trait]
+trait ManageConnection: Send + Sync {
+    type Connection: Send + Sync;
+
+    async fn connect(&self) -> Result<Self::Connection, Box<dyn std::error::Error>>;
+
+    /// Called on checkout (see the validate-on-checkout path) to catch a
+    /// connection that went stale while idle. Takes a shared reference
+    /// rather than bb8's `&mut Conn`: this pool always hands connections
+    /// out of `transport_connections` as a cloned `Arc<Connection>` (see
+    /// `send_request`/`check_connection_health`), never an owned value, so
+    /// every concrete check - like `Transport::healthcheck` above - is
+    /// written against `&Conn` already.
+    async fn is_valid(&self, conn: &Self::Connection) -> Result<(), Box<dyn std::error::Error>>;
+
+    /// Cheap synchronous liveness check for a connection already suspected
+    /// bad - unlike `is_valid`, this never does I/O.
+    fn has_broken(&self, conn: &Self::Connection) -> bool;
+}
+
+/// Default `ManageConnection`, wrapping a `Transport` plus the endpoint it
+/// dials. This is exactly what `SecureChannelPool` did before it became
+/// generic over `ManageConnection`, kept as the default manager so
+/// `SecureChannelPool::new`/`builder` don't change shape for the common
+/// case of pooling a single `Transport`.
+#[allow(dead_code)]
+struct SecureChannelManager<T: Transport> {
+    transport: Arc<T>,
+    endpoint: String,
+}
+
+#[async_trait::async_trait]
+impl<T: Transport> ManageConnection for SecureChannelManager<T> {
+    type Connection = T::Conn;
+
+    async fn connect(&self) -> Result<Self::Connection, Box<dyn std::error::Error>> {
+        self.transport.connect(&self.endpoint).await
+    }
+
+    async fn is_valid(&self, conn: &Self::Connection) -> Result<(), Box<dyn std::error::Error>> {
+        self.transport.healthcheck(conn).await
+    }
+
+    fn has_broken(&self, _conn: &Self::Connection) -> bool {
+        // Neither `TcpStream` nor `BitcoinRpcConn` expose a cheap
+        // synchronous liveness signal; `is_valid` on checkout is what
+        // catches staleness here.
+        false
+    }
+}
+
+// === Transparent payload compression ===
+//
+// `send_request` used to ship payloads uncompressed, which matters for
+// large Bitcoin payloads (block/tx batches) crossing the relay link. Real
+// gzip/zstd both need a crate (flate2/zstd) this tree has no manifest to
+// add, so both `Codec` variants route through the same hand-rolled
+// run-length codec below - good enough to shrink the long repeated runs
+// common in batched block/tx payloads and to exercise the codec-tagging
+// path end to end, but not a drop-in replacement for the real formats.
+const CODEC_ID_NONE: u8 = 0;
+const CODEC_ID_GZIP: u8 = 1;
+const CODEC_ID_ZSTD: u8 = 2;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[allow(dead_code)]
+enum Codec {
+    None,
+    Gzip,
+    Zstd,
+}
+
+impl Codec {
+    fn id(self) -> u8 {
+        match self {
+            Codec::None => CODEC_ID_NONE,
+            Codec::Gzip => CODEC_ID_GZIP,
+            Codec::Zstd => CODEC_ID_ZSTD,
+        }
+    }
+}
+
+/// Encodes runs of 3+ repeated bytes as `[0x00, byte, count]` (count capped
+/// at 255 per token, splitting longer runs across tokens); a run of literal
+/// `0x00` bytes is escaped the same way whatever its length (even 1 or 2),
+/// so `0x00` never appears un-escaped in the output.
+fn rle_compress(input: &[u8]) -> Vec<u8> {
+    const ESCAPE: u8 = 0x00;
+    let mut out = Vec::with_capacity(input.len());
+    let mut i = 0;
+    while i < input.len() {
+        let byte = input[i];
+        let mut run = 1usize;
+        while i + run < input.len() && input[i + run] == byte && run < 255 {
+            run += 1;
+        }
+        if run >= 3 || byte == ESCAPE {
+            out.push(ESCAPE);
+            out.push(byte);
+            out.push(run as u8);
+        } else {
+            out.extend(std::iter::repeat(byte).take(run));
+        }
+        i += run;
+    }
+    out
+}
+
+/// Inverse of `rle_compress`.
+fn rle_decompress(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    const ESCAPE: u8 = 0x00;
+    let mut out = Vec::with_capacity(input.len());
+    let mut i = 0;
+    while i < input.len() {
+        if input[i] == ESCAPE {
+            if i + 2 >= input.len() {
+                return Err("truncated RLE run token".into());
+            }
+            out.extend(std::iter::repeat(input[i + 1]).take(input[i + 2] as usize));
+            i += 3;
+        } else {
+            out.push(input[i]);
+            i += 1;
+        }
+    }
+    Ok(out)
+}
+
+/// Compresses `payload` with `codec` once it's at least `min_size` bytes,
+/// prefixing the result with a one-byte codec id so a peer that doesn't
+/// negotiate compression can still tell `CODEC_ID_NONE` apart from a
+/// compressed frame - the wire format stays self-describing either way.
+fn frame_with_codec(payload: &[u8], codec: Codec, min_size: usize) -> Vec<u8> {
+    if codec == Codec::None || payload.len() < min_size {
+        let mut framed = Vec::with_capacity(payload.len() + 1);
+        framed.push(CODEC_ID_NONE);
+        framed.extend_from_slice(payload);
+        return framed;
+    }
+    let compressed = rle_compress(payload);
+    let mut framed = Vec::with_capacity(compressed.len() + 1);
+    framed.push(codec.id());
+    framed.extend_from_slice(&compressed);
+    framed
+}
+
+/// Inverse of `frame_with_codec`: strips the codec id byte and decompresses
+/// if needed.
+fn unframe_with_codec(framed: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    let (&id, body) = framed.split_first().ok_or("empty framed payload")?;
+    match id {
+        CODEC_ID_NONE => Ok(body.to_vec()),
+        CODEC_ID_GZIP | CODEC_ID_ZSTD => rle_decompress(body),
+        other => Err(format!("unknown codec id {}", other).into()),
+    }
+}
+
+/// Returned by `get_connection` once `shutdown` has started draining the
+/// pool, distinguishing "the pool is going away" from the existing
+/// string-based transport/contention errors so a caller can match on it and
+/// back off instead of retrying a pool that will never accept it.
+#[derive(Clone, Debug)]
+#[allow(dead_code)]
+enum PoolError {
+    ShuttingDown,
+}
+
+impl std::fmt::Display for PoolError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PoolError::ShuttingDown => write!(f, "pool is shutting down: rejecting new connection requests"),
+        }
+    }
+}
+
+impl std::error::Error for PoolError {}
+
+// === Main SecureChannelPool ===
+#[allow(dead_code)]
+struct SecureChannelPool<M: ManageConnection> {
+    config: PoolConfig,
+    connections: Arc<RwLock<FastIdMap<SecureConnection>>>,
+    // Keyed by the same id as `connections`; split out rather than folded
+    // into `SecureConnection` so the pool's bookkeeping (state, histogram,
+    // security context) stays manager-agnostic.
+    transport_connections: Arc<RwLock<FastIdMap<Arc<M::Connection>>>>,
+    metrics: Arc<RwLock<PoolMetrics>>,
+    // How long `get_connection` took to resolve, success or failure -
+    // rendered as the `securechannelpool_wait_time_seconds` histogram.
+    wait_time_histogram: Arc<RwLock<LatencyHistogram>>,
+    // Connection age at the moment `run_cleanup` evicts it - rendered as
+    // the `securechannelpool_connection_lifetime_at_eviction_ms` histogram.
+    lifetime_at_eviction_histogram: Arc<RwLock<LatencyHistogram>>,
+    circuit_breaker: Arc<RwLock<CircuitBreaker>>,
+    manager: Arc<M>,
+    compression: Codec,
+    health_score: Arc<RwLock<f64>>,
+    is_running: Arc<RwLock<bool>>,
+    // Flipped on by `shutdown` before anything else, so `get_connection`
+    // starts rejecting new checkouts the instant a drain begins rather than
+    // racing the background tasks' teardown.
+    is_draining: Arc<RwLock<bool>>,
+    // `None` resolves to the ambient runtime (`Handle::try_current`) when
+    // `start` is called; `PoolBuilder::with_runtime_handle` lets a caller
+    // pin background tasks to a specific runtime instead.
+    runtime_handle: Option<tokio::runtime::Handle>,
+    // Broadcasts the shutdown request to every background loop at once;
+    // `run_cleanup`/`run_metrics`/`run_health_check` each hold a `subscribe`d
+    // receiver and `select!` on `changed()` alongside their ticker.
+    shutdown_tx: tokio::sync::watch::Sender<bool>,
+    // Flips to `true` once `start` has pre-warmed `config.min_idle`
+    // connections and `run_metrics` has completed its first tick;
+    // `wait_ready` blocks on this instead of a caller racing `start`'s
+    // background setup and routing traffic before either is actually ready.
+    ready_tx: tokio::sync::watch::Sender<bool>,
+    task_handles: Mutex<Vec<tokio::task::JoinHandle<()>>>,
+    // Set by `PoolBuilder::with_ctrl_c_shutdown`; `start` spawns a task that
+    // awaits `ctrl_c()` and calls `shutdown` with this timeout, so `main`
+    // no longer needs its own `tokio::select! { ctrl_c() => shutdown() }`.
+    ctrl_c_drain_timeout: Option<Duration>,
+    // Kept apart from `task_handles`: the ctrl_c task itself calls
+    // `shutdown`, which drains and awaits `task_handles` - awaiting its own
+    // handle there would deadlock.
+    ctrlc_handle: Mutex<Option<tokio::task::JoinHandle<()>>>,
+}
+
+// === Builder Pattern ===
+#[allow(dead_code)]
+struct PoolBuilder<M: ManageConnection> {
+    config: PoolConfig,
+    runtime_handle: Option<tokio::runtime::Handle>,
+    manager: M,
+    compression: Codec,
+    ctrl_c_drain_timeout: Option<Duration>,
+}
+
+impl SecureChannelPool<SecureChannelManager<TcpTlsTransport>> {
+    fn new(endpoint: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let config = PoolConfig {
+            max_connections: 100,
+            min_idle: 10,
+            max_lifetime: Duration::from_secs(1800),
+            max_latency_ms: 1000,
+            cleanup_interval: Duration::from_secs(30),
+            metrics_port: 9090,
+            namespace: "default".to_string(),
+            circuit_breaker_failure_threshold: 10,
+            circuit_breaker_cooldown: Duration::from_secs(60),
+            enterprise_features_enabled: true,
+            audit_logging_enabled: false,
+            compliance_mode: false,
+            compression_min_size_bytes: 256,
+            test_on_checkout: true,
+            max_checkout_retries: 2,
+        };
+        Ok(SecureChannelPool {
+            connections: Arc::new(RwLock::new(HashMap::default())),
+            transport_connections: Arc::new(RwLock::new(HashMap::default())),
+            metrics: Arc::new(RwLock::new(PoolMetrics {
+                total_connections: 0,
+                active_connections: 0,
+                idle_connections: 0,
+                total_requests: 0,
+                successful_requests: 0,
+                failed_requests: 0,
+                avg_latency_ms: 0.0,
+                p95_latency_ms: 0.0,
+                p99_latency_ms: 0.0,
+                throughput_ops_per_sec: 0.0,
+                error_rate_percent: 0.0,
+                uptime_seconds: 0,
+                total_original_bytes: 0,
+                total_compressed_bytes: 0,
+                compression_ratio: 1.0,
+                connections_created: 0,
+                connections_reused: 0,
+                connections_evicted: 0,
+                connections_failed: 0,
+                checkout_threshold_exceeded: 0,
+            })),
+            wait_time_histogram: Arc::new(RwLock::new(LatencyHistogram::new())),
+            lifetime_at_eviction_histogram: Arc::new(RwLock::new(LatencyHistogram::new())),
+            circuit_breaker: Arc::new(RwLock::new(CircuitBreaker {
+                state: CircuitBreakerState::Closed,
+                failure_count: 0,
+                half_open_successes: 0,
+                failure_threshold: config.circuit_breaker_failure_threshold,
+                success_threshold: 5,
+                timeout: config.circuit_breaker_cooldown,
+                last_failure_time: None,
+            })),
+            config,
+            manager: Arc::new(SecureChannelManager {
+                transport: Arc::new(TcpTlsTransport),
+                endpoint: endpoint.to_string(),
+            }),
+            compression: Codec::None,
+            health_score: Arc::new(RwLock::new(100.0)),
+            is_running: Arc::new(RwLock::new(false)),
+            is_draining: Arc::new(RwLock::new(false)),
+            runtime_handle: None,
+            shutdown_tx: tokio::sync::watch::channel(false).0,
+            ready_tx: tokio::sync::watch::channel(false).0,
+            task_handles: Mutex::new(Vec::new()),
+            ctrl_c_drain_timeout: None,
+            ctrlc_handle: Mutex::new(None),
+        })
+    }
+
+    fn builder(endpoint: &str) -> PoolBuilder<SecureChannelManager<TcpTlsTransport>> {
+        PoolBuilder {
+            config: PoolConfig {
+                max_connections: 100,
+                min_idle: 10,
+                max_lifetime: Duration::from_secs(1800),
+                max_latency_ms: 1000,
+                cleanup_interval: Duration::from_secs(30),
+                metrics_port: 9090,
+                namespace: "default".to_string(),
+                circuit_breaker_failure_threshold: 10,
+                circuit_breaker_cooldown: Duration::from_secs(60),
+                enterprise_features_enabled: true,
+                audit_logging_enabled: false,
+                compliance_mode: false,
+                compression_min_size_bytes: 256,
+                test_on_checkout: true,
+                max_checkout_retries: 2,
+            },
+            runtime_handle: None,
+            manager: SecureChannelManager {
+                transport: Arc::new(TcpTlsTransport),
+                endpoint: endpoint.to_string(),
+            },
+            compression: Codec::None,
+            ctrl_c_drain_timeout: None,
+        }
+    }
+}
+
+impl<M: ManageConnection> SecureChannelPool<M> {
+    /// Entry point for pooling a backend that isn't a `Transport` at all -
+    /// anything that can answer `ManageConnection::connect`/`is_valid`. The
+    /// `Transport`-backed path (`builder`/`with_transport`) is just
+    /// `builder_with_manager` with a `SecureChannelManager` already filled
+    /// in.
+    fn builder_with_manager(manager: M) -> PoolBuilder<M> {
+        PoolBuilder {
+            config: PoolConfig {
+                max_connections: 100,
+                min_idle: 10,
+                max_lifetime: Duration::from_secs(1800),
+                max_latency_ms: 1000,
+                cleanup_interval: Duration::from_secs(30),
+                metrics_port: 9090,
+                namespace: "default".to_string(),
+                circuit_breaker_failure_threshold: 10,
+                circuit_breaker_cooldown: Duration::from_secs(60),
+                enterprise_features_enabled: true,
+                audit_logging_enabled: false,
+                compliance_mode: false,
+                compression_min_size_bytes: 256,
+                test_on_checkout: true,
+                max_checkout_retries: 2,
+            },
+            runtime_handle: None,
+            manager,
+            compression: Codec::None,
+            ctrl_c_drain_timeout: None,
+        }
+    }
+
+    /// Spawns `BackgroundContext`'s cleanup/metrics/health-check loops on
+    /// `runtime_handle` (or the ambient runtime, if none was set), tracking
+    /// their `JoinHandle`s so `shutdown` can tear them down cleanly. Also
+    /// installs the `ctrl_c` shutdown task if
+    /// `PoolBuilder::with_ctrl_c_shutdown` was used, since that task needs
+    /// the `Arc<Self>` this method already has in hand.
+    async fn start(self: &Arc<Self>) -> Result<(), Box<dyn std::error::Error>> {
+        {
+            let mut running = self.is_running.write();
+            if *running {
+                return Err("pool already started".into());
+            }
+            *running = true;
+        }
+
+        let handle = match &self.runtime_handle {
+            Some(h) => h.clone(),
+            None => tokio::runtime::Handle::try_current()?,
+        };
+
+        let ctx = Arc::new(BackgroundContext {
+            config: self.config.clone(),
+            connections: self.connections.clone(),
+            metrics: self.metrics.clone(),
+            lifetime_at_eviction_histogram: self.lifetime_at_eviction_histogram.clone(),
+            health_score: self.health_score.clone(),
+            is_running: self.is_running.clone(),
+            started_at: SystemTime::now(),
+        });
+
+        let (metrics_ready_tx, metrics_ready_rx) = tokio::sync::oneshot::channel();
+
+        let mut handles = self.task_handles.lock();
+        handles.push(handle.spawn(ctx.clone().run_cleanup(self.shutdown_tx.subscribe())));
+        handles.push(handle.spawn(ctx.clone().run_metrics(self.shutdown_tx.subscribe(), Some(metrics_ready_tx))));
+        handles.push(handle.spawn(ctx.run_health_check(self.shutdown_tx.subscribe())));
+
+        // Pre-warming `min_idle` connections can block on retries/backoff
+        // against a slow or flapping endpoint, so it runs on its own tracked
+        // task rather than delaying `start`'s return - `wait_ready` is what
+        // callers block on instead. `ready_tx` is left unset (so
+        // `wait_ready` never resolves) if pre-warming never succeeds, the
+        // same "never claim healthy" stance as a failed metrics bind.
+        let pool = self.clone();
+        let ready_tx = self.ready_tx.clone();
+        let min_idle = self.config.min_idle;
+        handles.push(handle.spawn(async move {
+            if pool.establish_idle_connections(min_idle).await.is_ok() && metrics_ready_rx.await.is_ok() {
+                let _ = ready_tx.send(true);
+            }
+        }));
+        drop(handles);
+
+        if let Some(drain_timeout) = self.ctrl_c_drain_timeout {
+            let pool = self.clone();
+            let ctrlc_handle = handle.spawn(async move {
+                if tokio::signal::ctrl_c().await.is_ok() {
+                    let _ = pool.shutdown(drain_timeout).await;
+                }
+            });
+            *self.ctrlc_handle.lock() = Some(ctrlc_handle);
+        }
+
+        Ok(())
+    }
+
+    /// Resolves once `start` has pre-warmed `config.min_idle` connections
+    /// and `run_metrics` has completed its first tick (see the `ready_tx`
+    /// field doc comment) - lets orchestration code block on actual
+    /// readiness before routing traffic, rather than assuming the pool is
+    /// live the instant `start` returns. Never resolves if `start` hasn't
+    /// been called, or if pre-warming never succeeds.
+    async fn wait_ready(&self) {
+        let mut ready_rx = self.ready_tx.subscribe();
+        loop {
+            if *ready_rx.borrow() {
+                return;
+            }
+            if ready_rx.changed().await.is_err() {
+                return;
+            }
+        }
+    }
+
+    /// Flips the pool into a draining state - `get_connection` starts
+    /// rejecting with `PoolError::ShuttingDown` immediately - broadcasts
+    /// shutdown to every background loop over `shutdown_tx`, awaits their
+    /// `JoinHandle`s, and then polls until every checked-out connection is
+    /// returned or `drain_timeout` elapses, force-closing whatever is still
+    /// outstanding past the deadline. Safe to call from tests or a
+    /// production shutdown path.
+    async fn shutdown(&self, drain_timeout: Duration) -> Result<(), Box<dyn std::error::Error>> {
+        *self.is_draining.write() = true;
+        *self.is_running.write() = false;
+        let _ = self.shutdown_tx.send(true);
+
+        let handles: Vec<_> = self.task_handles.lock().drain(..).collect();
+        for handle in handles {
+            handle.await?;
+        }
+
+        let deadline = tokio::time::Instant::now() + drain_timeout;
+        while self.connections.read().values().any(|c| matches!(c.state, ConnectionState::Active)) {
+            if tokio::time::Instant::now() >= deadline {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(25)).await;
+        }
+
+        let forced: Vec<u64> = {
+            let mut connections = self.connections.write();
+            let forced: Vec<u64> = connections
+                .iter()
+                .filter(|(_, c)| matches!(c.state, ConnectionState::Active))
+                .map(|(id, _)| *id)
+                .collect();
+            for id in &forced {
+                connections.remove(id);
+            }
+            forced
+        };
+        if !forced.is_empty() {
+            let mut transport_connections = self.transport_connections.write();
+            for id in &forced {
+                transport_connections.remove(id);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Timed wrapper around `get_connection_inner`: records how long the
+    /// call took (success or failure) into `wait_time_histogram`, and bumps
+    /// `checkout_threshold_exceeded` when that exceeds `max_latency_ms` -
+    /// the starvation signal `get_prometheus_metrics` exposes as
+    /// `securechannelpool_checkout_threshold_exceeded_total`.
+    async fn get_connection(&self) -> Result<u64, Box<dyn std::error::Error>> {
+        let start = tokio::time::Instant::now();
+        let result = self.get_connection_inner().await;
+        let elapsed_ms = start.elapsed().as_secs_f64() * 1000.0;
+        self.wait_time_histogram.write().record(elapsed_ms);
+        if elapsed_ms > self.config.max_latency_ms as f64 {
+            self.metrics.write().checkout_threshold_exceeded += 1;
+        }
+        result
+    }
+
+    /// Acquires an idle connection (or mints a new one, up to
+    /// `max_connections`), failing fast rather than blocking indefinitely
+    /// if the connection table is contended past `max_latency_ms`, rejecting
+    /// outright while the circuit breaker is `Open`, and rejecting with
+    /// `PoolError::ShuttingDown` once `shutdown` has started draining the
+    /// pool.
+    async fn get_connection_inner(&self) -> Result<u64, Box<dyn std::error::Error>> {
+        if *self.is_draining.read() {
+            return Err(Box::new(PoolError::ShuttingDown));
+        }
+
+        if !self.circuit_breaker.write().allow_request() {
+            return Err("circuit breaker open: rejecting connection acquisition".into());
+        }
+
+        let budget = Duration::from_millis(self.config.max_latency_ms);
+
+        let idle_id = {
+            let mut connections = self
+                .connections
+                .try_write_for(budget)
+                .ok_or("timed out waiting for the connection table lock")?;
+
+            if let Some((_, conn)) = connections.iter_mut().find(|(_, c)| matches!(c.state, ConnectionState::Idle)) {
+                // Parked in `Reconnecting` rather than handed out as
+                // `Active` yet - `check_connection_health`/`is_valid` below
+                // still need to run before this id is safe to use.
+                conn.state = ConnectionState::Reconnecting;
+                Some(conn.id)
+            } else {
+                if connections.len() >= self.config.max_connections {
+                    return Err("connection pool exhausted".into());
+                }
+                None
+            }
+        };
+
+        if let Some(id) = idle_id {
+            if !self.config.test_on_checkout {
+                self.metrics.write().connections_reused += 1;
+                return Ok(self.activate_connection(id));
+            }
+
+            let transport_conn = self.transport_connections.read().get(&id).cloned();
+            let stale = match &transport_conn {
+                Some(conn) => self.manager.has_broken(conn) || self.manager.is_valid(conn).await.is_err(),
+                None => true,
+            };
+
+            if !stale {
+                self.metrics.write().connections_reused += 1;
+                return Ok(self.activate_connection(id));
+            }
+
+            // Discard the stale connection and mint a fresh one in its
+            // place rather than surfacing a dead socket to the caller - see
+            // `connect_with_retries` for the backoff between attempts.
+            self.connections.write().remove(&id);
+            self.transport_connections.write().remove(&id);
+            let transport_conn = self.connect_with_retries().await?;
+            self.insert_connection(id, transport_conn, budget)?;
+            return Ok(id);
+        }
+
+        // Minting a new connection calls out to `self.manager`, which may
+        // yield, so it happens outside the lock above rather than blocking
+        // idle-connection reuse for other callers. Two callers can race past
+        // the length check and both mint, briefly exceeding
+        // `max_connections` by a small margin - acceptable for a bounded
+        // relay fleet, not a hard resource cap.
+        let transport_conn = self.connect_with_retries().await?;
+
+        let id = self
+            .connections
+            .try_read_for(budget)
+            .ok_or("timed out waiting for the connection table lock")?
+            .len() as u64
+            + 1;
+        self.insert_connection(id, transport_conn, budget)?;
+        Ok(id)
+    }
+
+    /// Marks an already-inserted connection `Active` and returns its id -
+    /// the common tail of both the idle-reuse and freshly-minted paths in
+    /// `get_connection`.
+    fn activate_connection(&self, id: u64) -> u64 {
+        if let Some(conn) = self.connections.write().get_mut(&id) {
+            conn.state = ConnectionState::Active;
+            conn.last_activity = SystemTime::now();
+        }
+        id
+    }
+
+    /// Inserts bookkeeping plus the manager's connection under `id`, then
+    /// activates it.
+    fn insert_connection(
+        &self,
+        id: u64,
+        transport_conn: M::Connection,
+        budget: Duration,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut connections = self
+            .connections
+            .try_write_for(budget)
+            .ok_or("timed out waiting for the connection table lock")?;
+        connections.insert(
+            id,
+            SecureConnection {
+                id,
+                state: ConnectionState::Active,
+                created_at: SystemTime::now(),
+                last_activity: SystemTime::now(),
+                bytes_sent: 0,
+                bytes_received: 0,
+                latency_histogram: LatencyHistogram::new(),
+                security_context: SecurityContext {
+                    authenticated: false,
+                    session_key_rotated: SystemTime::now(),
+                    encryption_active: false,
+                    tls_version: "TLS1.3".to_string(),
+                    cipher_suite: "TLS_AES_256_GCM_SHA384".to_string(),
+                },
+            },
+        );
+        drop(connections);
+        self.transport_connections.write().insert(id, Arc::new(transport_conn));
+        self.metrics.write().connections_created += 1;
+        Ok(())
+    }
+
+    /// Calls `ManageConnection::connect` up to `config.max_checkout_retries`
+    /// additional times (so `max_checkout_retries == 0` is exactly one
+    /// attempt), sleeping `checkout_backoff(attempt)` between failures.
+    async fn connect_with_retries(&self) -> Result<M::Connection, Box<dyn std::error::Error>> {
+        let mut last_err: Option<Box<dyn std::error::Error>> = None;
+        for attempt in 0..=self.config.max_checkout_retries {
+            match self.manager.connect().await {
+                Ok(conn) => return Ok(conn),
+                Err(err) => {
+                    last_err = Some(err);
+                    if attempt < self.config.max_checkout_retries {
+                        tokio::time::sleep(Self::checkout_backoff(attempt)).await;
+                    }
+                }
+            }
+        }
+        self.metrics.write().connections_failed += 1;
+        Err(last_err.unwrap_or_else(|| "connect failed with zero checkout retries configured".into()))
+    }
+
+    /// Mints and idles `count` connections up front via `connect_with_retries`,
+    /// used by `start` to satisfy `config.min_idle` before `wait_ready`
+    /// resolves - otherwise the pool only mints connections lazily, on
+    /// whichever `get_connection` call happens to arrive first.
+    async fn establish_idle_connections(&self, count: usize) -> Result<(), Box<dyn std::error::Error>> {
+        let budget = Duration::from_millis(self.config.max_latency_ms);
+        for _ in 0..count {
+            let transport_conn = self.connect_with_retries().await?;
+            let id = self
+                .connections
+                .try_read_for(budget)
+                .ok_or("timed out waiting for the connection table lock")?
+                .len() as u64
+                + 1;
+            self.insert_connection(id, transport_conn, budget)?;
+            self.return_connection(id).await?;
+        }
+        Ok(())
+    }
+
+    /// `50ms * 2^attempt`, capped at 2s, jittered by up to +-25% off the low
+    /// bits of the current time - not cryptographic, just enough that a
+    /// fleet of callers retrying the same flapping backend doesn't
+    /// re-hammer it in lockstep.
+    fn checkout_backoff(attempt: usize) -> Duration {
+        const BASE_MS: u64 = 50;
+        const CAP_MS: u64 = 2_000;
+        let exp_ms = BASE_MS.saturating_mul(1u64 << attempt.min(16)).min(CAP_MS);
+
+        let nanos = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map(|d| d.subsec_nanos() as u64)
+            .unwrap_or(0);
+        let jitter_range = (exp_ms / 4).max(1);
+        let jitter = (nanos % (2 * jitter_range + 1)) as i64 - jitter_range as i64;
+        Duration::from_millis((exp_ms as i64 + jitter).max(0) as u64)
+    }
+
+    async fn return_connection(&self, id: u64) -> Result<(), Box<dyn std::error::Error>> {
+        let budget = Duration::from_millis(self.config.max_latency_ms);
+        let mut connections = self
+            .connections
+            .try_write_for(budget)
+            .ok_or("timed out waiting for the connection table lock")?;
+
+        match connections.get_mut(&id) {
+            Some(conn) => {
+                conn.state = ConnectionState::Idle;
+                conn.last_activity = SystemTime::now();
+                Ok(())
+            }
+            None => Err(format!("unknown connection id {}", id).into()),
+        }
+    }
+
+    /// Probes a connection against `ManageConnection::is_valid` (outside the
+    /// normal acquire/send/return cycle), demoting it to `Degraded` on
+    /// failure so `run_cleanup`/`get_connection` route new work around it.
+    async fn check_connection_health(&self, id: u64) -> Result<(), Box<dyn std::error::Error>> {
+        let transport_conn = self
+            .transport_connections
+            .read()
+            .get(&id)
+            .cloned()
+            .ok_or_else(|| format!("unknown connection id {}", id))?;
+
+        match self.manager.is_valid(&transport_conn).await {
+            Ok(()) => Ok(()),
+            Err(err) => {
+                if let Some(conn) = self.connections.write().get_mut(&id) {
+                    conn.state = ConnectionState::Degraded;
+                }
+                Err(err)
+            }
+        }
+    }
+    /// Unhealthy while the circuit breaker has tripped `Open` on the
+    /// downstream transport; `HalfOpen`/`Closed` are both considered healthy.
+    fn is_healthy(&self) -> bool {
+        !matches!(self.circuit_breaker.read().state, CircuitBreakerState::Open)
+    }
+
+    /// The `run_health_check` background loop's latest `100.0 - error_rate_percent`
+    /// reading, rather than the hardcoded value this used to return.
+    fn get_health_score(&self) -> f64 {
+        *self.health_score.read()
+    }
+
+    fn get_status_json(&self) -> String {
+        let breaker_state = match self.circuit_breaker.read().state {
+            CircuitBreakerState::Closed => "closed",
+            CircuitBreakerState::HalfOpen => "half_open",
+            CircuitBreakerState::Open => "open",
+        };
+        format!(
+            "{{\"healthy\":{},\"health_score\":{:.2},\"circuit_breaker_state\":\"{}\"}}",
+            self.is_healthy(),
+            self.get_health_score(),
+            breaker_state,
+        )
+    }
+    fn get_metrics_json(&self) -> String {
+        let metrics = self.metrics.read();
+        format!(
+            "{{\"total_requests\":{},\"successful_requests\":{},\"failed_requests\":{},\
+\"error_rate_percent\":{:.2},\"p95_latency_ms\":{:.2},\"p99_latency_ms\":{:.2},\
+\"compression_ratio\":{:.3}}}",
+            metrics.total_requests,
+            metrics.successful_requests,
+            metrics.failed_requests,
+            metrics.error_rate_percent,
+            metrics.p95_latency_ms,
+            metrics.p99_latency_ms,
+            metrics.compression_ratio,
+        )
+    }
+    /// Renders `hist` as a Prometheus histogram (cumulative `_bucket`
+    /// series with `le` labels, plus `_sum`/`_count`) under `name`.
+    /// `unit_divisor` converts the histogram's internal millisecond buckets
+    /// into whatever unit `name` is labeled in - `1.0` for milliseconds,
+    /// `1000.0` for seconds.
+    fn render_histogram(name: &str, hist: &LatencyHistogram, unit_divisor: f64) -> String {
+        let mut out = String::new();
+        out.push_str(&format!("# TYPE {} histogram\n", name));
+        let mut cumulative = 0u64;
+        let mut approx_sum = 0.0f64;
+        for (index, &count) in hist.buckets.iter().enumerate() {
+            cumulative += count;
+            let (lower, upper) = LatencyHistogram::bucket_bounds(index);
+            if count > 0 {
+                approx_sum += (lower * upper).sqrt() * count as f64;
+            }
+            out.push_str(&format!("{}_bucket{{le=\"{:.6}\"}} {}\n", name, upper / unit_divisor, cumulative));
+        }
+        out.push_str(&format!("{}_bucket{{le=\"+Inf\"}} {}\n", name, cumulative));
+        out.push_str(&format!("{}_sum {:.6}\n", name, approx_sum / unit_divisor));
+        out.push_str(&format!("{}_count {}\n", name, cumulative));
+        out
+    }
+
+    /// Renders the pool-wide latency histogram as a proper Prometheus
+    /// histogram (cumulative `_bucket` series with `le` labels, plus
+    /// `_sum`/`_count`), alongside the derived p95/p99 gauges.
+    fn get_prometheus_metrics(&self) -> String {
+        let aggregate = {
+            let connections = self.connections.read();
+            let mut aggregate = LatencyHistogram::new();
+            for conn in connections.values() {
+                aggregate = aggregate.merge(&conn.latency_histogram);
+            }
+            aggregate
+        };
+
+        let mut out = String::new();
+        out.push_str("# HELP securechannelpool_request_latency_ms Request latency distribution in milliseconds\n");
+        out.push_str(&Self::render_histogram("securechannelpool_request_latency_ms", &aggregate, 1.0));
+
+        let metrics = self.metrics.read();
+        out.push_str(&format!("securechannelpool_p95_latency_ms {:.3}\n", metrics.p95_latency_ms));
+        out.push_str(&format!("securechannelpool_p99_latency_ms {:.3}\n", metrics.p99_latency_ms));
+
+        // Per-endpoint connection gauges/counters - `namespace` is the
+        // label every pool is already built with (`with_namespace`), so it
+        // doubles as the `endpoint` label here rather than adding a second,
+        // redundant one.
+        let endpoint = &self.config.namespace;
+        out.push_str("# HELP securechannelpool_connections_idle Idle connections currently held by the pool\n");
+        out.push_str(&format!("securechannelpool_connections_idle{{endpoint=\"{}\"}} {}\n", endpoint, metrics.idle_connections));
+        out.push_str("# HELP securechannelpool_connections_active Active connections currently checked out\n");
+        out.push_str(&format!("securechannelpool_connections_active{{endpoint=\"{}\"}} {}\n", endpoint, metrics.active_connections));
+        out.push_str("# HELP securechannelpool_connections_total Total connections currently tracked by the pool\n");
+        out.push_str(&format!("securechannelpool_connections_total{{endpoint=\"{}\"}} {}\n", endpoint, metrics.total_connections));
+        out.push_str(&format!("securechannelpool_connections_created_total{{endpoint=\"{}\"}} {}\n", endpoint, metrics.connections_created));
+        out.push_str(&format!("securechannelpool_connections_reused_total{{endpoint=\"{}\"}} {}\n", endpoint, metrics.connections_reused));
+        out.push_str(&format!("securechannelpool_connections_evicted_total{{endpoint=\"{}\"}} {}\n", endpoint, metrics.connections_evicted));
+        out.push_str(&format!("securechannelpool_connections_failed_total{{endpoint=\"{}\"}} {}\n", endpoint, metrics.connections_failed));
+        out.push_str("# HELP securechannelpool_checkout_threshold_exceeded_total Checkouts that took longer than max_latency_ms to resolve\n");
+        out.push_str(&format!("securechannelpool_checkout_threshold_exceeded_total{{endpoint=\"{}\"}} {}\n", endpoint, metrics.checkout_threshold_exceeded));
+        drop(metrics);
+
+        out.push_str("# HELP securechannelpool_wait_time_seconds How long get_connection() took to resolve, success or failure\n");
+        out.push_str(&Self::render_histogram(
+            "securechannelpool_wait_time_seconds",
+            &self.wait_time_histogram.read(),
+            1000.0,
+        ));
+
+        out.push_str("# HELP securechannelpool_connection_lifetime_at_eviction_ms Connection age when run_cleanup evicted it\n");
+        out.push_str(&Self::render_histogram(
+            "securechannelpool_connection_lifetime_at_eviction_ms",
+            &self.lifetime_at_eviction_histogram.read(),
+            1.0,
+        ));
+
+        out
+    }
+}
+
+impl<T: Transport> SecureChannelPool<SecureChannelManager<T>> {
+    /// Acquires a connection, frames and round-trips `data` through its
+    /// transport under the negotiated `Codec`, and returns the connection to
+    /// the pool regardless of the outcome. Feeds the outcome into the
+    /// circuit breaker and `PoolMetrics.error_rate_percent` (which
+    /// `run_health_check` in turn folds into `health_score`), and tracks
+    /// original-vs-framed byte counts for `compression_ratio`. Lives on the
+    /// `SecureChannelManager`-specific impl (rather than the generic
+    /// `ManageConnection` one) since framing a request for round-trip needs
+    /// `Transport::send`, which isn't part of the `ManageConnection`
+    /// contract.
+    async fn send_request(&self, data: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        let id = self.get_connection().await?;
+
+        let framed = frame_with_codec(data, self.compression, self.config.compression_min_size_bytes);
+        let framed_len = framed.len() as u64;
+
+        let transport_conn = self.transport_connections.read().get(&id).cloned();
+        let send_result = match transport_conn {
+            Some(conn) => self.manager.transport.send(&conn, &framed).await,
+            None => Err(format!("connection {} has no transport binding", id).into()),
+        };
+        let result = send_result.and_then(|response| unframe_with_codec(&response));
+
+        if let Some(conn) = self.connections.write().get_mut(&id) {
+            conn.bytes_sent += framed_len;
+            conn.bytes_received += result.as_ref().map(|r| r.len() as u64).unwrap_or(0);
+        }
+
+        if result.is_ok() {
+            self.circuit_breaker.write().record_success();
+        } else {
+            self.circuit_breaker.write().record_failure();
+        }
+
+        {
+            let mut metrics = self.metrics.write();
+            metrics.total_requests += 1;
+            if result.is_ok() {
+                metrics.successful_requests += 1;
+            } else {
+                metrics.failed_requests += 1;
+            }
+            metrics.error_rate_percent =
+                (metrics.failed_requests as f64 / metrics.total_requests as f64) * 100.0;
+
+            metrics.total_original_bytes += data.len() as u64;
+            metrics.total_compressed_bytes += framed_len;
+            metrics.compression_ratio = if metrics.total_compressed_bytes > 0 {
+                metrics.total_original_bytes as f64 / metrics.total_compressed_bytes as f64
+            } else {
+                1.0
+            };
+        }
+
+        self.return_connection(id).await?;
+        result
+    }
+}
+
+impl SecureChannelPool<SecureChannelManager<QuicTransport>> {
+    /// `get_prometheus_metrics` plus QUIC-level connection stats (RTT
+    /// estimate, lost packets, congestion window) - transport-specific, so
+    /// it lives apart from the generic `ManageConnection` impl every pool
+    /// gets, the same way `send_request` lives on the `Transport`-specific
+    /// impl above rather than the generic one.
+    async fn get_quic_prometheus_metrics(&self) -> String {
+        let mut out = self.get_prometheus_metrics();
+        if let Some(stats) = self.manager.transport.connection_stats().await {
+            out.push_str(&format!("securechannelpool_quic_rtt_ms {:.3}\n", stats.rtt_ms));
+            out.push_str(&format!("securechannelpool_quic_lost_packets {}\n", stats.lost_packets));
+            out.push_str(&format!("securechannelpool_quic_congestion_window {}\n", stats.congestion_window));
+        }
+        out
+    }
+}
+
+impl<M: ManageConnection> PoolBuilder<M> {
+    fn with_namespace(mut self, namespace: &str) -> Self {
+        self.config.namespace = namespace.to_string();
+        self
+    }
+    
+    fn with_max_connections(mut self, max: usize) -> Self {
+        self.config.max_connections = max;
+        self
+    }
+    
+    fn with_metrics_port(mut self, port: u16) -> Self {
+        self.config.metrics_port = port;
+        self
+    }
+
+    /// How many idle connections `start` pre-warms (see
+    /// `SecureChannelPool::establish_idle_connections`) before `wait_ready`
+    /// resolves, so the pool has spare capacity the instant traffic starts
+    /// routing instead of every early caller paying a fresh `connect`.
+    fn with_min_idle(mut self, count: usize) -> Self {
+        self.config.min_idle = count;
+        self
+    }
+    
+    fn with_cleanup_interval(mut self, duration: Duration) -> Self {
+        self.config.cleanup_interval = duration;
+        self
+    }
+    
+    fn with_latency_threshold(mut self, duration: Duration) -> Self {
+        self.config.max_latency_ms = duration.as_millis() as u64;
+        self
+    }
+    
+    fn with_enterprise_features(mut self, enabled: bool) -> Self {
+        self.config.enterprise_features_enabled = enabled;
+        self
+    }
+    
+    fn with_audit_logging(mut self, enabled: bool) -> Self {
+        self.config.audit_logging_enabled = enabled;
+        self
+    }
+    
+    fn with_compliance_mode(mut self, enabled: bool) -> Self {
+        self.config.compliance_mode = enabled;
+        self
+    }
+
+    /// Pins the pool's background loops to a specific runtime instead of
+    /// resolving the ambient one (`Handle::try_current`) when `start` runs.
+    fn with_runtime_handle(mut self, handle: tokio::runtime::Handle) -> Self {
+        self.runtime_handle = Some(handle);
+        self
+    }
+
+    /// Negotiates a payload codec for `send_request` (see the comment above
+    /// `CODEC_ID_NONE` for what `Gzip`/`Zstd` actually run under the hood today).
+    fn with_compression(mut self, codec: Codec) -> Self {
+        self.compression = codec;
+        self
+    }
+
+    /// Below this many bytes, `send_request` ships a payload uncompressed
+    /// rather than pay the codec-id framing overhead for no benefit.
+    fn with_compression_min_size(mut self, bytes: usize) -> Self {
+        self.config.compression_min_size_bytes = bytes;
+        self
+    }
+
+    /// Skips `ManageConnection::is_valid` on a reused idle connection when
+    /// `false`, trading a rare stale-connection hand-out for one less
+    /// round-trip on `get_connection`'s hot path - useful for
+    /// latency-sensitive callers. Defaults to `true`.
+    fn with_test_on_checkout(mut self, enabled: bool) -> Self {
+        self.config.test_on_checkout = enabled;
+        self
+    }
+
+    /// How many extra `ManageConnection::connect` attempts
+    /// `connect_with_retries` makes, with exponential backoff between them,
+    /// after the first attempt fails or a stale reused connection is
+    /// discarded on checkout.
+    fn with_max_checkout_retries(mut self, retries: usize) -> Self {
+        self.config.max_checkout_retries = retries;
+        self
+    }
+
+    /// Has `start` spawn a task that awaits `ctrl_c()` and calls
+    /// `shutdown(drain_timeout)` on the caller's behalf, so `main` doesn't
+    /// need its own `tokio::select! { worker => ..., ctrl_c() => shutdown() }`
+    /// to avoid leaking the pool's background tasks on exit.
+    fn with_ctrl_c_shutdown(mut self, drain_timeout: Duration) -> Self {
+        self.ctrl_c_drain_timeout = Some(drain_timeout);
+        self
+    }
+
+    fn build(self) -> Result<SecureChannelPool<M>, Box<dyn std::error::Error>> {
+        Ok(SecureChannelPool {
+            connections: Arc::new(RwLock::new(HashMap::default())),
+            transport_connections: Arc::new(RwLock::new(HashMap::default())),
+            metrics: Arc::new(RwLock::new(PoolMetrics {
+                total_connections: 0,
+                active_connections: 0,
+                idle_connections: 0,
+                total_requests: 0,
+                successful_requests: 0,
+                failed_requests: 0,
+                avg_latency_ms: 0.0,
+                p95_latency_ms: 0.0,
+                p99_latency_ms: 0.0,
+                throughput_ops_per_sec: 0.0,
+                error_rate_percent: 0.0,
+                uptime_seconds: 0,
+                total_original_bytes: 0,
+                total_compressed_bytes: 0,
+                compression_ratio: 1.0,
+                connections_created: 0,
+                connections_reused: 0,
+                connections_evicted: 0,
+                connections_failed: 0,
+                checkout_threshold_exceeded: 0,
+            })),
+            wait_time_histogram: Arc::new(RwLock::new(LatencyHistogram::new())),
+            lifetime_at_eviction_histogram: Arc::new(RwLock::new(LatencyHistogram::new())),
+            circuit_breaker: Arc::new(RwLock::new(CircuitBreaker {
+                state: CircuitBreakerState::Closed,
+                failure_count: 0,
+                half_open_successes: 0,
+                failure_threshold: self.config.circuit_breaker_failure_threshold,
+                success_threshold: 5,
+                timeout: self.config.circuit_breaker_cooldown,
+                last_failure_time: None,
+            })),
+            config: self.config,
+            manager: Arc::new(self.manager),
+            compression: self.compression,
+            health_score: Arc::new(RwLock::new(100.0)),
+            is_running: Arc::new(RwLock::new(false)),
+            is_draining: Arc::new(RwLock::new(false)),
+            runtime_handle: self.runtime_handle,
+            shutdown_tx: tokio::sync::watch::channel(false).0,
+            ready_tx: tokio::sync::watch::channel(false).0,
+            task_handles: Mutex::new(Vec::new()),
+            ctrl_c_drain_timeout: self.ctrl_c_drain_timeout,
+            ctrlc_handle: Mutex::new(None),
+        })
+    }
+}
+
+impl<T: Transport> PoolBuilder<SecureChannelManager<T>> {
+    /// Swaps in a different backend - a raw relay socket, a JSON-RPC
+    /// Bitcoin node, an in-memory mock for tests - in place of the default
+    /// `TcpTlsTransport`. Changes the builder's (and the eventual pool's)
+    /// transport type parameter, so this must be the last transport-related
+    /// call before `build`.
+    fn with_transport<U: Transport>(self, transport: U) -> PoolBuilder<SecureChannelManager<U>> {
+        PoolBuilder {
+            config: self.config,
+            runtime_handle: self.runtime_handle,
+            manager: SecureChannelManager {
+                transport: Arc::new(transport),
+                endpoint: self.manager.endpoint,
+            },
+            compression: self.compression,
+            ctrl_c_drain_timeout: self.ctrl_c_drain_timeout,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+    /// In-memory `Transport` for tests: `connect` mints a sequential id
+    /// (failing the first `fail_connects` attempts, if any), `send` echoes
+    /// the payload back, and `healthcheck` can be forced to fail via
+    /// `poisoned` to exercise the stale-connection-discard path.
+    struct MockTransport {
+        next_id: AtomicU64,
+        fail_connects: AtomicU64,
+        poisoned: AtomicBool,
+    }
+
+    impl MockTransport {
+        fn new() -> Self {
+            MockTransport {
+                next_id: AtomicU64::new(0),
+                fail_connects: AtomicU64::new(0),
+                poisoned: AtomicBool::new(false),
+            }
+        }
+
+        fn failing(fail_connects: u64) -> Self {
+            MockTransport {
+                next_id: AtomicU64::new(0),
+                fail_connects: AtomicU64::new(fail_connects),
+                poisoned: AtomicBool::new(false),
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl Transport for MockTransport {
+        type Conn = u64;
+
+        async fn connect(&self, _endpoint: &str) -> Result<Self::Conn, Box<dyn std::error::Error>> {
+            if self.fail_connects.load(Ordering::SeqCst) > 0 {
+                self.fail_connects.fetch_sub(1, Ordering::SeqCst);
+                return Err("mock connect failure".into());
+            }
+            Ok(self.next_id.fetch_add(1, Ordering::SeqCst))
+        }
+
+        async fn send(&self, _conn: &Self::Conn, data: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+            Ok(data.to_vec())
+        }
+
+        async fn healthcheck(&self, _conn: &Self::Conn) -> Result<(), Box<dyn std::error::Error>> {
+            if self.poisoned.load(Ordering::SeqCst) {
+                Err("mock connection poisoned".into())
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    fn test_pool(transport: MockTransport) -> SecureChannelPool<SecureChannelManager<MockTransport>> {
+        SecureChannelPool::builder("mock:0")
+            .with_namespace("test")
+            .with_transport(transport)
+            .with_max_connections(4)
+            .with_latency_threshold(Duration::from_millis(500))
+            .with_cleanup_interval(Duration::from_millis(10))
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn get_connection_mints_then_reuses() {
+        let pool = test_pool(MockTransport::new());
+        let id = pool.get_connection().await.unwrap();
+        pool.return_connection(id).await.unwrap();
+        let reused_id = pool.get_connection().await.unwrap();
+        assert_eq!(id, reused_id);
+        assert_eq!(pool.metrics.read().connections_created, 1);
+        assert_eq!(pool.metrics.read().connections_reused, 1);
+    }
+
+    #[tokio::test]
+    async fn send_request_round_trips_and_updates_metrics() {
+        let pool = test_pool(MockTransport::new());
+        let response = pool.send_request(b"hello").await.unwrap();
+        assert_eq!(response, b"hello");
+        assert_eq!(pool.metrics.read().successful_requests, 1);
+        assert_eq!(pool.metrics.read().total_requests, 1);
+    }
+
+    #[tokio::test]
+    async fn prometheus_metrics_expose_per_endpoint_counters_and_histograms() {
+        let pool = test_pool(MockTransport::new());
+        let id = pool.get_connection().await.unwrap();
+        pool.return_connection(id).await.unwrap();
+        pool.get_connection().await.unwrap();
+
+        let rendered = pool.get_prometheus_metrics();
+        // Connection and wait-time histograms/counters are labeled by
+        // `namespace` (the endpoint label) and carry this pool's own
+        // checkout counts, not just generic metric names.
+        assert!(rendered.contains("endpoint=\"test\""));
+        assert!(rendered.contains("securechannelpool_connections_created_total{endpoint=\"test\"} 1"));
+        assert!(rendered.contains("securechannelpool_connections_reused_total{endpoint=\"test\"} 1"));
+        assert!(rendered.contains("securechannelpool_wait_time_seconds_bucket"));
+        assert!(rendered.contains("securechannelpool_connection_lifetime_at_eviction_ms_bucket"));
+    }
+
+    #[tokio::test]
+    async fn exhausted_pool_rejects_new_connections() {
+        let pool = test_pool(MockTransport::new());
+        // Check out all `max_connections` (4) without returning any.
+        for _ in 0..4 {
+            pool.get_connection().await.unwrap();
+        }
+        assert!(pool.get_connection().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn connect_with_retries_recovers_from_transient_failures() {
+        // The first two connect attempts fail; the third (within
+        // max_checkout_retries = 2 extra attempts) succeeds.
+        let pool = SecureChannelPool::builder("mock:0")
+            .with_namespace("test")
+            .with_transport(MockTransport::failing(2))
+            .with_max_checkout_retries(2)
+            .build()
+            .unwrap();
+        assert!(pool.get_connection().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn connect_with_retries_gives_up_and_counts_failure() {
+        let pool = SecureChannelPool::builder("mock:0")
+            .with_namespace("test")
+            .with_transport(MockTransport::failing(10))
+            .with_max_checkout_retries(1)
+            .build()
+            .unwrap();
+        assert!(pool.get_connection().await.is_err());
+        assert_eq!(pool.metrics.read().connections_failed, 1);
+    }
+
+    #[tokio::test]
+    async fn stale_connection_is_discarded_and_replaced_on_checkout() {
+        let pool = test_pool(MockTransport::new());
+        let id = pool.get_connection().await.unwrap();
+        pool.return_connection(id).await.unwrap();
+
+        // Poisoning the transport makes `is_valid` fail for every existing
+        // connection, so the idle one found on the next checkout must be
+        // discarded and replaced rather than handed out stale.
+        pool.manager.transport.poisoned.store(true, Ordering::SeqCst);
+        let new_id = pool.get_connection().await.unwrap();
+        assert_eq!(pool.metrics.read().connections_reused, 0);
+        assert_eq!(pool.metrics.read().connections_created, 2);
+        let _ = new_id;
+    }
+
+    #[tokio::test]
+    async fn shutdown_rejects_new_checkouts_immediately() {
+        let pool = Arc::new(test_pool(MockTransport::new()));
+        pool.start().await.unwrap();
+        pool.shutdown(Duration::from_secs(1)).await.unwrap();
+        assert!(pool.get_connection().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn wait_ready_resolves_after_start_prewarms_min_idle() {
+        let pool = Arc::new(
+            SecureChannelPool::builder("mock:0")
+                .with_namespace("test")
+                .with_transport(MockTransport::new())
+                .with_min_idle(2)
+                .with_cleanup_interval(Duration::from_millis(10))
+                .build()
+                .unwrap(),
+        );
+        pool.start().await.unwrap();
+        tokio::time::timeout(Duration::from_secs(5), pool.wait_ready())
+            .await
+            .expect("wait_ready should resolve once min_idle is prewarmed");
+        assert_eq!(pool.metrics.read().connections_created, 2);
+        pool.shutdown(Duration::from_secs(1)).await.unwrap();
+    }
+
+    #[test]
+    fn circuit_breaker_opens_after_threshold_then_half_opens_after_cooldown() {
+        let mut breaker = CircuitBreaker {
+            state: CircuitBreakerState::Closed,
+            failure_count: 0,
+            half_open_successes: 0,
+            failure_threshold: 3,
+            success_threshold: 2,
+            timeout: Duration::from_millis(0),
+            last_failure_time: None,
+        };
+
+        for _ in 0..3 {
+            assert!(breaker.allow_request());
+            breaker.record_failure();
+        }
+        assert!(matches!(breaker.state, CircuitBreakerState::Open));
+
+        // Cooldown is zero, so the very next `allow_request` call flips it
+        // to `HalfOpen` and lets the trial request through.
+        assert!(breaker.allow_request());
+        assert!(matches!(breaker.state, CircuitBreakerState::HalfOpen));
+
+        breaker.record_success();
+        breaker.record_success();
+        assert!(matches!(breaker.state, CircuitBreakerState::Closed));
+    }
+
+    #[test]
+    fn circuit_breaker_half_open_failure_reopens() {
+        let mut breaker = CircuitBreaker {
+            state: CircuitBreakerState::HalfOpen,
+            failure_count: 0,
+            half_open_successes: 0,
+            failure_threshold: 3,
+            success_threshold: 2,
+            timeout: Duration::from_secs(60),
+            last_failure_time: None,
+        };
+        breaker.record_failure();
+        assert!(matches!(breaker.state, CircuitBreakerState::Open));
+    }
+
+    #[test]
+    fn rle_round_trips_runs_and_literal_escape_byte() {
+        let input = vec![0u8, 0, 5, 5, 5, 5, 5, 1, 2, 3];
+        let compressed = rle_compress(&input);
+        let restored = rle_decompress(&compressed).unwrap();
+        assert_eq!(restored, input);
+    }
+
+    #[test]
+    fn frame_with_codec_skips_compression_below_min_size() {
+        let payload = b"short";
+        let framed = frame_with_codec(payload, Codec::Gzip, 256);
+        assert_eq!(framed[0], CODEC_ID_NONE);
+        let restored = unframe_with_codec(&framed).unwrap();
+        assert_eq!(restored, payload);
+    }
+
+    #[test]
+    fn frame_with_codec_compresses_above_min_size() {
+        let payload = vec![7u8; 1024];
+        let framed = frame_with_codec(&payload, Codec::Zstd, 256);
+        assert_eq!(framed[0], CODEC_ID_ZSTD);
+        assert!(framed.len() < payload.len());
+        let restored = unframe_with_codec(&framed).unwrap();
+        assert_eq!(restored, payload);
+    }
+
+    #[test]
+    fn latency_histogram_quantile_tracks_recorded_samples() {
+        let mut hist = LatencyHistogram::new();
+        for _ in 0..99 {
+            hist.record(1.0);
+        }
+        hist.record(1000.0);
+        // 99 of 100 samples sit in the lowest bucket, so p50 should land
+        // there too, while the one far outlier pulls the upper quantiles up.
+        assert!(hist.quantile(0.5) < 5.0);
+        assert!(hist.quantile(0.999) > 500.0);
+    }
+
+    #[test]
+    fn fast_lock_rwlock_never_poisons_across_a_panicking_writer() {
+        let lock = Arc::new(RwLock::new(0u64));
+        let lock_clone = Arc::clone(&lock);
+        let _ = std::thread::spawn(move || {
+            let mut guard = lock_clone.write();
+            *guard = 42;
+            panic!("simulated writer panic while holding the lock");
+        })
+        .join();
+
+        // A real `std::sync::RwLock` would return `Err(PoisonError)` here;
+        // `fast_lock::RwLock` must still hand back a usable guard.
+        let guard = lock.read();
+        assert_eq!(*guard, 42);
+    }
+
+    #[test]
+    fn fast_id_map_holds_distinct_keys() {
+        let mut map: FastIdMap<&'static str> = HashMap::default();
+        map.insert(1, "one");
+        map.insert(2, "two");
+        assert_eq!(map.get(&1), Some(&"one"));
+        assert_eq!(map.get(&2), Some(&"two"));
+        assert_eq!(map.len(), 2);
+    }
+}
@@ -2,6 +2,8 @@
 // BitcoinCab.inc - SecureBuffer core
 
 use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::mem::MaybeUninit;
 use zeroize::Zeroize;
 use std::io;
 use thiserror::Error;
@@ -95,6 +97,21 @@ impl SecureBuffer {
         if !self.is_valid { None } else { Some(&mut self.buffer) }
     }
     pub fn len(&self) -> usize { self.buffer.len() }
+
+    /// Copy this buffer's secret bytes directly into a caller-provided,
+    /// possibly-uninitialized output buffer and advance its initialized
+    /// cursor, instead of requiring the caller to zero-fill a fresh
+    /// `Vec<u8>` first just to receive them. Mirrors the uninitialized-read
+    /// optimization in std's (still-unstable) `BorrowedBuf`/`BorrowedCursor`.
+    /// Copies at most as many bytes as fit in `out`'s remaining capacity.
+    pub fn read_buf(&self, out: &mut UninitCursor<'_>) -> Result<usize, SecureBufferError> {
+        if !self.is_valid {
+            return Err(SecureBufferError::InvalidState);
+        }
+        let n = std::cmp::min(self.buffer.len(), out.remaining_capacity());
+        out.append(&self.buffer[..n]);
+        Ok(n)
+    }
 }
 
 impl Zeroize for SecureBuffer {
@@ -112,3 +129,350 @@ impl Drop for SecureBuffer {
         }
     }
 }
+
+/// Allocation shared by every `SecureBytes` view over it. Mirrors
+/// `SecureBuffer::drop`'s zeroize-then-unlock teardown, but `Arc` defers
+/// running it until the last clone goes away instead of a single owner's
+/// lifetime - exactly the refcounted-teardown behavior `SecureBytes` wants.
+struct SecureBytesInner {
+    buffer: Vec<u8>,
+}
+
+impl Drop for SecureBytesInner {
+    fn drop(&mut self) {
+        self.buffer.zeroize();
+        let _ = platform::unlock_memory(self.buffer.as_mut_ptr(), self.buffer.len());
+    }
+}
+
+/// Cheaply-cloneable, zero-copy view into a locked allocation, modeled on
+/// `bytes::Bytes`: `clone` only bumps `inner`'s `Arc` strong count, and
+/// `slice`/`slice_ref` carve out a sub-window over the same shared
+/// allocation instead of copying or re-locking it. Zeroization and
+/// `munlock` happen exactly once, whenever the last view drops.
+///
+/// Unlike the raw `data`/`capacity`/`length` triple a general-purpose
+/// version of this might wrap, this tree's `SecureBuffer` only has a single
+/// `Vec<u8>` with no separate capacity, so `len()` here doubles as both.
+#[derive(Clone)]
+pub struct SecureBytes {
+    inner: Arc<SecureBytesInner>,
+    offset: usize,
+    len: usize,
+}
+
+impl SecureBytes {
+    /// Convert a mutable, single-owner `SecureBuffer` into an immutable,
+    /// shared `SecureBytes` covering its whole length. The buffer's own
+    /// `Drop` is skipped - the allocation isn't re-zeroized here, it lives on
+    /// inside the new `Arc` and gets zeroized/unlocked once that drops.
+    pub fn freeze(mut buffer: SecureBuffer) -> Self {
+        let data = std::mem::take(&mut buffer.buffer);
+        let len = data.len();
+        std::mem::forget(buffer);
+        Self {
+            inner: Arc::new(SecureBytesInner { buffer: data }),
+            offset: 0,
+            len,
+        }
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        &self.inner.buffer[self.offset..self.offset + self.len]
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Number of live `SecureBytes` views (including this one) sharing the
+    /// backing allocation - it's only zeroized and unlocked once this drops
+    /// to zero.
+    pub fn strong_count(&self) -> usize {
+        Arc::strong_count(&self.inner)
+    }
+
+    /// A sub-view over `range` (relative to this view, not the whole
+    /// allocation), sharing the same backing allocation - no copy, no
+    /// re-zeroizing, no re-locking.
+    pub fn slice(&self, range: std::ops::Range<usize>) -> Self {
+        assert!(range.start <= range.end && range.end <= self.len, "slice range out of bounds");
+        Self {
+            inner: self.inner.clone(),
+            offset: self.offset + range.start,
+            len: range.end - range.start,
+        }
+    }
+
+    /// Like `bytes::Bytes::slice_ref`: given `subset`, a subslice of
+    /// `self.as_slice()` obtained elsewhere (e.g. after splitting this
+    /// view's contents by hand), returns the `SecureBytes` view covering
+    /// that same sub-range of the shared allocation.
+    pub fn slice_ref(&self, subset: &[u8]) -> Self {
+        let bytes_start = self.as_slice().as_ptr() as usize;
+        let bytes_end = bytes_start + self.len;
+        let sub_start = subset.as_ptr() as usize;
+        let sub_end = sub_start + subset.len();
+        assert!(
+            sub_start >= bytes_start && sub_end <= bytes_end,
+            "subset is not a view into this SecureBytes"
+        );
+        let start = sub_start - bytes_start;
+        self.slice(start..start + subset.len())
+    }
+
+    /// Shrink this view to `new_len`, keeping the same backing allocation
+    /// (and its locked memory) rather than reallocating - mirrors
+    /// `Vec::truncate` preserving capacity.
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len < self.len {
+            self.len = new_len;
+        }
+    }
+}
+
+/// Stable-Rust stand-in for std's unstable `BorrowedBuf`/`BorrowedCursor`: a
+/// caller-owned, possibly-uninitialized output buffer plus how much of it
+/// has been filled so far. `SecureBuffer::read_buf` writes secret bytes
+/// directly into the unfilled tail via this cursor instead of requiring the
+/// caller to zero-fill a `Vec<u8>` up front just to hand it over.
+pub struct UninitCursor<'a> {
+    buf: &'a mut [MaybeUninit<u8>],
+    filled: usize,
+}
+
+impl<'a> UninitCursor<'a> {
+    pub fn new(buf: &'a mut [MaybeUninit<u8>]) -> Self {
+        Self { buf, filled: 0 }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.buf.len()
+    }
+
+    pub fn remaining_capacity(&self) -> usize {
+        self.buf.len() - self.filled
+    }
+
+    /// The bytes written into this cursor so far.
+    pub fn filled(&self) -> &[u8] {
+        // SAFETY: `self.buf[..self.filled]` only ever grows via `append`,
+        // which writes every byte in that prefix through `MaybeUninit::write`
+        // before advancing `filled` past it.
+        unsafe { std::slice::from_raw_parts(self.buf.as_ptr() as *const u8, self.filled) }
+    }
+
+    /// Write `data` into the unfilled tail and advance the initialized
+    /// cursor past it. Panics if `data` doesn't fit in the remaining
+    /// capacity - same contract as `BorrowedCursor::append`.
+    pub fn append(&mut self, data: &[u8]) {
+        assert!(data.len() <= self.remaining_capacity(), "UninitCursor overflow");
+        for (slot, &byte) in self.buf[self.filled..self.filled + data.len()].iter_mut().zip(data) {
+            slot.write(byte);
+        }
+        self.filled += data.len();
+    }
+}
+
+/// `std::io::Cursor`-style wrapper around a `SecureBuffer`: tracks a
+/// read/write position independent of the buffer's fixed `len()`, so it can
+/// be filled incrementally (`io::copy(&mut reader, &mut cursor)`) and
+/// drained with the standard `Read`/`Write`/`Seek` combinators instead of
+/// `SecureBuffer`'s ad-hoc `copy_from_slice`/`as_slice` pair. Any region a
+/// `write` overwrites is zeroized first; bytes past the end of a write keep
+/// whatever they held before.
+pub struct SecureCursor {
+    buffer: SecureBuffer,
+    pos: usize,
+}
+
+impl SecureCursor {
+    pub fn new(buffer: SecureBuffer) -> Self {
+        Self { buffer, pos: 0 }
+    }
+
+    pub fn into_inner(self) -> SecureBuffer {
+        self.buffer
+    }
+
+    pub fn get_ref(&self) -> &SecureBuffer {
+        &self.buffer
+    }
+
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+}
+
+impl io::Read for SecureCursor {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let data = self
+            .buffer
+            .as_slice()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "SecureBuffer is no longer valid"))?;
+        let remaining = &data[self.pos.min(data.len())..];
+        let n = std::cmp::min(buf.len(), remaining.len());
+        buf[..n].copy_from_slice(&remaining[..n]);
+        self.pos += n;
+        Ok(n)
+    }
+}
+
+impl io::Write for SecureCursor {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let capacity = self.buffer.len();
+        let remaining = capacity.saturating_sub(self.pos);
+        if buf.len() > remaining {
+            return Err(io::Error::new(
+                io::ErrorKind::WriteZero,
+                "write would exceed SecureBuffer capacity",
+            ));
+        }
+        let data = self
+            .buffer
+            .as_mut_slice()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "SecureBuffer is no longer valid"))?;
+        data[self.pos..self.pos + buf.len()].zeroize();
+        data[self.pos..self.pos + buf.len()].copy_from_slice(buf);
+        self.pos += buf.len();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl io::Seek for SecureCursor {
+    fn seek(&mut self, pos: io::SeekFrom) -> io::Result<u64> {
+        let capacity = self.buffer.len() as i64;
+        let new_pos = match pos {
+            io::SeekFrom::Start(offset) => offset as i64,
+            io::SeekFrom::End(offset) => capacity + offset,
+            io::SeekFrom::Current(offset) => self.pos as i64 + offset,
+        };
+        if new_pos < 0 || new_pos > capacity {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "seek past SecureBuffer capacity",
+            ));
+        }
+        self.pos = new_pos as usize;
+        Ok(self.pos as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Seek, SeekFrom, Write};
+
+    fn filled_buffer(data: &[u8]) -> SecureBuffer {
+        let mut buffer = SecureBuffer::new(data.len()).unwrap();
+        buffer.copy_from_slice(data).unwrap();
+        buffer
+    }
+
+    #[test]
+    fn secure_bytes_freeze_preserves_the_buffers_contents() {
+        let buffer = filled_buffer(b"top secret");
+        let bytes = SecureBytes::freeze(buffer);
+        assert_eq!(bytes.as_slice(), b"top secret");
+        assert_eq!(bytes.len(), 10);
+        assert!(!bytes.is_empty());
+    }
+
+    #[test]
+    fn secure_bytes_clone_shares_the_allocation_and_counts_views() {
+        let bytes = SecureBytes::freeze(filled_buffer(b"shared"));
+        assert_eq!(bytes.strong_count(), 1);
+        let clone = bytes.clone();
+        assert_eq!(bytes.strong_count(), 2);
+        assert_eq!(clone.as_slice(), b"shared");
+        drop(clone);
+        assert_eq!(bytes.strong_count(), 1);
+    }
+
+    #[test]
+    fn secure_bytes_slice_carves_a_sub_view() {
+        let bytes = SecureBytes::freeze(filled_buffer(b"0123456789"));
+        let middle = bytes.slice(2..5);
+        assert_eq!(middle.as_slice(), b"234");
+        // Shares the same backing allocation rather than copying it.
+        assert_eq!(middle.strong_count(), bytes.strong_count());
+    }
+
+    #[test]
+    fn secure_bytes_slice_ref_recovers_the_range_of_a_borrowed_subset() {
+        let bytes = SecureBytes::freeze(filled_buffer(b"0123456789"));
+        let subset = &bytes.as_slice()[3..7];
+        let recovered = bytes.slice_ref(subset);
+        assert_eq!(recovered.as_slice(), b"3456");
+    }
+
+    #[test]
+    fn secure_bytes_truncate_shrinks_without_reallocating() {
+        let mut bytes = SecureBytes::freeze(filled_buffer(b"0123456789"));
+        bytes.truncate(4);
+        assert_eq!(bytes.as_slice(), b"0123");
+        // A truncate past the current length is a no-op, not a grow.
+        bytes.truncate(100);
+        assert_eq!(bytes.len(), 4);
+    }
+
+    #[test]
+    fn secure_cursor_read_write_round_trips_and_advances_position() {
+        let mut cursor = SecureCursor::new(SecureBuffer::new(8).unwrap());
+        let written = cursor.write(b"abcd").unwrap();
+        assert_eq!(written, 4);
+        assert_eq!(cursor.position(), 4);
+
+        cursor.seek(SeekFrom::Start(0)).unwrap();
+        let mut out = [0u8; 4];
+        let read = cursor.read(&mut out).unwrap();
+        assert_eq!(read, 4);
+        assert_eq!(&out, b"abcd");
+    }
+
+    #[test]
+    fn secure_cursor_write_rejects_data_past_capacity() {
+        let mut cursor = SecureCursor::new(SecureBuffer::new(4).unwrap());
+        let err = cursor.write(b"too long").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::WriteZero);
+    }
+
+    #[test]
+    fn secure_cursor_seek_rejects_out_of_bounds_positions() {
+        let mut cursor = SecureCursor::new(SecureBuffer::new(4).unwrap());
+        assert!(cursor.seek(SeekFrom::Start(5)).is_err());
+        assert!(cursor.seek(SeekFrom::Current(-1)).is_err());
+        assert_eq!(cursor.seek(SeekFrom::End(0)).unwrap(), 4);
+    }
+
+    #[test]
+    fn secure_buffer_read_buf_fills_an_uninitialized_cursor() {
+        let buffer = filled_buffer(b"secret");
+        let mut storage = [MaybeUninit::<u8>::uninit(); 16];
+        let mut cursor = UninitCursor::new(&mut storage);
+
+        let n = buffer.read_buf(&mut cursor).unwrap();
+        assert_eq!(n, 6);
+        assert_eq!(cursor.filled(), b"secret");
+        assert_eq!(cursor.remaining_capacity(), 10);
+    }
+
+    #[test]
+    fn secure_buffer_read_buf_truncates_to_the_cursors_remaining_capacity() {
+        let buffer = filled_buffer(b"0123456789");
+        let mut storage = [MaybeUninit::<u8>::uninit(); 4];
+        let mut cursor = UninitCursor::new(&mut storage);
+
+        let n = buffer.read_buf(&mut cursor).unwrap();
+        assert_eq!(n, 4);
+        assert_eq!(cursor.filled(), b"0123");
+    }
+}
@@ -4,19 +4,31 @@
 
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
-use std::time::{SystemTime, UNIX_EPOCH, Duration};
+use std::time::{SystemTime, UNIX_EPOCH};
 use sha2::{Sha256, Digest};
 use rand::{thread_rng, RngCore, Rng};
 
-#[cfg(feature = "ipfs")]
-use reqwest::Client;
-
 use thiserror::Error;
 use tokio::sync::RwLock;
 use log::{info, warn, error, debug};
+use k256::schnorr::signature::Verifier;
+use k256::schnorr::{Signature, VerifyingKey};
+use serde::{Serialize, Deserialize};
+use rayon::prelude::*;
+
+use crate::merkle::{verify_inclusion, MerkleLeafProof, MerkleTree};
+use crate::storage_backend::StorageBackend;
+use crate::challenge_store::{ChallengeStore, InMemoryChallengeStore};
+
+/// Number of fixed-size chunks a file is notionally split into for Merkle
+/// proof-of-retrievability. Kept small and fixed rather than derived from
+/// real file size, since this service doesn't hold file bytes itself.
+const MERKLE_CHUNK_COUNT: usize = 8;
+/// How many leaves get challenged per proof round.
+const MERKLE_CHALLENGE_LEAVES: usize = 3;
 
 /// Storage challenge with enhanced cryptographic security
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StorageChallenge {
     pub id: String,
     pub file_id: String,
@@ -30,6 +42,9 @@ pub struct StorageChallenge {
     pub challenge_data: Vec<u8>, // Specific data to prove possession of
     pub sample_offset: u64, // Offset in file to sample
     pub sample_size: u32, // Size of sample to retrieve
+    pub merkle_root: [u8; 32], // Committed Merkle root over the file's chunks
+    pub merkle_chunk_count: usize, // Number of leaves in that tree
+    pub merkle_leaf_indices: Vec<usize>, // Leaves the provider must answer for
 }
 
 /// Storage proof with cryptographic verification data
@@ -40,10 +55,191 @@ pub struct StorageProof {
     pub provider: String,
     pub timestamp: u64,
     pub proof_data: Vec<u8>, // Actual data sample from storage
-    pub merkle_proof: Option<Vec<String>>, // Optional Merkle tree proof
+    pub merkle_proof: Option<Vec<MerkleLeafProof>>, // Answers for the challenged leaves
     pub signature: Option<String>, // Optional provider signature
 }
 
+/// One file's sample parameters within an `AggregateStorageChallenge`. The
+/// offset is derived from the round's shared beacon rather than an
+/// independent RNG call, so a whole round's sampling is reproducible from
+/// one seed instead of N unrelated ones.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AggregateFileChallenge {
+    pub file_id: String,
+    pub sample_offset: u64,
+    pub sample_size: u32,
+    pub challenge_data: Vec<u8>,
+    pub expected_hash: String,
+}
+
+/// One provider challenge spanning multiple files in a single round (see
+/// `StorageVerifier::generate_aggregate_challenge`). `aggregate_root` folds
+/// every file's `expected_hash` into one Merkle commitment, in the same
+/// order as `files`, so `verify_aggregate_proof` can accept or reject the
+/// whole round in one pass instead of verifying each file independently.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AggregateStorageChallenge {
+    pub round_id: String,
+    pub provider: String,
+    pub beacon: String,
+    pub timestamp: u64,
+    pub expiry: u64,
+    pub aggregate_root: [u8; 32],
+    pub files: Vec<AggregateFileChallenge>,
+}
+
+/// A provider's combined response to an `AggregateStorageChallenge`: one
+/// sample per file, in the same order as the challenge's `files` list.
+#[derive(Debug, Clone)]
+pub struct AggregateStorageProof {
+    pub round_id: String,
+    pub provider: String,
+    pub proof_data: Vec<Vec<u8>>,
+}
+
+/// Outcome of `verify_aggregate_proof`. When `verified` is false,
+/// `failed_files` names exactly which files caused the rejection instead of
+/// making the caller re-verify file-by-file to find out.
+#[derive(Debug, Clone)]
+pub struct AggregateVerificationResult {
+    pub verified: bool,
+    pub failed_files: Vec<String>,
+}
+
+/// Stand-in for reading a provider's stored chunks: this service doesn't
+/// hold file bytes, so chunk content is derived deterministically from
+/// `file_id` the same way `expected_hash` and other challenge material
+/// already is. A real storage backend would read these bytes off disk/IPFS
+/// instead.
+fn mock_file_chunks(file_id: &str, chunk_count: usize) -> Vec<Vec<u8>> {
+    (0..chunk_count)
+        .map(|i| {
+            let mut hasher = Sha256::new();
+            hasher.update(file_id.as_bytes());
+            hasher.update(b"chunk");
+            hasher.update((i as u64).to_le_bytes());
+            hasher.finalize().to_vec()
+        })
+        .collect()
+}
+
+/// A registered provider's authentication key, plus whether it belongs to a
+/// tier that must always submit a signed proof rather than just a
+/// data-possession one (see `StorageVerifier::register_provider`).
+#[derive(Clone)]
+struct ProviderAuth {
+    verifying_key: VerifyingKey,
+    signature_required: bool,
+}
+
+/// Canonical, order-stable bytes a provider's proof signature must cover.
+/// Mirrors `signing::canonical_verify_response`'s reasoning: signer and
+/// verifier must hash exactly these bytes, so the layout is fixed rather
+/// than derived from `StorageProof`'s field order.
+fn canonical_provider_proof_message(
+    challenge_id: &str,
+    file_id: &str,
+    sample_offset: u64,
+    sample_size: u32,
+    proof_data: &[u8],
+) -> Vec<u8> {
+    let mut message = Vec::with_capacity(
+        challenge_id.len() + 1 + file_id.len() + 1 + 8 + 4 + proof_data.len(),
+    );
+    message.extend_from_slice(challenge_id.as_bytes());
+    message.push(0); // field separator so file_id can't absorb challenge_id's tail
+    message.extend_from_slice(file_id.as_bytes());
+    message.push(0);
+    message.extend_from_slice(&sample_offset.to_le_bytes());
+    message.extend_from_slice(&sample_size.to_le_bytes());
+    message.extend_from_slice(proof_data);
+    message
+}
+
+/// log2 of the HyperLogLog register count (`m = 2^HLL_PRECISION`). p=12
+/// gives 4096 one-byte registers (4KB) for ~1.6% standard error - enough to
+/// answer "how many distinct providers/files today" without retaining every
+/// id, mirroring the HLL metrics approach used in Neon's proxy.
+const HLL_PRECISION: u32 = 12;
+const HLL_REGISTERS: usize = 1 << HLL_PRECISION;
+
+/// Constant-memory cardinality estimator: hashes each observed id with
+/// SHA256, uses the low `HLL_PRECISION` bits of the hash to pick a register,
+/// and stores the longest run of leading zeros seen in the remaining bits
+/// for that register. `estimate()` combines the registers via the standard
+/// HyperLogLog harmonic-mean formula, with the small-range correction for
+/// sketches that haven't filled up yet.
+#[derive(Debug, Clone)]
+pub struct HyperLogLog {
+    registers: Vec<u8>,
+}
+
+impl HyperLogLog {
+    fn new() -> Self {
+        Self {
+            registers: vec![0u8; HLL_REGISTERS],
+        }
+    }
+
+    /// Record an observation of `id`.
+    pub fn record(&mut self, id: &str) {
+        let mut hasher = Sha256::new();
+        hasher.update(id.as_bytes());
+        let hash = hasher.finalize();
+
+        let mut buf = [0u8; 8];
+        buf.copy_from_slice(&hash[..8]);
+        let value = u64::from_be_bytes(buf);
+
+        let register_index = (value & (HLL_REGISTERS as u64 - 1)) as usize; // low p bits
+        let remainder = value >> HLL_PRECISION;
+        // `leading_zeros` on the full u64 already counts the p bits the
+        // shift vacated, so subtracting them back out gives the rank within
+        // just the remaining (64 - p) bits.
+        let rank = (remainder.leading_zeros() - HLL_PRECISION) as u8 + 1;
+
+        if rank > self.registers[register_index] {
+            self.registers[register_index] = rank;
+        }
+    }
+
+    /// Estimate the number of distinct ids recorded so far.
+    pub fn estimate(&self) -> f64 {
+        let m = HLL_REGISTERS as f64;
+        let alpha_m = 0.7213 / (1.0 + 1.079 / m);
+
+        let sum_of_inverses: f64 = self.registers.iter().map(|&r| 2f64.powi(-(r as i32))).sum();
+        let raw_estimate = alpha_m * m * m / sum_of_inverses;
+
+        if raw_estimate <= 2.5 * m {
+            let zero_registers = self.registers.iter().filter(|&&r| r == 0).count();
+            if zero_registers > 0 {
+                return m * (m / zero_registers as f64).ln();
+            }
+        }
+
+        raw_estimate
+    }
+
+    /// Fold another sketch's observations into this one, so per-shard
+    /// sketches (e.g. one `StorageVerifier` per worker) can be combined into
+    /// a single cardinality estimate without re-observing every id. Standard
+    /// HLL merge: the combined register is the max of the two inputs', which
+    /// is exactly what a single sketch that had seen both shards' ids would
+    /// have recorded.
+    pub fn merge(&mut self, other: &HyperLogLog) {
+        for (register, &other_register) in self.registers.iter_mut().zip(other.registers.iter()) {
+            *register = (*register).max(other_register);
+        }
+    }
+}
+
+impl Default for HyperLogLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Verification metrics for monitoring and analytics
 #[derive(Debug, Clone, Default)]
 pub struct VerificationMetrics {
@@ -52,8 +248,15 @@ pub struct VerificationMetrics {
     pub failed_proofs: u64,
     pub expired_challenges: u64,
     pub rate_limited_requests: u64,
+    /// Failures bucketed by `ProofFailure` cause, a subset of `failed_proofs`
+    /// (the rest being metadata mismatches and expiry, counted above).
+    pub hash_mismatches: u64,
+    pub merkle_mismatches: u64,
+    pub bad_signatures: u64,
     pub average_response_time_ms: f64,
     pub last_reset: u64,
+    provider_cardinality: HyperLogLog,
+    file_cardinality: HyperLogLog,
 }
 
 impl VerificationMetrics {
@@ -64,6 +267,35 @@ impl VerificationMetrics {
         self.successful_proofs as f64 / self.total_challenges as f64
     }
 
+    fn record_provider(&mut self, provider: &str) {
+        self.provider_cardinality.record(provider);
+    }
+
+    fn record_file(&mut self, file_id: &str) {
+        self.file_cardinality.record(file_id);
+    }
+
+    /// Estimated number of distinct providers challenged since the last reset.
+    pub fn estimated_unique_providers(&self) -> f64 {
+        self.provider_cardinality.estimate()
+    }
+
+    /// Estimated number of distinct files challenged since the last reset.
+    pub fn estimated_unique_files(&self) -> f64 {
+        self.file_cardinality.estimate()
+    }
+
+    /// Fold another shard's cardinality sketches into this one, so
+    /// `estimated_unique_providers`/`estimated_unique_files` can report
+    /// across multiple `StorageVerifier` instances (e.g. one per worker)
+    /// without sharing a single sketch between them. Only the HLL registers
+    /// are merged; the running totals (`total_challenges`, etc.) are each
+    /// shard's own concern to sum separately.
+    pub fn merge_cardinality_from(&mut self, other: &VerificationMetrics) {
+        self.provider_cardinality.merge(&other.provider_cardinality);
+        self.file_cardinality.merge(&other.file_cardinality);
+    }
+
     pub fn reset_if_needed(&mut self, now: u64) {
         // Reset metrics daily
         if now - self.last_reset > 86400 {
@@ -75,12 +307,35 @@ impl VerificationMetrics {
     }
 }
 
+/// Why `verify_cryptographic_proof` rejected a proof, so callers/metrics
+/// can bucket the cause instead of treating every rejection alike.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProofFailure {
+    /// `proof_data` doesn't hash to the challenge's `expected_hash`.
+    HashMismatch,
+    /// A `merkle_proof` was missing for a file with a committed root, or
+    /// the supplied authentication path didn't reconstruct that root.
+    MerkleMismatch,
+    /// A `signature` was present but didn't verify against the provider's
+    /// registered key.
+    BadSignature,
+}
+
+/// Result of `StorageVerifier::screen_proof`'s cheap IO-bound checks: either
+/// the proof is already decided (bad metadata, expired) without touching the
+/// CPU-bound path, or it's ready for the hash/Merkle/signature work against
+/// the returned challenge.
+enum ScreenOutcome {
+    Resolved(bool),
+    Ready(StorageChallenge),
+}
+
 /// Enhanced error types for better debugging
 #[derive(Debug, thiserror::Error)]
 pub enum StorageVerificationError {
-    #[error("Rate limit exceeded: {limit} requests per {window}")]
-    RateLimitExceeded { limit: u32, window: String },
-    
+    #[error("Rate limited, retry after {retry_after}s")]
+    RateLimited { retry_after: u64 },
+
     #[error("Invalid input: {field} - {reason}")]
     InvalidInput { field: String, reason: String },
     
@@ -101,8 +356,17 @@ pub enum StorageVerificationError {
     
     #[error("Provider authentication failed")]
     AuthenticationFailed,
+
+    #[error("Persistence error: {reason}")]
+    PersistenceError { reason: String },
+
+    #[error("Hashing task failed: {reason}")]
+    HashingTaskFailed { reason: String },
 }
-/// Rate limiting configuration
+/// Rate limiting configuration for the HTTP-layer `TieredRateLimiter`
+/// (`rate_limiter.rs`), which gates requests before they ever reach a
+/// `StorageVerifier`. `StorageVerifier`'s own per-provider limiting is
+/// configured separately via `TokenBucketConfig`.
 #[derive(Debug, Clone)]
 pub struct RateLimitConfig {
     pub max_requests_per_minute: u32,
@@ -120,81 +384,228 @@ impl Default for RateLimitConfig {
     }
 }
 
-/// Request tracking for DoS protection
+/// Bounds on how much SHA256/Merkle hashing work can run concurrently on
+/// tokio's blocking pool at once.
 #[derive(Debug, Clone)]
-struct RequestTracker {
-    minute_requests: Vec<u64>,
-    hour_requests: Vec<u64>,
-    last_cleanup: u64,
+pub struct HashingConfig {
+    pub max_concurrent_hash_tasks: usize,
 }
 
-impl RequestTracker {
-    fn new() -> Self {
+impl Default for HashingConfig {
+    fn default() -> Self {
         Self {
-            minute_requests: Vec::new(),
-            hour_requests: Vec::new(),
-            last_cleanup: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
+            max_concurrent_hash_tasks: 32,
         }
     }
+}
+
+/// Per-provider token-bucket rate limiting for `StorageVerifier::generate_challenge`.
+/// Unlike a fixed-window counter this doesn't reject bursts outright as
+/// long as the long-run average stays under `cap`, and it's small
+/// (`tokens` + `last_refill`) rather than a growing `Vec` of timestamps.
+#[derive(Debug, Clone)]
+pub struct TokenBucketConfig {
+    /// Requests a bucket refills to over `window_secs`.
+    pub cap: u32,
+    /// Window the cap applies over.
+    pub window_secs: u64,
+    /// Fraction of `cap` a bucket may accumulate and spend in a single
+    /// burst, e.g. 0.99 for latency-sensitive traffic, 0.47 for steady
+    /// throughput.
+    pub burst_pct: f64,
+    /// Slack added to `window_secs` on every refill to absorb clock skew
+    /// between `last_refill` and wall-clock time.
+    pub duration_overhead_secs: u64,
+    /// How many times `generate_challenge_with_retry` sleeps `retry_after`
+    /// and re-attempts before giving up with `RateLimited`.
+    pub retries: u32,
+}
+
+impl Default for TokenBucketConfig {
+    fn default() -> Self {
+        Self::preconfig_throughput()
+    }
+}
 
-    fn cleanup(&mut self, now: u64) {
-        // Remove old requests
-        self.minute_requests.retain(|&ts| now - ts < 60);
-        self.hour_requests.retain(|&ts| now - ts < 3600);
-        self.last_cleanup = now;
+impl TokenBucketConfig {
+    /// Latency-sensitive profile: a bucket can burst through almost its
+    /// whole cap at once, and callers aren't expected to wait out retries.
+    pub fn preconfig_burst() -> Self {
+        Self {
+            cap: 60,
+            window_secs: 60,
+            burst_pct: 0.99,
+            duration_overhead_secs: 2,
+            retries: 1,
+        }
     }
 
-    fn can_make_request(&mut self, now: u64, config: &RateLimitConfig) -> bool {
-        // Auto-cleanup if needed
-        if now - self.last_cleanup > config.cleanup_interval_secs {
-            self.cleanup(now);
+    /// Steady-throughput profile: only a minority of the cap can burst,
+    /// favoring smooth long-run admission over low-latency retries.
+    pub fn preconfig_throughput() -> Self {
+        Self {
+            cap: 1000,
+            window_secs: 3600,
+            burst_pct: 0.47,
+            duration_overhead_secs: 30,
+            retries: 3,
         }
+    }
+}
+
+/// A single provider's token bucket. Refilled lazily on each
+/// `try_consume` rather than on a timer, so idle providers cost nothing.
+#[derive(Debug, Clone)]
+struct TokenBucket {
+    tokens: f64,
+    last_refill: u64,
+}
 
-        self.minute_requests.len() < config.max_requests_per_minute as usize &&
-        self.hour_requests.len() < config.max_requests_per_hour as usize
+impl TokenBucket {
+    fn new(now: u64, config: &TokenBucketConfig) -> Self {
+        Self {
+            tokens: config.burst_pct * config.cap as f64,
+            last_refill: now,
+        }
     }
 
-    fn record_request(&mut self, now: u64) {
-        self.minute_requests.push(now);
-        self.hour_requests.push(now);
+    /// Refills based on elapsed time (capped at the burst allowance), then
+    /// consumes one token if available. On failure, returns the number of
+    /// whole seconds the caller should wait before retrying.
+    fn try_consume(&mut self, now: u64, config: &TokenBucketConfig) -> Result<(), u64> {
+        let window = (config.window_secs + config.duration_overhead_secs).max(1) as f64;
+        let elapsed = now.saturating_sub(self.last_refill) as f64;
+        let burst_cap = config.burst_pct * config.cap as f64;
+
+        self.tokens = (self.tokens + elapsed / window * config.cap as f64).min(burst_cap);
+        self.last_refill = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(())
+        } else {
+            let tokens_needed = 1.0 - self.tokens;
+            let retry_after = (tokens_needed * window / config.cap as f64).ceil() as u64;
+            Err(retry_after.max(1))
+        }
     }
+
+    /// Nothing to prune: refill is computed lazily from `last_refill` in
+    /// `try_consume`, so a bucket holds no per-request state that goes stale.
+    fn cleanup(&mut self, _now: u64) {}
 }
 
 /// Enhanced storage verifier with cryptographic proofs and monitoring
 pub struct StorageVerifier {
     challenges: Arc<tokio::sync::Mutex<HashMap<String, StorageChallenge>>>,
     used_beacons: Arc<tokio::sync::Mutex<HashSet<String>>>,
-    request_trackers: Arc<tokio::sync::Mutex<HashMap<String, RequestTracker>>>,
+    request_trackers: Arc<tokio::sync::Mutex<HashMap<String, TokenBucket>>>,
     metrics: Arc<tokio::sync::Mutex<VerificationMetrics>>,
-    rate_limit_config: RateLimitConfig,
-    #[cfg(feature = "ipfs")]
-    http_client: Option<Client>,
+    /// Where challenge samples actually get fetched from. Generalizes what
+    /// used to be an `ipfs`-feature-only `reqwest::Client` hardwired to
+    /// public IPFS gateways - the same challenge/proof machinery now works
+    /// against any `StorageBackend` (in-memory, S3/Garage, IPFS, ...).
+    backend: Box<dyn StorageBackend>,
+    /// Pinned per-file Merkle roots, set via `register_file_root`. Read far
+    /// more often (every `generate_challenge`) than written, hence `RwLock`
+    /// rather than the `Mutex` used for the other, write-heavy maps.
+    file_roots: Arc<RwLock<HashMap<String, [u8; 32]>>>,
+    /// Registered provider authentication keys, set via `register_provider`.
+    provider_keys: Arc<RwLock<HashMap<String, ProviderAuth>>>,
+    /// Durable backing store for challenges/beacons. `challenges` and
+    /// `used_beacons` above stay as a fast in-memory write-through cache
+    /// over this; an in-memory-only store (the default) makes that cache
+    /// the only copy, same as before this field existed.
+    store: Arc<dyn ChallengeStore>,
+    bucket_config: TokenBucketConfig,
+    /// Bounds concurrent `spawn_blocking` hashing tasks so a flood of
+    /// `verify_proof` calls can't starve tokio's blocking pool.
+    hashing_semaphore: Arc<tokio::sync::Semaphore>,
+    /// Outstanding multi-file rounds from `generate_aggregate_challenge`,
+    /// keyed by `round_id`. Kept separately from `challenges` since an
+    /// `AggregateStorageChallenge` isn't a `StorageChallenge` and doesn't go
+    /// through `ChallengeStore` - a round that doesn't survive a restart
+    /// just has to be re-issued, same as losing any other in-flight request.
+    aggregate_challenges: Arc<tokio::sync::Mutex<HashMap<String, AggregateStorageChallenge>>>,
 }
 
 impl StorageVerifier {
     /// Create new verifier with default rate limiting
     pub fn new() -> Self {
-        Self::with_config(RateLimitConfig::default())
+        Self::with_config(TokenBucketConfig::default())
+    }
+
+    /// Preset verifier tuned for latency-sensitive traffic - mirrors
+    /// `TokenBucketConfig::preconfig_burst`.
+    pub fn preconfig_burst() -> Self {
+        Self::with_config(TokenBucketConfig::preconfig_burst())
+    }
+
+    /// Preset verifier tuned for steady long-run throughput - mirrors
+    /// `TokenBucketConfig::preconfig_throughput`.
+    pub fn preconfig_throughput() -> Self {
+        Self::with_config(TokenBucketConfig::preconfig_throughput())
+    }
+
+    /// Create new verifier with custom rate limiting, using the default
+    /// backend for this build (the multi-gateway IPFS fetcher when the
+    /// `ipfs` feature is enabled, otherwise an empty in-memory backend).
+    pub fn with_config(config: TokenBucketConfig) -> Self {
+        Self::with_backend(config, Self::default_backend())
+    }
+
+    /// Create new verifier with custom rate limiting and an explicit
+    /// storage backend, e.g. to verify an S3/Garage-backed provider or to
+    /// preload an `InMemoryBackend` for tests.
+    pub fn with_backend(config: TokenBucketConfig, backend: Box<dyn StorageBackend>) -> Self {
+        Self::with_store(config, backend, Arc::new(InMemoryChallengeStore::new()))
+    }
+
+    /// Create new verifier with custom rate limiting, storage backend, and
+    /// an explicit `ChallengeStore` - e.g. a `FileChallengeStore` so
+    /// outstanding challenges and replay protection survive a restart.
+    pub fn with_store(
+        config: TokenBucketConfig,
+        backend: Box<dyn StorageBackend>,
+        store: Arc<dyn ChallengeStore>,
+    ) -> Self {
+        Self::with_hashing_config(config, backend, store, HashingConfig::default())
     }
 
-    /// Create new verifier with custom rate limiting
-    pub fn with_config(config: RateLimitConfig) -> Self {
+    /// Create new verifier with an explicit bound on concurrent blocking
+    /// hash tasks, e.g. to give a high-traffic deployment more headroom
+    /// than `HashingConfig::default()`.
+    pub fn with_hashing_config(
+        config: TokenBucketConfig,
+        backend: Box<dyn StorageBackend>,
+        store: Arc<dyn ChallengeStore>,
+        hashing_config: HashingConfig,
+    ) -> Self {
         Self {
             challenges: Arc::new(tokio::sync::Mutex::new(HashMap::new())),
             used_beacons: Arc::new(tokio::sync::Mutex::new(HashSet::new())),
             request_trackers: Arc::new(tokio::sync::Mutex::new(HashMap::new())),
             metrics: Arc::new(tokio::sync::Mutex::new(VerificationMetrics::default())),
-            rate_limit_config: config,
-            #[cfg(feature = "ipfs")]
-            http_client: Some(Client::builder()
-                .timeout(Duration::from_secs(10))
-                .user_agent("UniversalSprint/1.0")
-                .build()
-                .unwrap_or_else(|_| Client::new())
-            ),
+            backend,
+            file_roots: Arc::new(RwLock::new(HashMap::new())),
+            provider_keys: Arc::new(RwLock::new(HashMap::new())),
+            store,
+            bucket_config: config,
+            hashing_semaphore: Arc::new(tokio::sync::Semaphore::new(hashing_config.max_concurrent_hash_tasks)),
+            aggregate_challenges: Arc::new(tokio::sync::Mutex::new(HashMap::new())),
         }
     }
 
+    #[cfg(feature = "ipfs")]
+    fn default_backend() -> Box<dyn StorageBackend> {
+        Box::new(crate::storage_backend::IpfsGatewayBackend::new())
+    }
+
+    #[cfg(not(feature = "ipfs"))]
+    fn default_backend() -> Box<dyn StorageBackend> {
+        Box::new(crate::storage_backend::InMemoryBackend::new())
+    }
+
     /// Generate secure storage challenge with cryptographic requirements
     pub async fn generate_challenge(&self, file_id: &str, provider: &str) -> Result<StorageChallenge, StorageVerificationError> {
         let start_time = SystemTime::now();
@@ -217,17 +628,15 @@ impl StorageVerifier {
         // Rate limiting check
         {
             let mut trackers = self.request_trackers.lock().await;
-            let tracker = trackers.entry(provider.to_string()).or_insert_with(RequestTracker::new);
-            
-            if !tracker.can_make_request(now, &self.rate_limit_config) {
+            let bucket = trackers
+                .entry(provider.to_string())
+                .or_insert_with(|| TokenBucket::new(now, &self.bucket_config));
+
+            if let Err(retry_after) = bucket.try_consume(now, &self.bucket_config) {
                 let mut metrics = self.metrics.lock().await;
                 metrics.rate_limited_requests += 1;
-                return Err(StorageVerificationError::RateLimitExceeded {
-                    limit: self.rate_limit_config.max_requests_per_minute,
-                    window: "minute".to_string(),
-                });
+                return Err(StorageVerificationError::RateLimited { retry_after });
             }
-            tracker.record_request(now);
         }
 
         // Generate cryptographic challenge
@@ -245,14 +654,36 @@ impl StorageVerifier {
         // Generate expected hash from challenge parameters
         let expected_hash = self.generate_expected_hash(file_id, &challenge_data, sample_offset, sample_size)?;
 
-        // Replay protection
+        // Commit to a Merkle root over the file's chunks, and pick which
+        // leaves the provider must answer for with an authentication path.
+        // A root registered via `register_file_root` (e.g. computed by a
+        // real storage backend from the file's actual chunks) takes
+        // precedence over the `mock_file_chunks` stand-in.
+        let merkle_root = self.resolve_merkle_root(file_id).await?;
+        let mut merkle_leaf_indices = Vec::with_capacity(MERKLE_CHALLENGE_LEAVES);
+        while merkle_leaf_indices.len() < MERKLE_CHALLENGE_LEAVES.min(MERKLE_CHUNK_COUNT) {
+            let idx = rng.gen_range(0..MERKLE_CHUNK_COUNT);
+            if !merkle_leaf_indices.contains(&idx) {
+                merkle_leaf_indices.push(idx);
+            }
+        }
+
+        // Replay protection. Checked against both the in-memory cache and
+        // the durable store, so a beacon used just before a restart still
+        // can't be replayed just after one.
         {
-            let mut used = self.used_beacons.lock().await;
-            if used.contains(&beacon) {
+            let already_used = {
+                let used = self.used_beacons.lock().await;
+                used.contains(&beacon)
+            } || self.store.has_beacon(&beacon).await?;
+            if already_used {
                 return Err(StorageVerificationError::CryptographicFailure {
                     reason: "Beacon collision detected".to_string(),
                 });
             }
+
+            self.store.put_beacon(&beacon, now + 1800).await?;
+            let mut used = self.used_beacons.lock().await;
             used.insert(beacon.clone());
 
             // Cleanup old beacons periodically
@@ -281,9 +712,15 @@ impl StorageVerifier {
             challenge_data,
             sample_offset,
             sample_size,
+            merkle_root,
+            merkle_chunk_count: MERKLE_CHUNK_COUNT,
+            merkle_leaf_indices,
         };
 
-        // Store challenge with automatic cleanup
+        // Store challenge with automatic cleanup. Written through to the
+        // durable store first, so a crash right after this never leaves a
+        // challenge only in memory.
+        self.store.put_challenge(&challenge).await?;
         {
             let mut challenges = self.challenges.lock().await;
             challenges.insert(challenge.id.clone(), challenge.clone());
@@ -299,6 +736,8 @@ impl StorageVerifier {
             let mut metrics = self.metrics.lock().await;
             metrics.reset_if_needed(now);
             metrics.total_challenges += 1;
+            metrics.record_provider(provider);
+            metrics.record_file(file_id);
         }
 
         log::info!("Generated challenge {} for provider {} file {}", 
@@ -307,11 +746,314 @@ impl StorageVerifier {
         Ok(challenge)
     }
 
-    /// Verify storage proof with enhanced cryptographic verification
-    pub async fn verify_proof(&self, proof: StorageProof) -> Result<bool, StorageVerificationError> {
-        let start_time = SystemTime::now();
-        let now = start_time.duration_since(UNIX_EPOCH).unwrap().as_secs();
+    /// Same as `generate_challenge`, but on `RateLimited` sleeps
+    /// `retry_after` and re-attempts up to `bucket_config.retries` times
+    /// before giving up with the last `RateLimited` error.
+    pub async fn generate_challenge_with_retry(
+        &self,
+        file_id: &str,
+        provider: &str,
+    ) -> Result<StorageChallenge, StorageVerificationError> {
+        let mut attempts = 0;
+        loop {
+            match self.generate_challenge(file_id, provider).await {
+                Err(StorageVerificationError::RateLimited { retry_after })
+                    if attempts < self.bucket_config.retries =>
+                {
+                    attempts += 1;
+                    tokio::time::sleep(std::time::Duration::from_secs(retry_after)).await;
+                }
+                other => return other,
+            }
+        }
+    }
+
+    /// Resolve the Merkle root to commit a challenge to: a root registered
+    /// via `register_file_root` (e.g. computed by a real storage backend
+    /// from the file's actual chunks) takes precedence over the
+    /// `mock_file_chunks` stand-in. Shared by `generate_challenge` and
+    /// `generate_aggregate_challenge` so both commit to the same root for a
+    /// given file.
+    async fn resolve_merkle_root(&self, file_id: &str) -> Result<[u8; 32], StorageVerificationError> {
+        let registered_root = self.file_roots.read().await.get(file_id).copied();
+        match registered_root {
+            Some(root) => Ok(root),
+            None => {
+                let chunks = mock_file_chunks(file_id, MERKLE_CHUNK_COUNT);
+                let merkle_tree = MerkleTree::from_chunks(&chunks).map_err(|e| {
+                    StorageVerificationError::CryptographicFailure {
+                        reason: format!("failed to build Merkle tree: {}", e),
+                    }
+                })?;
+                Ok(merkle_tree.root())
+            }
+        }
+    }
+
+    /// Deterministically derive a file's sample offset from a round beacon,
+    /// so every file in an `AggregateStorageChallenge` samples from one
+    /// shared seed instead of an independent RNG draw per file.
+    fn derive_sample_offset(beacon: &str, file_id: &str) -> u64 {
+        let mut hasher = Sha256::new();
+        hasher.update(beacon.as_bytes());
+        hasher.update(file_id.as_bytes());
+        let hash = hasher.finalize();
+        let mut buf = [0u8; 8];
+        buf.copy_from_slice(&hash[..8]);
+        u64::from_be_bytes(buf) % 1_000_000
+    }
+
+    /// Build one challenge covering several files from the same provider in
+    /// a single round, instead of paying `generate_challenge`'s rate-limit
+    /// and round-trip cost once per file. The round's beacon seeds every
+    /// file's sample offset (see `derive_sample_offset`), and each file's
+    /// `expected_hash` is folded into one `aggregate_root` Merkle
+    /// commitment that `verify_aggregate_proof` checks in one pass.
+    pub async fn generate_aggregate_challenge(
+        &self,
+        files: &[&str],
+        provider: &str,
+    ) -> Result<AggregateStorageChallenge, StorageVerificationError> {
+        if files.is_empty() || provider.is_empty() {
+            return Err(StorageVerificationError::InvalidInput {
+                field: "files or provider".to_string(),
+                reason: "Cannot be empty".to_string(),
+            });
+        }
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+
+        // One rate-limit charge for the whole round rather than one per file.
+        {
+            let mut trackers = self.request_trackers.lock().await;
+            let bucket = trackers
+                .entry(provider.to_string())
+                .or_insert_with(|| TokenBucket::new(now, &self.bucket_config));
+            if let Err(retry_after) = bucket.try_consume(now, &self.bucket_config) {
+                let mut metrics = self.metrics.lock().await;
+                metrics.rate_limited_requests += 1;
+                return Err(StorageVerificationError::RateLimited { retry_after });
+            }
+        }
+
+        let mut rng = thread_rng();
+        let random_salt: u64 = rng.gen();
+        // Seeds the beacon on the whole file set rather than a single
+        // `file_id`, so two rounds over different file sets for the same
+        // provider at the same timestamp still get distinct beacons.
+        let round_key = files.join(",");
+        let beacon = self.generate_beacon(&round_key, provider, now, random_salt)?;
+
+        // Replay protection, same mechanism as a single `generate_challenge`.
+        {
+            let already_used = {
+                let used = self.used_beacons.lock().await;
+                used.contains(&beacon)
+            } || self.store.has_beacon(&beacon).await?;
+            if already_used {
+                return Err(StorageVerificationError::CryptographicFailure {
+                    reason: "Beacon collision detected".to_string(),
+                });
+            }
+            self.store.put_beacon(&beacon, now + 1800).await?;
+            self.used_beacons.lock().await.insert(beacon.clone());
+        }
+
+        let mut file_challenges = Vec::with_capacity(files.len());
+        let mut leaves = Vec::with_capacity(files.len());
+        for &file_id in files {
+            let sample_offset = Self::derive_sample_offset(&beacon, file_id);
+            let sample_size: u32 = rng.gen_range(512..4096);
+
+            let mut challenge_data = vec![0u8; 32];
+            rng.fill_bytes(&mut challenge_data);
+
+            let expected_hash =
+                self.generate_expected_hash(file_id, &challenge_data, sample_offset, sample_size)?;
+
+            leaves.push(expected_hash.as_bytes().to_vec());
+            file_challenges.push(AggregateFileChallenge {
+                file_id: file_id.to_string(),
+                sample_offset,
+                sample_size,
+                challenge_data,
+                expected_hash,
+            });
+        }
+
+        let aggregate_root = MerkleTree::from_chunks(&leaves)
+            .map_err(|e| StorageVerificationError::CryptographicFailure {
+                reason: format!("failed to build aggregate Merkle tree: {}", e),
+            })?
+            .root();
+
+        let round_id = format!("round_{}_{:x}", &provider[..std::cmp::min(provider.len(), 8)], now);
+        let challenge = AggregateStorageChallenge {
+            round_id: round_id.clone(),
+            provider: provider.to_string(),
+            beacon,
+            timestamp: now,
+            expiry: now + 1800,
+            aggregate_root,
+            files: file_challenges,
+        };
+
+        self.aggregate_challenges
+            .lock()
+            .await
+            .insert(round_id.clone(), challenge.clone());
+
+        {
+            let mut metrics = self.metrics.lock().await;
+            metrics.reset_if_needed(now);
+            metrics.total_challenges += files.len() as u64;
+            metrics.record_provider(provider);
+            for file_id in files {
+                metrics.record_file(file_id);
+            }
+        }
+
+        log::info!(
+            "Generated aggregate challenge {} for provider {} covering {} files",
+            round_id,
+            provider,
+            challenge.files.len()
+        );
+
+        Ok(challenge)
+    }
+
+    /// Verify an `AggregateStorageProof` in one pass: recompute every file's
+    /// leaf hash from its submitted sample, fold them into a Merkle root the
+    /// same way `generate_aggregate_challenge` did, and compare against the
+    /// challenge's committed `aggregate_root`. Only on a mismatch does this
+    /// walk the per-file hashes to report which ones actually failed -
+    /// the common (all-pass) case never pays for that.
+    pub async fn verify_aggregate_proof(
+        &self,
+        proof: AggregateStorageProof,
+    ) -> Result<AggregateVerificationResult, StorageVerificationError> {
+        if proof.round_id.is_empty() || proof.provider.is_empty() {
+            return Err(StorageVerificationError::InvalidInput {
+                field: "round_id or provider".to_string(),
+                reason: "Cannot be empty".to_string(),
+            });
+        }
+
+        let challenge = self
+            .aggregate_challenges
+            .lock()
+            .await
+            .get(&proof.round_id)
+            .cloned()
+            .ok_or_else(|| StorageVerificationError::ChallengeNotFound {
+                challenge_id: proof.round_id.clone(),
+            })?;
+
+        let all_files = || challenge.files.iter().map(|f| f.file_id.clone()).collect();
+
+        if proof.provider != challenge.provider {
+            let mut metrics = self.metrics.lock().await;
+            metrics.failed_proofs += challenge.files.len() as u64;
+            return Ok(AggregateVerificationResult {
+                verified: false,
+                failed_files: all_files(),
+            });
+        }
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+        if now > challenge.expiry {
+            let mut metrics = self.metrics.lock().await;
+            metrics.expired_challenges += 1;
+            return Ok(AggregateVerificationResult {
+                verified: false,
+                failed_files: all_files(),
+            });
+        }
+
+        if proof.proof_data.len() != challenge.files.len() {
+            return Err(StorageVerificationError::InvalidInput {
+                field: "proof_data".to_string(),
+                reason: format!(
+                    "expected {} per-file samples, got {}",
+                    challenge.files.len(),
+                    proof.proof_data.len()
+                ),
+            });
+        }
+
+        let files = challenge.files.clone();
+        let samples = proof.proof_data.clone();
+        let computed_hashes = self
+            .run_blocking_hash(move || {
+                files
+                    .iter()
+                    .zip(samples.iter())
+                    .map(|(file_challenge, sample_data)| {
+                        // A wrongly-sized sample can never be the right one;
+                        // short-circuit to a hash that can't possibly match
+                        // rather than hashing it, so it still shows up as a
+                        // per-file failure instead of a different failure
+                        // mode than every other rejected file.
+                        if sample_data.len() != file_challenge.sample_size as usize {
+                            return String::new();
+                        }
+                        let mut hasher = Sha256::new();
+                        hasher.update(sample_data);
+                        hasher.update(&file_challenge.challenge_data);
+                        hasher.update(file_challenge.sample_offset.to_le_bytes());
+                        hasher.update(file_challenge.sample_size.to_le_bytes());
+                        hex::encode(hasher.finalize())
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .await?;
+
+        let computed_leaves: Vec<Vec<u8>> = computed_hashes.iter().map(|h| h.as_bytes().to_vec()).collect();
+        let computed_root = MerkleTree::from_chunks(&computed_leaves)
+            .map_err(|e| StorageVerificationError::CryptographicFailure {
+                reason: format!("failed to build aggregate Merkle tree: {}", e),
+            })?
+            .root();
+
+        let (verified, failed_files) = if computed_root == challenge.aggregate_root {
+            (true, Vec::new())
+        } else {
+            let failed: Vec<String> = challenge
+                .files
+                .iter()
+                .zip(computed_hashes.iter())
+                .filter(|(file_challenge, computed)| file_challenge.expected_hash != **computed)
+                .map(|(file_challenge, _)| file_challenge.file_id.clone())
+                .collect();
+            (false, failed)
+        };
+
+        {
+            let mut metrics = self.metrics.lock().await;
+            let failed_count = failed_files.len() as u64;
+            metrics.successful_proofs += challenge.files.len() as u64 - failed_count;
+            metrics.failed_proofs += failed_count;
+            metrics.hash_mismatches += failed_count;
+        }
+
+        Ok(AggregateVerificationResult {
+            verified,
+            failed_files,
+        })
+    }
 
+    /// Cheap, IO-bound checks shared by `verify_proof` and
+    /// `verify_proofs_batch`: challenge lookup (cache then durable store
+    /// fallback), metadata match, expiry, and timestamp skew. Resolves a
+    /// proof outright when one of those fails without ever reaching the
+    /// CPU-bound hash/Merkle/signature work, or hands back the looked-up
+    /// `StorageChallenge` once it's clear that work is needed.
+    async fn screen_proof(
+        &self,
+        proof: &StorageProof,
+        now: u64,
+    ) -> Result<ScreenOutcome, StorageVerificationError> {
         // Input validation
         if proof.challenge_id.is_empty() || proof.file_id.is_empty() || proof.provider.is_empty() {
             return Err(StorageVerificationError::InvalidInput {
@@ -320,24 +1062,40 @@ impl StorageVerifier {
             });
         }
 
-        let challenges = self.challenges.lock().await;
-        let challenge = challenges.get(&proof.challenge_id)
-            .ok_or_else(|| StorageVerificationError::ChallengeNotFound {
-                challenge_id: proof.challenge_id.clone(),
-            })?;
+        // Look up the challenge in the in-memory cache first; fall back to
+        // the durable store (e.g. after a restart dropped the cache) and
+        // repopulate the cache from it so later lookups stay fast.
+        let cached = self.challenges.lock().await.get(&proof.challenge_id).cloned();
+        let challenge = match cached {
+            Some(challenge) => challenge,
+            None => match self.store.get_challenge(&proof.challenge_id).await? {
+                Some(challenge) => {
+                    self.challenges
+                        .lock()
+                        .await
+                        .insert(challenge.id.clone(), challenge.clone());
+                    challenge
+                }
+                None => {
+                    return Err(StorageVerificationError::ChallengeNotFound {
+                        challenge_id: proof.challenge_id.clone(),
+                    })
+                }
+            },
+        };
 
         // Basic metadata verification
         if proof.file_id != challenge.file_id || proof.provider != challenge.provider {
             let mut metrics = self.metrics.lock().await;
             metrics.failed_proofs += 1;
-            return Ok(false);
+            return Ok(ScreenOutcome::Resolved(false));
         }
 
         // Expiry check
         if now > challenge.expiry {
             let mut metrics = self.metrics.lock().await;
             metrics.expired_challenges += 1;
-            return Ok(false);
+            return Ok(ScreenOutcome::Resolved(false));
         }
 
         // Timestamp validation (allow some clock skew)
@@ -347,32 +1105,69 @@ impl StorageVerifier {
             });
         }
 
+        Ok(ScreenOutcome::Ready(challenge))
+    }
+
+    /// Verify storage proof with enhanced cryptographic verification
+    pub async fn verify_proof(&self, proof: StorageProof) -> Result<bool, StorageVerificationError> {
+        let start_time = SystemTime::now();
+        let now = start_time.duration_since(UNIX_EPOCH).unwrap().as_secs();
+
+        let challenge = match self.screen_proof(&proof, now).await? {
+            ScreenOutcome::Resolved(result) => return Ok(result),
+            ScreenOutcome::Ready(challenge) => challenge,
+        };
+
         // Cryptographic proof verification
-        let is_valid = self.verify_cryptographic_proof(&proof, challenge).await?;
+        let failure = self.verify_cryptographic_proof(&proof, &challenge).await?;
+        let is_valid = failure.is_none();
 
         // Update metrics
         {
             let mut metrics = self.metrics.lock().await;
             let elapsed = start_time.elapsed().unwrap_or_default().as_millis() as f64;
-            metrics.average_response_time_ms = 
+            metrics.average_response_time_ms =
                 (metrics.average_response_time_ms + elapsed) / 2.0;
-            
-            if is_valid {
-                metrics.successful_proofs += 1;
-                log::info!("Proof verified successfully: {} for provider {}", 
-                          proof.challenge_id, proof.provider);
-            } else {
-                metrics.failed_proofs += 1;
-                log::warn!("Proof verification failed: {} for provider {}", 
-                          proof.challenge_id, proof.provider);
+
+            match failure {
+                None => {
+                    metrics.successful_proofs += 1;
+                    log::info!("Proof verified successfully: {} for provider {}",
+                              proof.challenge_id, proof.provider);
+                }
+                Some(cause) => {
+                    metrics.failed_proofs += 1;
+                    match cause {
+                        ProofFailure::HashMismatch => metrics.hash_mismatches += 1,
+                        ProofFailure::MerkleMismatch => metrics.merkle_mismatches += 1,
+                        ProofFailure::BadSignature => metrics.bad_signatures += 1,
+                    }
+                    log::warn!("Proof verification failed ({:?}): {} for provider {}",
+                              cause, proof.challenge_id, proof.provider);
+                }
             }
         }
 
         Ok(is_valid)
     }
 
-    /// Perform cryptographic verification of the storage proof
-    async fn verify_cryptographic_proof(&self, proof: &StorageProof, challenge: &StorageChallenge) -> Result<bool, StorageVerificationError> {
+    /// Perform cryptographic verification of the storage proof, mirroring
+    /// how a counterparty validates a received transaction before acting
+    /// on it: hash check, then Merkle inclusion, then signature, each
+    /// against state this verifier already committed to (`expected_hash`,
+    /// `merkle_root`, the provider's registered key) - never against
+    /// anything the proof itself supplies unchecked. Returns `None` when
+    /// every applicable check passes, or the first `ProofFailure` cause
+    /// otherwise. All of the actual CPU-bound hashing runs on tokio's
+    /// blocking pool via `run_blocking_hash` - this async fn itself only
+    /// clones small pieces of `proof`/`challenge` to move into those
+    /// blocking closures, never hashing multi-kilobyte samples directly on
+    /// the worker thread.
+    async fn verify_cryptographic_proof(
+        &self,
+        proof: &StorageProof,
+        challenge: &StorageChallenge,
+    ) -> Result<Option<ProofFailure>, StorageVerificationError> {
         // Verify proof data is not empty
         if proof.proof_data.is_empty() {
             return Err(StorageVerificationError::CryptographicFailure {
@@ -383,69 +1178,461 @@ impl StorageVerifier {
         // Verify proof data size matches expected sample size
         if proof.proof_data.len() != challenge.sample_size as usize {
             return Err(StorageVerificationError::CryptographicFailure {
-                reason: format!("Proof data size {} does not match expected {}", 
+                reason: format!("Proof data size {} does not match expected {}",
                                proof.proof_data.len(), challenge.sample_size),
             });
         }
 
         // Generate hash from proof data combined with challenge data
-        let mut hasher = Sha256::new();
-        hasher.update(&proof.proof_data);
-        hasher.update(&challenge.challenge_data);
-        hasher.update(challenge.sample_offset.to_le_bytes());
-        hasher.update(challenge.sample_size.to_le_bytes());
-        let computed_hash = hex::encode(hasher.finalize());
+        let proof_data = proof.proof_data.clone();
+        let challenge_data = challenge.challenge_data.clone();
+        let sample_offset = challenge.sample_offset;
+        let sample_size = challenge.sample_size;
+        let expected_hash = challenge.expected_hash.clone();
+
+        let hash_matches = self
+            .run_blocking_hash(move || {
+                let mut hasher = Sha256::new();
+                hasher.update(&proof_data);
+                hasher.update(&challenge_data);
+                hasher.update(sample_offset.to_le_bytes());
+                hasher.update(sample_size.to_le_bytes());
+                hex::encode(hasher.finalize()) == expected_hash
+            })
+            .await?;
 
         // Verify computed hash matches expected hash
-        if computed_hash != challenge.expected_hash {
-            log::debug!("Hash mismatch: computed={}, expected={}", 
-                       computed_hash, challenge.expected_hash);
-            return Ok(false);
+        if !hash_matches {
+            debug!("Hash mismatch for challenge {}", challenge.id);
+            return Ok(Some(ProofFailure::HashMismatch));
         }
 
-        // Optional: Verify Merkle proof if provided
-        if let Some(ref merkle_proof) = proof.merkle_proof {
-            if !self.verify_merkle_proof(merkle_proof, &proof.proof_data, &challenge.file_id)? {
-                return Ok(false);
+        // Merkle inclusion: required whenever this file has a registered
+        // root, not just when the proof happens to include one - a proof
+        // that silently omits it must fail rather than pass by default.
+        let root_registered = self.file_roots.read().await.contains_key(&challenge.file_id);
+        match &proof.merkle_proof {
+            Some(merkle_proof) => {
+                let merkle_proof = merkle_proof.clone();
+                let challenge_owned = challenge.clone();
+                let merkle_ok = self
+                    .run_blocking_hash(move || Self::verify_merkle_proof(&merkle_proof, &challenge_owned))
+                    .await??;
+                if !merkle_ok {
+                    return Ok(Some(ProofFailure::MerkleMismatch));
+                }
+            }
+            None if root_registered => {
+                debug!("Missing Merkle proof for registered file {}", challenge.file_id);
+                return Ok(Some(ProofFailure::MerkleMismatch));
             }
+            None => {}
         }
 
-        // Optional: Verify provider signature if provided
-        if let Some(ref signature) = proof.signature {
-            if !self.verify_provider_signature(signature, &proof.proof_data, &proof.provider)? {
-                return Ok(false);
+        // Provider signature authentication: a signature, when present, must
+        // verify against that provider's registered key. A provider
+        // registered with `signature_required` must always include one.
+        match &proof.signature {
+            Some(signature) => {
+                if !self.verify_provider_signature(signature, proof, challenge).await? {
+                    return Ok(Some(ProofFailure::BadSignature));
+                }
+            }
+            None => {
+                if self.provider_signature_required(&proof.provider).await {
+                    return Err(StorageVerificationError::AuthenticationFailed);
+                }
             }
         }
 
-        Ok(true)
+        Ok(None)
     }
 
-    /// Generate expected hash for cryptographic verification
-    fn generate_expected_hash(&self, file_id: &str, challenge_data: &[u8], sample_offset: u64, sample_size: u32) -> Result<String, StorageVerificationError> {
-        let mut hasher = Sha256::new();
-        hasher.update(file_id.as_bytes());
-        hasher.update(challenge_data);
-        hasher.update(sample_offset.to_le_bytes());
-        hasher.update(sample_size.to_le_bytes());
+    /// Verify many proofs at once the way a high-throughput validator would:
+    /// the cheap async/IO phase (`screen_proof` - challenge lookup, metadata,
+    /// expiry, timestamp skew) runs per proof on the tokio runtime first, same
+    /// as `verify_proof`. Proofs that survive it batch up and the CPU-heavy
+    /// hash/Merkle/signature work is dispatched across a rayon pool inside a
+    /// single `spawn_blocking`, instead of one `spawn_blocking` per proof.
+    /// Results come back in input order. The parallel phase's outcomes are
+    /// counted under a single `metrics` lock taken after it joins, so
+    /// concurrent `verify_proofs_batch` calls never interleave those counter
+    /// updates; metadata and expiry failures found during screening are
+    /// counted as they are found, one lock each, as in `verify_proof`.
+    pub async fn verify_proofs_batch(
+        &self,
+        proofs: Vec<StorageProof>,
+    ) -> Vec<Result<bool, StorageVerificationError>> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+
+        let mut results: Vec<Option<Result<bool, StorageVerificationError>>> =
+            (0..proofs.len()).map(|_| None).collect();
+        let mut pending = Vec::new();
+
+        for (idx, proof) in proofs.into_iter().enumerate() {
+            match self.screen_proof(&proof, now).await {
+                Ok(ScreenOutcome::Resolved(result)) => results[idx] = Some(Ok(result)),
+                Ok(ScreenOutcome::Ready(challenge)) => pending.push((idx, proof, challenge)),
+                Err(e) => results[idx] = Some(Err(e)),
+            }
+        }
+
+        if !pending.is_empty() {
+            let provider_keys = self.provider_keys.read().await.clone();
+            let file_roots = self.file_roots.read().await.clone();
+
+            let outcomes = self
+                .run_blocking_hash(move || {
+                    pending
+                        .into_par_iter()
+                        .map(|(idx, proof, challenge)| {
+                            let root_registered = file_roots.contains_key(&challenge.file_id);
+                            let outcome = Self::verify_crypto_sync(
+                                &proof,
+                                &challenge,
+                                root_registered,
+                                &provider_keys,
+                            );
+                            (idx, proof, outcome)
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .await;
+
+            match outcomes {
+                Ok(outcomes) => {
+                    let mut metrics = self.metrics.lock().await;
+                    for (idx, proof, outcome) in outcomes {
+                        match outcome {
+                            Ok(failure) => {
+                                results[idx] = Some(Ok(failure.is_none()));
+                                match failure {
+                                    None => {
+                                        metrics.successful_proofs += 1;
+                                    }
+                                    Some(cause) => {
+                                        metrics.failed_proofs += 1;
+                                        match cause {
+                                            ProofFailure::HashMismatch => metrics.hash_mismatches += 1,
+                                            ProofFailure::MerkleMismatch => metrics.merkle_mismatches += 1,
+                                            ProofFailure::BadSignature => metrics.bad_signatures += 1,
+                                        }
+                                        debug!(
+                                            "Batch proof verification failed ({:?}): {} for provider {}",
+                                            cause, proof.challenge_id, proof.provider
+                                        );
+                                    }
+                                }
+                            }
+                            Err(e) => results[idx] = Some(Err(e)),
+                        }
+                    }
+                }
+                Err(e) => {
+                    // The blocking task itself failed (panicked or was
+                    // cancelled) - every proof still pending gets that error
+                    // rather than silently resolving as `false`.
+                    let reason = e.to_string();
+                    for result in results.iter_mut() {
+                        if result.is_none() {
+                            *result = Some(Err(StorageVerificationError::HashingTaskFailed {
+                                reason: reason.clone(),
+                            }));
+                        }
+                    }
+                }
+            }
+        }
+
+        results
+            .into_iter()
+            .map(|r| r.expect("every proof index is resolved by either the IO or CPU phase"))
+            .collect()
+    }
+
+    /// Synchronous twin of `verify_cryptographic_proof`, for use inside the
+    /// rayon pool in `verify_proofs_batch` where nothing can `.await` a lock.
+    /// Takes a snapshot of `provider_keys` and whether this file's root is
+    /// registered instead of reading them live, since the batch already
+    /// captured both before entering `spawn_blocking`.
+    fn verify_crypto_sync(
+        proof: &StorageProof,
+        challenge: &StorageChallenge,
+        root_registered: bool,
+        provider_keys: &HashMap<String, ProviderAuth>,
+    ) -> Result<Option<ProofFailure>, StorageVerificationError> {
+        if proof.proof_data.is_empty() {
+            return Err(StorageVerificationError::CryptographicFailure {
+                reason: "Proof data cannot be empty".to_string(),
+            });
+        }
+
+        if proof.proof_data.len() != challenge.sample_size as usize {
+            return Err(StorageVerificationError::CryptographicFailure {
+                reason: format!(
+                    "Proof data size {} does not match expected {}",
+                    proof.proof_data.len(),
+                    challenge.sample_size
+                ),
+            });
+        }
+
+        let mut hasher = Sha256::new();
+        hasher.update(&proof.proof_data);
+        hasher.update(&challenge.challenge_data);
+        hasher.update(challenge.sample_offset.to_le_bytes());
+        hasher.update(challenge.sample_size.to_le_bytes());
+        if hex::encode(hasher.finalize()) != challenge.expected_hash {
+            debug!("Hash mismatch for challenge {}", challenge.id);
+            return Ok(Some(ProofFailure::HashMismatch));
+        }
+
+        match &proof.merkle_proof {
+            Some(merkle_proof) => {
+                if !Self::verify_merkle_proof(merkle_proof, challenge)? {
+                    return Ok(Some(ProofFailure::MerkleMismatch));
+                }
+            }
+            None if root_registered => {
+                debug!("Missing Merkle proof for registered file {}", challenge.file_id);
+                return Ok(Some(ProofFailure::MerkleMismatch));
+            }
+            None => {}
+        }
+
+        match &proof.signature {
+            Some(signature_hex) => {
+                let auth = provider_keys
+                    .get(&proof.provider)
+                    .ok_or(StorageVerificationError::AuthenticationFailed)?;
+
+                let signature_bytes = hex::decode(signature_hex).map_err(|e| {
+                    StorageVerificationError::CryptographicFailure {
+                        reason: format!("invalid signature encoding: {}", e),
+                    }
+                })?;
+                let signature = Signature::try_from(signature_bytes.as_slice()).map_err(|e| {
+                    StorageVerificationError::CryptographicFailure {
+                        reason: format!("invalid signature encoding: {}", e),
+                    }
+                })?;
+
+                let message = canonical_provider_proof_message(
+                    &challenge.id,
+                    &proof.file_id,
+                    challenge.sample_offset,
+                    challenge.sample_size,
+                    &proof.proof_data,
+                );
+
+                if auth.verifying_key.verify(&message, &signature).is_err() {
+                    return Ok(Some(ProofFailure::BadSignature));
+                }
+            }
+            None => {
+                let required = provider_keys
+                    .get(&proof.provider)
+                    .map(|auth| auth.signature_required)
+                    .unwrap_or(false);
+                if required {
+                    return Err(StorageVerificationError::AuthenticationFailed);
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Run CPU-bound hashing work on tokio's blocking thread pool, bounded
+    /// by `hashing_semaphore` so a flood of concurrent `verify_proof` calls
+    /// can't exceed the configured hashing thread budget (see
+    /// `HashingConfig`). Callers must have already cloned whatever data the
+    /// closure needs and dropped any locks - this never runs while holding
+    /// `challenges`/`used_beacons`/`metrics`.
+    async fn run_blocking_hash<T, F>(&self, f: F) -> Result<T, StorageVerificationError>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let permit = self
+            .hashing_semaphore
+            .clone()
+            .acquire_owned()
+            .await
+            .map_err(|e| StorageVerificationError::HashingTaskFailed {
+                reason: e.to_string(),
+            })?;
+        let result = tokio::task::spawn_blocking(f).await.map_err(|e| {
+            StorageVerificationError::HashingTaskFailed {
+                reason: e.to_string(),
+            }
+        });
+        drop(permit);
+        result
+    }
+
+    /// Generate expected hash for cryptographic verification
+    fn generate_expected_hash(&self, file_id: &str, challenge_data: &[u8], sample_offset: u64, sample_size: u32) -> Result<String, StorageVerificationError> {
+        let mut hasher = Sha256::new();
+        hasher.update(file_id.as_bytes());
+        hasher.update(challenge_data);
+        hasher.update(sample_offset.to_le_bytes());
+        hasher.update(sample_size.to_le_bytes());
         hasher.update(b"UniversalSprint_Expected"); // Domain separator
         
         Ok(hex::encode(hasher.finalize()))
     }
 
-    /// Verify Merkle proof for file integrity
-    fn verify_merkle_proof(&self, _merkle_proof: &[String], _proof_data: &[u8], _file_id: &str) -> Result<bool, StorageVerificationError> {
-        // Placeholder for Merkle tree verification
-        // In production, this would verify the proof against a known Merkle root
-        log::debug!("Merkle proof verification not yet implemented");
+    /// Verify each challenged leaf's authentication path against the
+    /// challenge's committed Merkle root - a real proof-of-retrievability
+    /// check rather than a placeholder.
+    fn verify_merkle_proof(
+        merkle_proof: &[MerkleLeafProof],
+        challenge: &StorageChallenge,
+    ) -> Result<bool, StorageVerificationError> {
+        // The provider must answer exactly the leaves that were challenged,
+        // no more, no fewer - otherwise it could cherry-pick easy leaves.
+        let mut answered: Vec<usize> = merkle_proof.iter().map(|p| p.leaf_index).collect();
+        answered.sort_unstable();
+        let mut challenged = challenge.merkle_leaf_indices.clone();
+        challenged.sort_unstable();
+        if answered != challenged {
+            debug!("Merkle proof answered {:?}, expected {:?}", answered, challenged);
+            return Ok(false);
+        }
+
+        for leaf_proof in merkle_proof {
+            let result = verify_inclusion(
+                &leaf_proof.leaf_data,
+                leaf_proof.leaf_index,
+                challenge.merkle_chunk_count,
+                &leaf_proof.path,
+                &challenge.merkle_root,
+            );
+            if result.is_err() {
+                debug!("Merkle inclusion check failed for leaf {}: {:?}", leaf_proof.leaf_index, result);
+                return Ok(false);
+            }
+        }
+
         Ok(true)
     }
 
-    /// Verify provider signature for authentication
-    fn verify_provider_signature(&self, _signature: &str, _proof_data: &[u8], _provider: &str) -> Result<bool, StorageVerificationError> {
-        // Placeholder for digital signature verification
-        // In production, this would verify the provider's signature
-        log::debug!("Provider signature verification not yet implemented");
-        Ok(true)
+    /// Builds authentication-path answers for the leaves a challenge named.
+    /// Stands in for a provider retrieving its stored chunks and proving
+    /// possession; callers (e.g. the `/verify` handler) use this to package
+    /// a `StorageProof::merkle_proof`.
+    pub fn merkle_leaf_proofs(&self, challenge: &StorageChallenge) -> Vec<MerkleLeafProof> {
+        let chunks = mock_file_chunks(&challenge.file_id, challenge.merkle_chunk_count);
+        let tree = match MerkleTree::from_chunks(&chunks) {
+            Ok(tree) => tree,
+            Err(_) => return Vec::new(),
+        };
+
+        challenge
+            .merkle_leaf_indices
+            .iter()
+            .filter_map(|&idx| {
+                let path = tree.proof(idx).ok()?;
+                Some(MerkleLeafProof {
+                    leaf_index: idx,
+                    leaf_data: chunks[idx].clone(),
+                    path,
+                })
+            })
+            .collect()
+    }
+
+    /// Pin a file's committed Merkle root ahead of time - e.g. one a real
+    /// storage backend computed from the file's actual chunks - so
+    /// `generate_challenge` commits to it instead of the `mock_file_chunks`
+    /// stand-in. The root persists across challenges for that `file_id`
+    /// until overwritten by another call.
+    pub async fn register_file_root(&self, file_id: &str, root: [u8; 32]) {
+        self.file_roots.write().await.insert(file_id.to_string(), root);
+    }
+
+    /// Register a provider's authentication key so `verify_proof` can check
+    /// a signed proof's signature against it. Uses the same BIP-340 Schnorr
+    /// primitives as `signing::SigningKeypair` rather than `ed25519-dalek`:
+    /// there's no ed25519 dependency anywhere in this tree, and unlike the
+    /// simpler HMAC/SigV4/RLE hand-rolls elsewhere in the codebase, hand-
+    /// rolling Edwards curve arithmetic from scratch is a real security risk
+    /// rather than a reasonable dependency substitute. `signature_required`
+    /// marks a provider tier that must always submit a signed proof, not
+    /// just a data-possession one.
+    pub async fn register_provider(
+        &self,
+        provider: &str,
+        public_key_hex: &str,
+        signature_required: bool,
+    ) -> Result<(), StorageVerificationError> {
+        let key_bytes = hex::decode(public_key_hex).map_err(|e| StorageVerificationError::InvalidInput {
+            field: "public_key_hex".to_string(),
+            reason: e.to_string(),
+        })?;
+        let verifying_key = VerifyingKey::from_bytes(&key_bytes).map_err(|e| {
+            StorageVerificationError::InvalidInput {
+                field: "public_key_hex".to_string(),
+                reason: e.to_string(),
+            }
+        })?;
+
+        self.provider_keys.write().await.insert(
+            provider.to_string(),
+            ProviderAuth {
+                verifying_key,
+                signature_required,
+            },
+        );
+        Ok(())
+    }
+
+    /// Whether `provider` was registered with `signature_required: true`.
+    /// Unregistered providers default to not requiring one, preserving the
+    /// prior behavior for callers that never opted into authentication.
+    async fn provider_signature_required(&self, provider: &str) -> bool {
+        self.provider_keys
+            .read()
+            .await
+            .get(provider)
+            .map(|auth| auth.signature_required)
+            .unwrap_or(false)
+    }
+
+    /// Verify a hex-encoded provider signature over the canonical proof
+    /// message against that provider's registered key.
+    async fn verify_provider_signature(
+        &self,
+        signature_hex: &str,
+        proof: &StorageProof,
+        challenge: &StorageChallenge,
+    ) -> Result<bool, StorageVerificationError> {
+        let registry = self.provider_keys.read().await;
+        let auth = registry
+            .get(&proof.provider)
+            .ok_or(StorageVerificationError::AuthenticationFailed)?;
+
+        let signature_bytes = hex::decode(signature_hex).map_err(|e| {
+            StorageVerificationError::CryptographicFailure {
+                reason: format!("invalid signature encoding: {}", e),
+            }
+        })?;
+        let signature = Signature::try_from(signature_bytes.as_slice()).map_err(|e| {
+            StorageVerificationError::CryptographicFailure {
+                reason: format!("invalid signature encoding: {}", e),
+            }
+        })?;
+
+        let message = canonical_provider_proof_message(
+            &challenge.id,
+            &proof.file_id,
+            challenge.sample_offset,
+            challenge.sample_size,
+            &proof.proof_data,
+        );
+
+        Ok(auth.verifying_key.verify(&message, &signature).is_ok())
     }
 
     /// Get current verification metrics
@@ -515,93 +1702,32 @@ impl StorageVerifier {
                 tracker.cleanup(now);
             }
         }
+
+        // TTL-driven compaction of the durable store. Best-effort: a
+        // compaction failure shouldn't take down an otherwise-healthy
+        // verifier, just delay reclaiming disk space until the next call.
+        if let Err(e) = self.store.compact_expired(now).await {
+            log::warn!("challenge store compaction failed: {}", e);
+        }
     }
 }
 
-// Optional IPFS functionality
-#[cfg(feature = "ipfs")]
 impl StorageVerifier {
-    /// Fetch sample from IPFS with enhanced security
-    pub async fn fetch_ipfs_sample(&self, cid: &str, max_size: usize) -> Result<Vec<u8>, StorageVerificationError> {
-        // Input validation
-        if cid.is_empty() || cid.len() > 128 {
-            return Err(StorageVerificationError::InvalidInput {
-                field: "cid".to_string(),
-                reason: "Invalid CID format".to_string(),
-            });
-        }
-        
-        let safe_size = std::cmp::min(max_size, 8192); // Max 8KB sample
-        
-        let client = self.http_client.as_ref()
-            .ok_or_else(|| StorageVerificationError::NetworkError {
-                source: "HTTP client not available".to_string().into(),
-            })?;
-
-        // Use multiple IPFS gateways for redundancy
-        let gateways = [
-            "https://ipfs.io/ipfs",
-            "https://cloudflare-ipfs.com/ipfs",
-            "https://gateway.pinata.cloud/ipfs",
-        ];
-
-        for gateway in &gateways {
-            let url = format!("{}/{}?format=raw", gateway, cid);
-            
-            match self.try_fetch_from_gateway(&client, &url, safe_size).await {
-                Ok(data) => return Ok(data),
-                Err(e) => {
-                    log::warn!("Failed to fetch from {}: {:?}", gateway, e);
-                    continue;
-                }
-            }
-        }
-
-        Err(StorageVerificationError::NetworkError {
-            source: "Failed to fetch from all IPFS gateways".to_string().into(),
-        })
+    /// Fetch a challenge sample through whichever `StorageBackend` this
+    /// verifier was built with.
+    async fn fetch_sample(&self, file_id: &str, offset: u64, len: u32) -> Result<Vec<u8>, StorageVerificationError> {
+        self.backend.fetch_range(file_id, offset, len).await
     }
 
-    async fn try_fetch_from_gateway(&self, client: &Client, url: &str, size: usize) -> Result<Vec<u8>, StorageVerificationError> {
-        let resp = client
-            .get(url)
-            .header("Range", format!("bytes=0-{}", size - 1))
-            .send()
-            .await
-            .map_err(|e| StorageVerificationError::NetworkError {
-                source: format!("HTTP error: {}", e).into()
-            })?;        if !resp.status().is_success() {
-            return Err(StorageVerificationError::NetworkError {
-                source: format!("HTTP {}", resp.status()).into(),
-            });
-        }
+    /// Verify content with comprehensive cryptographic checks, issuing a
+    /// challenge and sampling it through the configured `StorageBackend` -
+    /// memory, S3/Garage, or IPFS, whichever this verifier was built with.
+    pub async fn verify_content(&self, file_id: &str, provider: &str, sample_size: Option<usize>) -> Result<bool, StorageVerificationError> {
+        let challenge = self.generate_challenge(file_id, provider).await?;
+        let requested_size = sample_size.unwrap_or(challenge.sample_size as usize) as u32;
 
-        let bytes = resp
-            .bytes()
-            .await
-            .map_err(|e| StorageVerificationError::NetworkError {
-                source: format!("Failed to read response: {}", e).into(),
-            })?;
+        let sample = self.fetch_sample(file_id, challenge.sample_offset, requested_size).await?;
 
-        if bytes.len() > size {
-            return Err(StorageVerificationError::InvalidInput {
-                field: "response_size".to_string(),
-                reason: "Response too large".to_string(),
-            });
-        }
-
-        Ok(bytes.to_vec())
-    }
-
-    /// Verify IPFS content with comprehensive cryptographic checks
-    pub async fn verify_ipfs_content(&self, cid: &str, provider: &str, sample_size: Option<usize>) -> Result<bool, StorageVerificationError> {
-        let challenge = self.generate_challenge(cid, provider).await?;
-        let requested_size = sample_size.unwrap_or(challenge.sample_size as usize);
-
-        // Fetch sample with timeout
-        let sample = self.fetch_ipfs_sample(cid, requested_size).await
-            .map_err(|e| StorageVerificationError::NetworkError { source: Box::new(e) })?;
-        
         if sample.is_empty() {
             return Ok(false);
         }
@@ -609,14 +1735,14 @@ impl StorageVerifier {
         // Verify sample size matches challenge requirements
         if sample.len() != challenge.sample_size as usize {
             return Err(StorageVerificationError::CryptographicFailure {
-                reason: format!("Sample size mismatch: got {}, expected {}", 
+                reason: format!("Sample size mismatch: got {}, expected {}",
                                sample.len(), challenge.sample_size),
             });
         }
 
         let proof = StorageProof {
             challenge_id: challenge.id.clone(),
-            file_id: cid.to_string(),
+            file_id: file_id.to_string(),
             provider: provider.to_string(),
             timestamp: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
             proof_data: sample,
@@ -628,6 +1754,22 @@ impl StorageVerifier {
     }
 }
 
+// Thin, IPFS-named aliases kept for existing callers: equivalent to
+// `verify_content`/`fetch_sample` as long as this verifier was built with
+// its default (or an explicit `IpfsGatewayBackend`) backend.
+#[cfg(feature = "ipfs")]
+impl StorageVerifier {
+    /// Fetch sample from IPFS with enhanced security
+    pub async fn fetch_ipfs_sample(&self, cid: &str, max_size: usize) -> Result<Vec<u8>, StorageVerificationError> {
+        self.fetch_sample(cid, 0, max_size as u32).await
+    }
+
+    /// Verify IPFS content with comprehensive cryptographic checks
+    pub async fn verify_ipfs_content(&self, cid: &str, provider: &str, sample_size: Option<usize>) -> Result<bool, StorageVerificationError> {
+        self.verify_content(cid, provider, sample_size).await
+    }
+}
+
 impl Default for StorageVerifier {
     fn default() -> Self {
         Self::new()
@@ -677,12 +1819,290 @@ mod tests {
         // The verification will fail because the proof data doesn't match expected hash
     }
 
+    #[tokio::test]
+    async fn test_merkle_proof_generation_and_verification() {
+        let verifier = StorageVerifier::new();
+        let challenge = verifier.generate_challenge("merkle_file", "merkle_provider").await.unwrap();
+
+        let leaf_proofs = verifier.merkle_leaf_proofs(&challenge);
+        assert_eq!(leaf_proofs.len(), challenge.merkle_leaf_indices.len());
+
+        for leaf_proof in &leaf_proofs {
+            assert!(crate::merkle::verify_inclusion(
+                &leaf_proof.leaf_data,
+                leaf_proof.leaf_index,
+                challenge.merkle_chunk_count,
+                &leaf_proof.path,
+                &challenge.merkle_root,
+            )
+            .is_ok());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_merkle_proof_rejects_wrong_leaf_set() {
+        let verifier = StorageVerifier::new();
+        let challenge = verifier.generate_challenge("merkle_file2", "merkle_provider2").await.unwrap();
+
+        let mut proof_data = vec![0u8; challenge.sample_size as usize];
+        proof_data[0] = 7;
+
+        let proof = StorageProof {
+            challenge_id: challenge.id.clone(),
+            file_id: challenge.file_id.clone(),
+            provider: challenge.provider.clone(),
+            timestamp: challenge.timestamp + 1,
+            proof_data,
+            // Answering leaf 0 regardless of what was actually challenged
+            // should fail unless it happens to be the full challenged set.
+            merkle_proof: Some(vec![crate::merkle::MerkleLeafProof {
+                leaf_index: (challenge.merkle_leaf_indices[0] + 1) % challenge.merkle_chunk_count,
+                leaf_data: vec![0u8; 32],
+                path: vec![],
+            }]),
+            signature: None,
+        };
+
+        let result = verifier.verify_proof(proof).await.unwrap();
+        assert!(!result);
+    }
+
+    #[tokio::test]
+    async fn test_missing_merkle_proof_rejected_for_registered_file() {
+        let verifier = StorageVerifier::new();
+        verifier.register_file_root("audited_file", [0x11u8; 32]).await;
+        let challenge = verifier
+            .generate_challenge("audited_file", "audited_provider")
+            .await
+            .unwrap();
+
+        // A committed root was registered for this file, so a proof that
+        // omits the Merkle proof must not silently pass.
+        let proof = StorageProof {
+            challenge_id: challenge.id.clone(),
+            file_id: challenge.file_id.clone(),
+            provider: challenge.provider.clone(),
+            timestamp: challenge.timestamp,
+            proof_data: vec![0u8; challenge.sample_size as usize],
+            merkle_proof: None,
+            signature: None,
+        };
+
+        assert!(!verifier.verify_proof(proof).await.unwrap());
+        let metrics = verifier.get_metrics().await;
+        assert_eq!(metrics.merkle_mismatches, 1);
+    }
+
+    #[tokio::test]
+    async fn test_metrics_bucket_hash_mismatch_separately() {
+        let verifier = StorageVerifier::new();
+        let challenge = verifier.generate_challenge("bucketed_file", "bucketed_provider").await.unwrap();
+
+        // Wrong proof data fails the hash check before any Merkle/signature
+        // check runs, so only `hash_mismatches` should move.
+        let proof = StorageProof {
+            challenge_id: challenge.id.clone(),
+            file_id: challenge.file_id.clone(),
+            provider: challenge.provider.clone(),
+            timestamp: challenge.timestamp,
+            proof_data: vec![0xAAu8; challenge.sample_size as usize],
+            merkle_proof: None,
+            signature: None,
+        };
+
+        assert!(!verifier.verify_proof(proof).await.unwrap());
+        let metrics = verifier.get_metrics().await;
+        assert_eq!(metrics.hash_mismatches, 1);
+        assert_eq!(metrics.merkle_mismatches, 0);
+        assert_eq!(metrics.bad_signatures, 0);
+    }
+
+    #[tokio::test]
+    async fn test_registered_file_root_overrides_mock_chunks() {
+        let verifier = StorageVerifier::new();
+        let registered_root = [0x42u8; 32];
+        verifier.register_file_root("pinned_file", registered_root).await;
+
+        let challenge = verifier.generate_challenge("pinned_file", "provider1").await.unwrap();
+        assert_eq!(challenge.merkle_root, registered_root);
+
+        // An unregistered file still falls back to the mock-chunk-derived root.
+        let other_challenge = verifier.generate_challenge("unpinned_file", "provider1").await.unwrap();
+        assert_ne!(other_challenge.merkle_root, registered_root);
+    }
+
+    #[tokio::test]
+    async fn test_registered_provider_signature_verifies() {
+        let verifier = StorageVerifier::new();
+        let keypair = crate::signing::SigningKeypair::generate();
+        verifier
+            .register_provider("signing_provider", &keypair.public_key_hex(), false)
+            .await
+            .unwrap();
+
+        let challenge = verifier
+            .generate_challenge("signed_file", "signing_provider")
+            .await
+            .unwrap();
+        let proof_data = vec![9u8; challenge.sample_size as usize];
+        let message = canonical_provider_proof_message(
+            &challenge.id,
+            &challenge.file_id,
+            challenge.sample_offset,
+            challenge.sample_size,
+            &proof_data,
+        );
+        let signature_hex = keypair.sign_hex(&message);
+
+        let proof = StorageProof {
+            challenge_id: challenge.id.clone(),
+            file_id: challenge.file_id.clone(),
+            provider: "signing_provider".to_string(),
+            timestamp: challenge.timestamp,
+            proof_data,
+            merkle_proof: None,
+            signature: Some(signature_hex),
+        };
+
+        assert!(verifier
+            .verify_provider_signature(proof.signature.as_ref().unwrap(), &proof, &challenge)
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_unregistered_provider_signature_is_authentication_failure() {
+        let verifier = StorageVerifier::new();
+        let challenge = verifier
+            .generate_challenge("some_file", "unregistered_provider")
+            .await
+            .unwrap();
+        let proof = StorageProof {
+            challenge_id: challenge.id.clone(),
+            file_id: challenge.file_id.clone(),
+            provider: "unregistered_provider".to_string(),
+            timestamp: challenge.timestamp,
+            proof_data: vec![0u8; challenge.sample_size as usize],
+            merkle_proof: None,
+            signature: Some("00".repeat(64)),
+        };
+
+        let result = verifier
+            .verify_provider_signature(proof.signature.as_ref().unwrap(), &proof, &challenge)
+            .await;
+        assert!(matches!(result, Err(StorageVerificationError::AuthenticationFailed)));
+    }
+
+    #[tokio::test]
+    async fn test_tampered_signature_fails_verification() {
+        let verifier = StorageVerifier::new();
+        let keypair = crate::signing::SigningKeypair::generate();
+        verifier
+            .register_provider("signing_provider", &keypair.public_key_hex(), false)
+            .await
+            .unwrap();
+
+        let challenge = verifier
+            .generate_challenge("signed_file2", "signing_provider")
+            .await
+            .unwrap();
+        let proof_data = vec![9u8; challenge.sample_size as usize];
+        let message = canonical_provider_proof_message(
+            &challenge.id,
+            &challenge.file_id,
+            challenge.sample_offset,
+            challenge.sample_size,
+            &proof_data,
+        );
+        let signature_hex = keypair.sign_hex(&message);
+
+        let tampered_proof = StorageProof {
+            challenge_id: challenge.id.clone(),
+            file_id: challenge.file_id.clone(),
+            provider: "signing_provider".to_string(),
+            timestamp: challenge.timestamp,
+            proof_data: vec![7u8; challenge.sample_size as usize], // doesn't match the signed message
+            merkle_proof: None,
+            signature: Some(signature_hex),
+        };
+
+        let verified = verifier
+            .verify_provider_signature(
+                tampered_proof.signature.as_ref().unwrap(),
+                &tampered_proof,
+                &challenge,
+            )
+            .await
+            .unwrap();
+        assert!(!verified);
+    }
+
+    #[tokio::test]
+    async fn test_signature_required_tier_rejects_missing_signature() {
+        let verifier = StorageVerifier::new();
+        let keypair = crate::signing::SigningKeypair::generate();
+        verifier
+            .register_provider("high_value_provider", &keypair.public_key_hex(), true)
+            .await
+            .unwrap();
+
+        assert!(verifier.provider_signature_required("high_value_provider").await);
+        assert!(!verifier.provider_signature_required("unregistered_provider").await);
+    }
+
+    #[tokio::test]
+    async fn test_register_provider_rejects_invalid_hex_key() {
+        let verifier = StorageVerifier::new();
+        let result = verifier
+            .register_provider("bad_provider", "not hex", false)
+            .await;
+        assert!(matches!(result, Err(StorageVerificationError::InvalidInput { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_challenge_survives_cache_eviction_via_store() {
+        // Simulates a restart: a fresh verifier sharing the same durable
+        // store as the one that issued the challenge must still be able to
+        // look it up, even though its own in-memory cache starts empty.
+        let store: std::sync::Arc<dyn crate::challenge_store::ChallengeStore> =
+            std::sync::Arc::new(crate::challenge_store::InMemoryChallengeStore::new());
+
+        let issuer = StorageVerifier::with_store(
+            TokenBucketConfig::default(),
+            Box::new(crate::storage_backend::InMemoryBackend::new()),
+            store.clone(),
+        );
+        let challenge = issuer.generate_challenge("durable_file", "durable_provider").await.unwrap();
+
+        let restarted = StorageVerifier::with_store(
+            TokenBucketConfig::default(),
+            Box::new(crate::storage_backend::InMemoryBackend::new()),
+            store,
+        );
+
+        let proof = StorageProof {
+            challenge_id: challenge.id.clone(),
+            file_id: challenge.file_id.clone(),
+            provider: challenge.provider.clone(),
+            timestamp: challenge.timestamp,
+            proof_data: vec![0u8; challenge.sample_size as usize],
+            merkle_proof: None,
+            signature: None,
+        };
+
+        // Found via the store fallback rather than ChallengeNotFound.
+        let result = restarted.verify_proof(proof).await;
+        assert!(result.is_ok());
+    }
+
     #[tokio::test]
     async fn test_rate_limiting_with_metrics() {
-        let config = RateLimitConfig {
-            max_requests_per_minute: 2,
-            max_requests_per_hour: 10,
-            cleanup_interval_secs: 1,
+        let config = TokenBucketConfig {
+            cap: 2,
+            window_secs: 60,
+            burst_pct: 1.0,
+            duration_overhead_secs: 0,
+            retries: 0,
         };
         let verifier = StorageVerifier::with_config(config);
 
@@ -700,6 +2120,31 @@ mod tests {
         assert_eq!(metrics.rate_limited_requests, 1); // Failed one due to rate limiting
     }
 
+    #[tokio::test]
+    async fn test_generate_challenge_with_retry_waits_out_the_bucket() {
+        let config = TokenBucketConfig {
+            cap: 1,
+            window_secs: 1,
+            burst_pct: 1.0,
+            duration_overhead_secs: 0,
+            retries: 2,
+        };
+        let verifier = StorageVerifier::with_config(config);
+
+        assert!(verifier.generate_challenge("file1", "provider1").await.is_ok());
+        // The bucket is empty now; a bare `generate_challenge` is rate
+        // limited, but the retrying variant should sleep out the refill
+        // and eventually succeed.
+        assert!(matches!(
+            verifier.generate_challenge("file2", "provider1").await,
+            Err(StorageVerificationError::RateLimited { .. })
+        ));
+        assert!(verifier
+            .generate_challenge_with_retry("file3", "provider1")
+            .await
+            .is_ok());
+    }
+
     #[tokio::test]
     async fn test_beacon_uniqueness() {
         let verifier = StorageVerifier::new();
@@ -736,4 +2181,299 @@ mod tests {
         let metrics_after_reset = verifier.get_metrics().await;
         assert_eq!(metrics_after_reset.total_challenges, 0);
     }
+
+    #[tokio::test]
+    async fn test_metrics_cardinality_estimation() {
+        let verifier = StorageVerifier::new();
+
+        for i in 0..200 {
+            let file_id = format!("file_{}", i);
+            let provider = format!("provider_{}", i % 20);
+            verifier.generate_challenge(&file_id, &provider).await.unwrap();
+        }
+
+        let metrics = verifier.get_metrics().await;
+        let files = metrics.estimated_unique_files();
+        let providers = metrics.estimated_unique_providers();
+
+        // HyperLogLog is an estimate, not an exact count - allow generous tolerance.
+        assert!((files - 200.0).abs() / 200.0 < 0.2, "file estimate {} too far from 200", files);
+        assert!((providers - 20.0).abs() / 20.0 < 0.5, "provider estimate {} too far from 20", providers);
+    }
+
+    #[tokio::test]
+    async fn test_cardinality_sketches_are_mergeable_across_shards() {
+        let shard_a = StorageVerifier::new();
+        let shard_b = StorageVerifier::new();
+
+        for i in 0..100 {
+            shard_a
+                .generate_challenge(&format!("shard_a_file_{}", i), "shard_a_provider")
+                .await
+                .unwrap();
+        }
+        for i in 0..100 {
+            shard_b
+                .generate_challenge(&format!("shard_b_file_{}", i), "shard_b_provider")
+                .await
+                .unwrap();
+        }
+
+        let mut merged = shard_a.get_metrics().await;
+        merged.merge_cardinality_from(&shard_b.get_metrics().await);
+
+        // Neither shard alone saw all 200 files or both providers; the
+        // merged sketch should estimate close to the combined totals.
+        let files = merged.estimated_unique_files();
+        let providers = merged.estimated_unique_providers();
+        assert!((files - 200.0).abs() / 200.0 < 0.2, "merged file estimate {} too far from 200", files);
+        assert!((providers - 2.0).abs() <= 1.0, "merged provider estimate {} too far from 2", providers);
+    }
+
+    /// Fires many simultaneous `verify_proof` calls and checks the tokio
+    /// runtime stays responsive throughout - a cheap stand-in for a
+    /// background task getting starved if hashing ran inline on the worker
+    /// threads instead of `spawn_blocking`.
+    #[tokio::test]
+    async fn test_concurrent_verify_proof_does_not_block_runtime() {
+        let verifier = Arc::new(StorageVerifier::new());
+
+        let mut challenges = Vec::new();
+        for i in 0..32 {
+            let file_id = format!("concurrent_file_{}", i);
+            let provider = format!("concurrent_provider_{}", i);
+            challenges.push(verifier.generate_challenge(&file_id, &provider).await.unwrap());
+        }
+
+        let heartbeat_ticks = Arc::new(tokio::sync::Mutex::new(0u32));
+        let heartbeat_ticks_clone = heartbeat_ticks.clone();
+        let heartbeat = tokio::spawn(async move {
+            for _ in 0..20 {
+                tokio::time::sleep(std::time::Duration::from_millis(1)).await;
+                *heartbeat_ticks_clone.lock().await += 1;
+            }
+        });
+
+        let verify_tasks: Vec<_> = challenges
+            .into_iter()
+            .map(|challenge| {
+                let verifier = verifier.clone();
+                tokio::spawn(async move {
+                    let mut proof_data = vec![0u8; challenge.sample_size as usize];
+                    proof_data[0] = 1;
+                    let proof = StorageProof {
+                        challenge_id: challenge.id.clone(),
+                        file_id: challenge.file_id.clone(),
+                        provider: challenge.provider.clone(),
+                        timestamp: challenge.timestamp,
+                        proof_data,
+                        merkle_proof: None,
+                        signature: None,
+                    };
+                    verifier.verify_proof(proof).await
+                })
+            })
+            .collect();
+
+        for task in verify_tasks {
+            assert!(task.await.unwrap().is_ok());
+        }
+        heartbeat.await.unwrap();
+
+        // The heartbeat task should have made real progress alongside the
+        // hashing load rather than being starved behind it.
+        assert!(*heartbeat_ticks.lock().await > 0);
+    }
+
+    #[tokio::test]
+    async fn test_verify_proofs_batch_preserves_order_and_updates_metrics() {
+        let verifier = StorageVerifier::new();
+
+        let mut proofs = Vec::new();
+        for i in 0..6 {
+            let file_id = format!("batch_file_{}", i);
+            let provider = format!("batch_provider_{}", i);
+            let challenge = verifier.generate_challenge(&file_id, &provider).await.unwrap();
+
+            // Odd-indexed proofs carry a wrongly-sized sample, which fails
+            // before hashing with a `CryptographicFailure`; even-indexed ones
+            // are correctly sized but still don't hash-match (the sample
+            // verifiers don't have the real file), so they resolve to
+            // `Ok(false)` bucketed as a hash mismatch. Mixing both keeps the
+            // batch's result order meaningfully checkable.
+            let size = if i % 2 == 1 {
+                challenge.sample_size as usize + 1
+            } else {
+                challenge.sample_size as usize
+            };
+            proofs.push(StorageProof {
+                challenge_id: challenge.id.clone(),
+                file_id: challenge.file_id.clone(),
+                provider: challenge.provider.clone(),
+                timestamp: challenge.timestamp,
+                proof_data: vec![0u8; size],
+                merkle_proof: None,
+                signature: None,
+            });
+        }
+
+        let results = verifier.verify_proofs_batch(proofs).await;
+        assert_eq!(results.len(), 6);
+        for (i, result) in results.iter().enumerate() {
+            if i % 2 == 1 {
+                assert!(
+                    matches!(result, Err(StorageVerificationError::CryptographicFailure { .. })),
+                    "unexpected result at index {}",
+                    i
+                );
+            } else {
+                assert!(matches!(result, Ok(false)), "unexpected result at index {}", i);
+            }
+        }
+
+        let metrics = verifier.get_metrics().await;
+        assert_eq!(metrics.successful_proofs, 0);
+        assert_eq!(metrics.failed_proofs, 3);
+        assert_eq!(metrics.hash_mismatches, 3);
+    }
+
+    #[tokio::test]
+    async fn test_aggregate_challenge_round_trip_accepts_correct_samples() {
+        let verifier = StorageVerifier::new();
+        let challenge = verifier
+            .generate_aggregate_challenge(&["agg_file_a", "agg_file_b", "agg_file_c"], "agg_provider")
+            .await
+            .unwrap();
+        assert_eq!(challenge.files.len(), 3);
+
+        // The offsets are derived from the shared beacon, not independent
+        // RNG draws, so re-deriving them out-of-band matches the challenge.
+        for file_challenge in &challenge.files {
+            assert_eq!(
+                file_challenge.sample_offset,
+                StorageVerifier::derive_sample_offset(&challenge.beacon, &file_challenge.file_id)
+            );
+        }
+
+        let proof_data = challenge
+            .files
+            .iter()
+            .map(|f| vec![0u8; f.sample_size as usize])
+            .collect();
+        let proof = AggregateStorageProof {
+            round_id: challenge.round_id.clone(),
+            provider: "agg_provider".to_string(),
+            proof_data,
+        };
+
+        // None of these samples actually hash to the committed
+        // `expected_hash` values (same structural quirk as single-file
+        // proofs - the sample content never matches what `expected_hash`
+        // commits to in this mock setup), so the whole round is rejected
+        // and every file should be named as a cause.
+        let result = verifier.verify_aggregate_proof(proof).await.unwrap();
+        assert!(!result.verified);
+        assert_eq!(result.failed_files.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_aggregate_verification_recovers_which_files_failed() {
+        let verifier = StorageVerifier::new();
+        let challenge = verifier
+            .generate_aggregate_challenge(&["recov_file_a", "recov_file_b"], "recov_provider")
+            .await
+            .unwrap();
+
+        // Wrong-sized sample for the second file only.
+        let proof_data = vec![
+            vec![0u8; challenge.files[0].sample_size as usize],
+            vec![0u8; challenge.files[1].sample_size as usize + 1],
+        ];
+        let proof = AggregateStorageProof {
+            round_id: challenge.round_id.clone(),
+            provider: "recov_provider".to_string(),
+            proof_data,
+        };
+
+        let result = verifier.verify_aggregate_proof(proof).await.unwrap();
+        assert!(!result.verified);
+        assert!(result.failed_files.contains(&"recov_file_b".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_aggregate_proof_rejects_wrong_file_count() {
+        let verifier = StorageVerifier::new();
+        let challenge = verifier
+            .generate_aggregate_challenge(&["count_file_a", "count_file_b"], "count_provider")
+            .await
+            .unwrap();
+
+        let proof = AggregateStorageProof {
+            round_id: challenge.round_id.clone(),
+            provider: "count_provider".to_string(),
+            proof_data: vec![vec![0u8; challenge.files[0].sample_size as usize]],
+        };
+        let result = verifier.verify_aggregate_proof(proof).await;
+        assert!(matches!(result, Err(StorageVerificationError::InvalidInput { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_aggregate_challenge_rejects_empty_file_list() {
+        let verifier = StorageVerifier::new();
+        let result = verifier.generate_aggregate_challenge(&[], "some_provider").await;
+        assert!(matches!(result, Err(StorageVerificationError::InvalidInput { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_aggregate_proof_unknown_round_is_not_found() {
+        let verifier = StorageVerifier::new();
+        let proof = AggregateStorageProof {
+            round_id: "nonexistent_round".to_string(),
+            provider: "some_provider".to_string(),
+            proof_data: vec![vec![0u8; 8]],
+        };
+        let result = verifier.verify_aggregate_proof(proof).await;
+        assert!(matches!(result, Err(StorageVerificationError::ChallengeNotFound { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_verify_proofs_batch_resolves_screening_failures_without_hashing() {
+        let verifier = StorageVerifier::new();
+        let challenge = verifier
+            .generate_challenge("batch_screened_file", "batch_screened_provider")
+            .await
+            .unwrap();
+
+        // Wrong provider fails `screen_proof`'s metadata check and should
+        // resolve to `Ok(false)` without ever reaching the rayon phase.
+        let mismatched_provider = StorageProof {
+            challenge_id: challenge.id.clone(),
+            file_id: challenge.file_id.clone(),
+            provider: "someone_else".to_string(),
+            timestamp: challenge.timestamp,
+            proof_data: vec![0u8; challenge.sample_size as usize],
+            merkle_proof: None,
+            signature: None,
+        };
+        // Unknown challenge id fails lookup entirely.
+        let unknown_challenge = StorageProof {
+            challenge_id: "does-not-exist".to_string(),
+            file_id: "whatever".to_string(),
+            provider: "whoever".to_string(),
+            timestamp: challenge.timestamp,
+            proof_data: vec![0u8; 8],
+            merkle_proof: None,
+            signature: None,
+        };
+
+        let results = verifier
+            .verify_proofs_batch(vec![mismatched_provider, unknown_challenge])
+            .await;
+        assert_eq!(results.len(), 2);
+        assert!(matches!(results[0], Ok(false)));
+        assert!(matches!(
+            results[1],
+            Err(StorageVerificationError::ChallengeNotFound { .. })
+        ));
+    }
 }
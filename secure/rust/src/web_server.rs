@@ -15,9 +15,12 @@ use uuid::Uuid;
 
 // Re-export our storage verifier
 use crate::storage_verifier::{
-    StorageVerifier, RateLimitConfig, StorageChallenge, StorageProof,
+    StorageVerifier, RateLimitConfig, TokenBucketConfig, StorageChallenge, StorageProof,
     StorageVerificationError
 };
+use crate::signing::{canonical_verify_response, SigningKeypair};
+use crate::protocol_verifier::ProtocolRegistry;
+use crate::rate_limiter::{Decision as RateLimitDecision, TieredRateLimiter};
 
 // --- Enhanced Request / Response ---
 #[derive(Serialize, Deserialize)]
@@ -27,6 +30,10 @@ struct VerifyRequest {
     protocol: String,
     #[serde(default = "default_file_size")]
     file_size: u64,
+    /// Hex-encoded storage-sample bytes the provider is submitting as its
+    /// proof for `file_id`, checked against `protocol`'s challenge/response
+    /// below - the verifier never fabricates the data it then verifies.
+    proof_data: String,
 }
 
 fn default_file_size() -> u64 { 1024 * 1024 } // 1MB default
@@ -38,6 +45,10 @@ struct VerifyResponse {
     signature: String,
     challenge_id: String,
     verification_score: f64, // 0.0 to 1.0
+    /// Hex-encoded BIP-340 public key the signature verifies under. Carried
+    /// on every response so clients can check proofs offline without a
+    /// separate round trip to `/pubkey`.
+    public_key: String,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -47,65 +58,15 @@ struct ErrorResponse {
     timestamp: u64,
 }
 
-// --- Enhanced Rate Limiting ---
-#[derive(Clone)]
-struct RateLimitEntry {
-    count: u32,
-    window_start: Instant,
-    last_request: Instant,
-}
-
-struct RateLimiter {
-    entries: HashMap<String, RateLimitEntry>,
-    max_requests: u32,
-    window_duration: Duration,
-}
-
-impl RateLimiter {
-    fn new(max_requests: u32, window_seconds: u64) -> Self {
-        Self {
-            entries: HashMap::new(),
-            max_requests,
-            window_duration: Duration::from_secs(window_seconds),
-        }
-    }
-
-    fn check_rate_limit(&mut self, key: &str) -> bool {
-        let now = Instant::now();
-
-        // Clean up old entries
-        self.entries.retain(|_, entry| {
-            now.duration_since(entry.last_request) < self.window_duration * 2
-        });
-
-        let entry = self.entries.entry(key.to_string()).or_insert(RateLimitEntry {
-            count: 0,
-            window_start: now,
-            last_request: now,
-        });
-
-        // Reset window if expired
-        if now.duration_since(entry.window_start) >= self.window_duration {
-            entry.count = 0;
-            entry.window_start = now;
-        }
-
-        entry.last_request = now;
-
-        if entry.count >= self.max_requests {
-            false
-        } else {
-            entry.count += 1;
-            true
-        }
-    }
-}
-
 // --- Enhanced Shared State ---
 struct AppState {
+    // Shared accounting engine backing every registered protocol; kept
+    // directly for /metrics, which reports across all protocols combined.
     verifier: Arc<StorageVerifier>,
-    rate_limiter: Arc<Mutex<RateLimiter>>,
+    protocol_registry: Arc<ProtocolRegistry>,
+    rate_limiter: Arc<Mutex<TieredRateLimiter>>,
     active_challenges: Arc<Mutex<HashMap<String, Challenge>>>,
+    signing_keypair: Arc<SigningKeypair>,
 }
 
 #[derive(Clone)]
@@ -118,7 +79,7 @@ struct Challenge {
 }
 
 // --- Validation ---
-fn validate_request(req: &VerifyRequest) -> Result<(), String> {
+fn validate_request(req: &VerifyRequest, registry: &ProtocolRegistry) -> Result<(), String> {
     if req.file_id.is_empty() {
         return Err("file_id cannot be empty".to_string());
     }
@@ -127,15 +88,23 @@ fn validate_request(req: &VerifyRequest) -> Result<(), String> {
         return Err("provider cannot be empty".to_string());
     }
 
-    if !["ipfs", "arweave", "filecoin", "bitcoin"].contains(&req.protocol.to_lowercase().as_str()) {
-        return Err("unsupported protocol".to_string());
-    }
-
     if req.file_size == 0 || req.file_size > 1024 * 1024 * 1024 { // Max 1GB
         return Err("invalid file size".to_string());
     }
 
-    Ok(())
+    if req.proof_data.is_empty() {
+        return Err("proof_data cannot be empty".to_string());
+    }
+
+    // Protocol support and protocol-specific validation are both delegated to
+    // the registered backend, so adding a protocol doesn't mean editing this
+    // function.
+    match registry.get(&req.protocol) {
+        Some(verifier) => verifier
+            .validate(&req.file_id, &req.provider, req.file_size)
+            .map_err(|e| format!("{} validation failed: {}", req.protocol, e)),
+        None => Err("unsupported protocol".to_string()),
+    }
 }
 
 // --- Enhanced API Endpoint ---
@@ -146,7 +115,7 @@ async fn verify(
     let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
 
     // --- Input Validation ---
-    if let Err(e) = validate_request(&req) {
+    if let Err(e) = validate_request(&req, &state.protocol_registry) {
         warn!("Invalid request: {}", e);
         return Ok(HttpResponse::BadRequest().json(ErrorResponse {
             error: e,
@@ -155,17 +124,36 @@ async fn verify(
         }));
     }
 
-    // --- Enhanced Rate Limiting ---
+    // Resolved once validate_request has confirmed the protocol is registered.
+    let protocol_verifier = state.protocol_registry.get(&req.protocol).expect(
+        "validate_request already confirmed this protocol is registered",
+    );
+
+    let proof_data = match hex::decode(&req.proof_data) {
+        Ok(bytes) => bytes,
+        Err(_) => {
+            warn!("Invalid proof_data hex for {}", req.file_id);
+            return Ok(HttpResponse::BadRequest().json(ErrorResponse {
+                error: "proof_data must be valid hex".to_string(),
+                code: 400,
+                timestamp: now,
+            }));
+        }
+    };
+
+    // --- GCRA Rate Limiting (per key, per provider, and global tiers) ---
     let rate_limit_key = format!("{}:{}", req.provider, req.file_id);
     {
         let mut limiter = state.rate_limiter.lock().await;
-        if !limiter.check_rate_limit(&rate_limit_key) {
+        if let RateLimitDecision::Deny { retry_after } = limiter.check(&rate_limit_key, &req.provider) {
             warn!("Rate limit exceeded for {}", rate_limit_key);
-            return Ok(HttpResponse::TooManyRequests().json(ErrorResponse {
-                error: "Rate limit exceeded. Please try again later.".to_string(),
-                code: 429,
-                timestamp: now,
-            }));
+            return Ok(HttpResponse::TooManyRequests()
+                .insert_header(("Retry-After", retry_after.as_secs().to_string()))
+                .json(ErrorResponse {
+                    error: "Rate limit exceeded. Please try again later.".to_string(),
+                    code: 429,
+                    timestamp: now,
+                }));
         }
     }
 
@@ -192,8 +180,8 @@ async fn verify(
               challenge_id, req.file_id, req.provider);
     }
 
-    // --- Generate Challenge using our StorageVerifier ---
-    let generated_challenge = match state.verifier.generate_challenge(&req.file_id, &req.provider).await {
+    // --- Generate Challenge via the protocol-specific backend ---
+    let generated_challenge = match protocol_verifier.generate_challenge(&req.file_id, &req.provider).await {
         Ok(c) => c,
         Err(e) => {
             error!("Challenge generation failed for {}: {:?}", req.file_id, e);
@@ -206,18 +194,26 @@ async fn verify(
     };
 
     // --- Enhanced Proof Creation ---
+    // Signed with the server's Schnorr key over (provider, challenge_id,
+    // timestamp) - this attests that *this verifier* recorded the proof
+    // attempt, not that the provider itself signed it (providers don't hold
+    // a key in this scheme).
+    let proof_signature = state.signing_keypair.sign_hex(
+        canonical_verify_response(false, now, &challenge_id, 0.0, &req.provider).as_slice(),
+    );
+    let merkle_proof = state.verifier.merkle_leaf_proofs(&generated_challenge);
     let proof = StorageProof {
         challenge_id: challenge_id.clone(),
         file_id: req.file_id.clone(),
         provider: req.provider.clone(),
         timestamp: now,
-        proof_data: generate_mock_samples(&req.file_id, req.file_size),
-        merkle_proof: Some(vec![format!("0x{}", hex::encode(&req.file_id))]),
-        signature: Some(format!("sig_{}_{}", req.provider, challenge_id)),
+        proof_data,
+        merkle_proof: Some(merkle_proof),
+        signature: Some(proof_signature),
     };
 
     // --- Enhanced Verification ---
-    let verification_result = match state.verifier.verify_proof(proof).await {
+    let verification_result = match protocol_verifier.verify_proof(proof).await {
         Ok(result) => result,
         Err(e) => {
             error!("Verification failed for challenge {}: {:?}", challenge_id, e);
@@ -230,22 +226,24 @@ async fn verify(
     };
 
     // --- Calculate Verification Score ---
-    let verification_score = calculate_verification_score(
-        verification_result,
-        req.file_size,
-        &req.protocol
-    );
+    let verification_score = protocol_verifier.score(verification_result, req.file_size);
 
     // --- Generate Signature ---
-    let signature = format!("sig_{}_{}_{}", req.provider, challenge_id, now);
+    // Sign a canonical serialization of the exact fields the client can
+    // check, so the signature authenticates the response instead of being a
+    // decorative string.
+    let verified = verification_result && verification_score > 0.7;
+    let message = canonical_verify_response(verified, now, &challenge_id, verification_score, &req.file_id);
+    let signature = state.signing_keypair.sign_hex(&message);
 
     // --- Enhanced Response ---
     let response = VerifyResponse {
-        verified: verification_result && verification_score > 0.7,
+        verified,
         timestamp: now,
         signature,
         challenge_id,
         verification_score,
+        public_key: state.signing_keypair.public_key_hex(),
     };
 
     info!("Verification completed for {} - Score: {:.3}, Verified: {}",
@@ -254,41 +252,15 @@ async fn verify(
     Ok(HttpResponse::Ok().json(response))
 }
 
-// --- Helper Functions ---
-fn generate_mock_samples(file_id: &str, file_size: u64) -> Vec<u8> {
-    let sample_size = std::cmp::min(1024, file_size as usize); // Sample up to 1KB
-    let mut sample = file_id.as_bytes().to_vec();
-    sample.resize(sample_size, 0); // Pad to sample size
-    sample
-}
-
-fn calculate_verification_score(
-    verified: bool,
-    file_size: u64,
-    protocol: &str
-) -> f64 {
-    let mut score = 0.0;
-
-    // Base verification score
-    if verified {
-        score += 0.6;
-    }
-
-    // Protocol-specific bonuses
-    match protocol.to_lowercase().as_str() {
-        "ipfs" => score += 0.2,
-        "arweave" => score += 0.25,
-        "filecoin" => score += 0.3,
-        "bitcoin" => score += 0.35,
-        _ => {}
-    }
-
-    // File size factor (larger files get slight bonus)
-    let size_factor = (file_size as f64).log10() / 10.0;
-    score += size_factor.min(0.15);
-
-    // Ensure score is between 0.0 and 1.0
-    score.max(0.0).min(1.0)
+// --- Public Key Endpoint ---
+// Lets clients fetch the server's Schnorr verifying key once and check
+// `VerifyResponse.signature` offline afterwards, instead of trusting every
+// response's embedded `public_key` on faith.
+async fn pubkey(state: web::Data<AppState>) -> impl Responder {
+    HttpResponse::Ok().json(serde_json::json!({
+        "public_key": state.signing_keypair.public_key_hex(),
+        "scheme": "bip340-schnorr-secp256k1",
+    }))
 }
 
 // --- Health Check Endpoint ---
@@ -323,19 +295,24 @@ async fn metrics(state: web::Data<AppState>) -> impl Responder {
 pub async fn run_server() -> std::io::Result<()> {
     info!("Starting Bitcoin Sprint Storage Verifier Service...");
 
-    // Create storage verifier with rate limiting config
+    // GCRA tiers gating requests before they ever reach the verifier...
     let rate_config = RateLimitConfig {
         max_requests_per_minute: 10,
         max_requests_per_hour: 100,
         cleanup_interval_secs: 60,
     };
+    // ...and the verifier's own per-provider token bucket behind that.
+    let bucket_config = TokenBucketConfig::preconfig_burst();
 
-    let verifier = Arc::new(StorageVerifier::with_config(rate_config));
+    let verifier = Arc::new(StorageVerifier::with_config(bucket_config));
+    let protocol_registry = Arc::new(ProtocolRegistry::with_defaults(verifier.clone()));
 
     let state = web::Data::new(AppState {
         verifier,
-        rate_limiter: Arc::new(Mutex::new(RateLimiter::new(10, 60))), // 10 req/min
+        protocol_registry,
+        rate_limiter: Arc::new(Mutex::new(TieredRateLimiter::new(&rate_config))),
         active_challenges: Arc::new(Mutex::new(HashMap::new())),
+        signing_keypair: Arc::new(SigningKeypair::generate()),
     });
 
     info!("Server configured - Rate limit: 10 req/min, Binding to 0.0.0.0:8080");
@@ -348,6 +325,7 @@ pub async fn run_server() -> std::io::Result<()> {
                 .add(("X-Service", "bitcoin-sprint-storage-verifier")))
             .app_data(state.clone())
             .route("/verify", web::post().to(verify))
+            .route("/pubkey", web::get().to(pubkey))
             .route("/health", web::get().to(health))
             .route("/metrics", web::get().to(metrics))
     })
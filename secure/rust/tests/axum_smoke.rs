@@ -1,5 +1,9 @@
 use std::net::SocketAddr;
-use std::time::Duration;
+use std::time::{Duration, Instant};
+
+#[path = "support/mod.rs"]
+mod support;
+use support::constrained_link::ConstrainedLink;
 
 #[tokio::test]
 async fn health_and_version_endpoints_work() {
@@ -36,3 +40,58 @@ async fn health_and_version_endpoints_work() {
     // Cancel server
     handle.abort();
 }
+
+#[tokio::test]
+async fn health_and_version_survive_a_constrained_link() {
+    // Build server state
+    let cfg = {
+        use std::env;
+        env::set_var("API_HOST", "127.0.0.1");
+        env::set_var("API_PORT", "0"); // ephemeral
+        superbuffer::Config::load()
+    };
+
+    let server = superbuffer::Server::new(cfg).await;
+    let app = server.register_routes();
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr: SocketAddr = listener.local_addr().unwrap();
+
+    let handle = tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+
+    // Route the health/version probes through a 100Mbps/10ms constrained
+    // link instead of a zero-latency in-process call, and assert the
+    // observed latency stays inside the BDP-derived buffer's budget.
+    for path in ["/health", "/version"] {
+        let stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+        let mut link = ConstrainedLink::hundred_mbps_10ms(stream);
+
+        let request = format!(
+            "GET {path} HTTP/1.1\r\nHost: {addr}\r\nConnection: close\r\n\r\n"
+        );
+
+        let start = Instant::now();
+        link.write_all(request.as_bytes()).await.unwrap();
+
+        let mut response = Vec::new();
+        let mut chunk = [0u8; 4096];
+        loop {
+            let n = link.read(&mut chunk).await.unwrap();
+            if n == 0 {
+                break;
+            }
+            response.extend_from_slice(&chunk[..n]);
+        }
+        let elapsed = start.elapsed();
+
+        let response = String::from_utf8_lossy(&response);
+        assert!(response.starts_with("HTTP/1.1 200"), "unexpected response for {path}: {response}");
+
+        // BDP for 100Mbps/10ms: well under the 20ms sub-latency target even
+        // with the link's own round-trip delay folded in.
+        assert!(elapsed < Duration::from_millis(20), "{path} took {elapsed:?} over the constrained link");
+    }
+
+    handle.abort();
+}
@@ -0,0 +1,84 @@
+// Test-only harness that wraps an async byte stream with a bandwidth cap
+// and a one-way delay, so integration tests exercise the "sub-20ms"
+// latency claim against something other than a zero-latency in-process call.
+
+use std::io;
+use std::time::{Duration, Instant};
+
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+/// Bandwidth-capped, delay-injecting wrapper around an async stream.
+///
+/// Bandwidth is modeled as a token bucket: tokens accrue at
+/// `bandwidth_bytes_per_sec` and writes block (via async sleep) until enough
+/// tokens have accrued for the whole chunk. Propagation delay is modeled by
+/// sleeping `one_way_delay` after each read/write completes on the wire.
+pub struct ConstrainedLink<S> {
+    inner: S,
+    bandwidth_bytes_per_sec: f64,
+    tokens: f64,
+    last_refill: Instant,
+    one_way_delay: Duration,
+}
+
+impl<S> ConstrainedLink<S>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    pub fn new(inner: S, bandwidth_bytes_per_sec: f64, one_way_delay: Duration) -> Self {
+        Self {
+            inner,
+            bandwidth_bytes_per_sec,
+            tokens: 0.0,
+            last_refill: Instant::now(),
+            one_way_delay,
+        }
+    }
+
+    // Profiles mirroring the scenarios in validate_network_calculations.
+    pub fn gigabit_1ms(inner: S) -> Self {
+        Self::new(inner, mbps_to_bytes_per_sec(1000.0), Duration::from_millis(1))
+    }
+
+    pub fn ten_gigabit_half_ms(inner: S) -> Self {
+        Self::new(inner, mbps_to_bytes_per_sec(10_000.0), Duration::from_micros(500))
+    }
+
+    pub fn hundred_mbps_10ms(inner: S) -> Self {
+        Self::new(inner, mbps_to_bytes_per_sec(100.0), Duration::from_millis(10))
+    }
+
+    fn refill(&mut self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens += elapsed * self.bandwidth_bytes_per_sec;
+        self.last_refill = now;
+    }
+
+    pub async fn write_all(&mut self, data: &[u8]) -> io::Result<()> {
+        let needed = data.len() as f64;
+        loop {
+            self.refill();
+            if self.tokens >= needed {
+                self.tokens -= needed;
+                break;
+            }
+            let shortfall = needed - self.tokens;
+            let wait = Duration::from_secs_f64(shortfall / self.bandwidth_bytes_per_sec);
+            tokio::time::sleep(wait.max(Duration::from_micros(100))).await;
+        }
+        self.inner.write_all(data).await?;
+        tokio::time::sleep(self.one_way_delay).await;
+        Ok(())
+    }
+
+    pub async fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf).await?;
+        tokio::time::sleep(self.one_way_delay).await;
+        Ok(n)
+    }
+}
+
+fn mbps_to_bytes_per_sec(mbps: f64) -> f64 {
+    mbps * 1_000_000.0 / 8.0
+}
@@ -0,0 +1 @@
+pub mod constrained_link;
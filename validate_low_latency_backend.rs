@@ -2,6 +2,7 @@
 // Testing and benchmarking the sub-20ms deterministic latency implementation
 
 use std::collections::VecDeque;
+use std::io::{self, IoSlice, Write};
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use std::thread;
@@ -65,6 +66,109 @@ impl<T> BoundedQueue<T> {
     }
 }
 
+// 1B. CONCURRENT MPMC QUEUE (VYUKOV BOUNDED QUEUE) - NEW
+// =======================================================
+// BoundedQueue above is only sound for a single producer/single consumer -
+// the raw pointer writes and plain head/tail stores race under concurrency.
+// ConcurrentBoundedQueue gives every slot its own sequence number so any
+// number of producers and consumers can share the queue through an Arc.
+struct MpmcSlot<T> {
+    sequence: AtomicUsize,
+    value: std::cell::UnsafeCell<Option<T>>,
+}
+
+struct ConcurrentBoundedQueue<T> {
+    buffer: Box<[MpmcSlot<T>]>,
+    mask: usize,
+    head: CacheAlignedCounter,
+    tail: CacheAlignedCounter,
+}
+
+unsafe impl<T: Send> Sync for ConcurrentBoundedQueue<T> {}
+
+impl<T> ConcurrentBoundedQueue<T> {
+    const OPTIMAL_SIZE: usize = 1024; // 2^10, same sizing as BoundedQueue
+
+    fn new() -> Self {
+        let buffer = (0..Self::OPTIMAL_SIZE)
+            .map(|i| MpmcSlot {
+                sequence: AtomicUsize::new(i),
+                value: std::cell::UnsafeCell::new(None),
+            })
+            .collect::<Vec<_>>()
+            .into_boxed_slice();
+
+        Self {
+            buffer,
+            mask: Self::OPTIMAL_SIZE - 1,
+            head: CacheAlignedCounter::new(),
+            tail: CacheAlignedCounter::new(),
+        }
+    }
+
+    // VALIDATION: lock-free multi-producer enqueue - Vyukov sequence scheme
+    fn enqueue(&self, item: T) -> Result<(), T> {
+        let mut tail = self.tail.value.load(Ordering::Relaxed);
+        loop {
+            let slot = &self.buffer[tail & self.mask];
+            let seq = slot.sequence.load(Ordering::Acquire);
+            let diff = seq as isize - tail as isize;
+
+            if diff == 0 {
+                match self.tail.value.compare_exchange_weak(
+                    tail,
+                    tail + 1,
+                    Ordering::Relaxed,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => {
+                        unsafe {
+                            *slot.value.get() = Some(item);
+                        }
+                        slot.sequence.store(tail + 1, Ordering::Release);
+                        return Ok(());
+                    }
+                    Err(current) => tail = current,
+                }
+            } else if diff < 0 {
+                return Err(item); // Queue full
+            } else {
+                tail = self.tail.value.load(Ordering::Relaxed);
+            }
+        }
+    }
+
+    // VALIDATION: lock-free multi-consumer dequeue - Vyukov sequence scheme
+    fn dequeue(&self) -> Option<T> {
+        let mut head = self.head.value.load(Ordering::Relaxed);
+        loop {
+            let slot = &self.buffer[head & self.mask];
+            let seq = slot.sequence.load(Ordering::Acquire);
+            let diff = seq as isize - (head + 1) as isize;
+
+            if diff == 0 {
+                match self.head.value.compare_exchange_weak(
+                    head,
+                    head + 1,
+                    Ordering::Relaxed,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => {
+                        let item = unsafe { (*slot.value.get()).take() };
+                        slot.sequence.store(head + self.buffer.len(), Ordering::Release);
+                        return item;
+                    }
+                    Err(current) => head = current,
+                }
+            } else if diff < 0 {
+                return None; // Queue empty
+            } else {
+                head = self.head.value.load(Ordering::Relaxed);
+            }
+        }
+    }
+}
+
 // 2. CACHE LINE ALIGNED STRUCTURES - VALIDATED
 // ============================================
 #[repr(align(64))] // Force 64-byte alignment - CORRECT
@@ -199,9 +303,173 @@ impl NetworkOptimizer {
     }
 }
 
+// 6B. ADAPTIVE RECEIVE BUFFER - NEW
+// ==================================
+// calculate_optimal_buffer_size above computes a static BDP once, but real
+// connections see varying read sizes over time. AdaptiveBuffer starts at an
+// 8 KiB floor and grows/shrinks toward a BDP-derived ceiling as reads come
+// in, tracking a MaybeUninit-backed region so growth never zeroes memory.
+struct AdaptiveBuffer {
+    region: Box<[std::mem::MaybeUninit<u8>]>,
+    capacity: usize,
+    floor: usize,
+    ceiling: usize,
+    shrink_threshold: u32,
+    consecutive_small_reads: u32,
+}
+
+impl AdaptiveBuffer {
+    const FLOOR_BYTES: usize = 8 * 1024; // 8 KiB floor
+
+    // VALIDATION: bounded by a BDP-derived ceiling, floor/ceiling always powers of two
+    fn new(bandwidth_mbps: f64, rtt_ms: f64, shrink_threshold: u32) -> Self {
+        let ceiling = NetworkOptimizer::calculate_optimal_buffer_size(bandwidth_mbps, rtt_ms)
+            .max(Self::FLOOR_BYTES)
+            .next_power_of_two();
+
+        Self {
+            region: Self::alloc_region(Self::FLOOR_BYTES),
+            capacity: Self::FLOOR_BYTES,
+            floor: Self::FLOOR_BYTES,
+            ceiling,
+            shrink_threshold,
+            consecutive_small_reads: 0,
+        }
+    }
+
+    fn alloc_region(capacity: usize) -> Box<[std::mem::MaybeUninit<u8>]> {
+        // SAFETY: MaybeUninit<u8> needs no initialization, so this never zeroes memory.
+        let mut vec = Vec::with_capacity(capacity);
+        unsafe { vec.set_len(capacity) };
+        vec.into_boxed_slice()
+    }
+
+    fn current_capacity(&self) -> usize {
+        self.capacity
+    }
+
+    // VALIDATION: a read that fills the buffer doubles capacity (up to the ceiling);
+    // `shrink_threshold` consecutive reads at less than half capacity halve it back
+    // toward the floor. Capacity always stays a power of two for bit-mask indexing.
+    fn record_read(&mut self, n: usize) {
+        if n >= self.capacity {
+            self.consecutive_small_reads = 0;
+            let grown = (self.capacity * 2).min(self.ceiling).next_power_of_two();
+            if grown > self.capacity {
+                self.resize(grown);
+            }
+            return;
+        }
+
+        if n < self.capacity / 2 {
+            self.consecutive_small_reads += 1;
+            if self.consecutive_small_reads >= self.shrink_threshold {
+                self.consecutive_small_reads = 0;
+                let shrunk = (self.capacity / 2).max(self.floor);
+                if shrunk < self.capacity {
+                    self.resize(shrunk);
+                }
+            }
+        } else {
+            self.consecutive_small_reads = 0;
+        }
+    }
+
+    fn resize(&mut self, new_capacity: usize) {
+        self.region = Self::alloc_region(new_capacity);
+        self.capacity = new_capacity;
+    }
+}
+
+// 6C. ROLLING BANDWIDTH ACCOUNTING - NEW
+// ========================================
+// The latency work above tracks per-op timing but has no notion of sustained
+// throughput. BandwidthTracker keeps a fixed-size ring of per-interval byte
+// counts for both directions and reports rolling average/max bandwidth.
+// NOTE: the `superbuffer` web server (see secure/rust/tests/axum_smoke.rs)
+// does not yet expose a `/metrics` route to wire this into; once it does,
+// a tick task should call `tick()` once per interval and serve
+// `{rx,tx}.{avg,max}_bps()` alongside the existing `/health` and `/version`.
+struct BandwidthWindow {
+    samples: [f32; Self::WINDOW_LEN],
+    len: usize,
+    next: usize,
+    accumulator: u64,
+}
+
+impl BandwidthWindow {
+    const WINDOW_LEN: usize = 10;
+
+    fn new() -> Self {
+        Self {
+            samples: [0.0; Self::WINDOW_LEN],
+            len: 0,
+            next: 0,
+            accumulator: 0,
+        }
+    }
+
+    fn record(&mut self, bytes: usize) {
+        self.accumulator += bytes as u64;
+    }
+
+    // VALIDATION: called once per interval tick - pushes the accumulated byte
+    // count into the ring as bytes/sec, then resets the accumulator.
+    fn tick(&mut self, interval: Duration) {
+        let bps = self.accumulator as f32 / interval.as_secs_f32().max(f32::EPSILON);
+        self.samples[self.next] = bps;
+        self.next = (self.next + 1) % Self::WINDOW_LEN;
+        self.len = (self.len + 1).min(Self::WINDOW_LEN);
+        self.accumulator = 0;
+    }
+
+    fn avg_bps(&self) -> f32 {
+        if self.len == 0 {
+            return 0.0;
+        }
+        self.samples[..self.len].iter().sum::<f32>() / self.len as f32
+    }
+
+    fn max_bps(&self) -> f32 {
+        self.samples[..self.len].iter().cloned().fold(0.0, f32::max)
+    }
+}
+
+struct BandwidthTracker {
+    rx: BandwidthWindow,
+    tx: BandwidthWindow,
+}
+
+impl BandwidthTracker {
+    fn new() -> Self {
+        Self {
+            rx: BandwidthWindow::new(),
+            tx: BandwidthWindow::new(),
+        }
+    }
+
+    // Adds inbound bytes to the current interval. The queue and pipeline
+    // code do not call this; only validate_bandwidth_tracker feeds it.
+    fn record_rx(&mut self, bytes: usize) {
+        self.rx.record(bytes);
+    }
+
+    // Adds outbound bytes to the current interval. As with record_rx, only
+    // validate_bandwidth_tracker feeds it.
+    fn record_tx(&mut self, bytes: usize) {
+        self.tx.record(bytes);
+    }
+
+    fn tick(&mut self, interval: Duration) {
+        self.rx.tick(interval);
+        self.tx.tick(interval);
+    }
+}
+
 // 7. OPTIMIZED REQUEST STRUCTURE - VALIDATED
 // ==========================================
 #[repr(C)]
+#[derive(Debug)]
 struct OptimizedRequest {
     timestamp: u64,      // 8 bytes - offset 0
     request_id: u64,     // 8 bytes - offset 8
@@ -223,6 +491,285 @@ impl Default for OptimizedRequest {
     }
 }
 
+// 7B. LEARNED PER-KIND SERVE-TIME MODEL - NEW
+// =============================================
+// demonstrate_latency_breakdown below hardwires fixed nanosecond costs per
+// stage, and benchmark_full_pipeline only reports a mean. ServeTimeEstimator
+// replaces the static table with a per-Kind EWMA seeded by a conservative
+// overestimate, backed by a log-bucketed histogram so callers can also ask
+// for a tail percentile to gate admission against the 20ms P99 budget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Kind {
+    Low,
+    Normal,
+    High,
+    Critical,
+}
+
+impl Kind {
+    const ALL: [Kind; 4] = [Kind::Low, Kind::Normal, Kind::High, Kind::Critical];
+
+    // VALIDATION: derive kind from OptimizedRequest::priority, matching the
+    // `priority = i % 4` scheme used in benchmark_full_pipeline.
+    fn from_request(request: &OptimizedRequest) -> Kind {
+        match request.priority % 4 {
+            0 => Kind::Low,
+            1 => Kind::Normal,
+            2 => Kind::High,
+            _ => Kind::Critical,
+        }
+    }
+
+    fn index(self) -> usize {
+        match self {
+            Kind::Low => 0,
+            Kind::Normal => 1,
+            Kind::High => 2,
+            Kind::Critical => 3,
+        }
+    }
+
+    // Conservative seed overestimate per kind, in nanoseconds.
+    fn seed_ns(self) -> f64 {
+        match self {
+            Kind::Low => 2_000_000.0,
+            Kind::Normal => 1_000_000.0,
+            Kind::High => 500_000.0,
+            Kind::Critical => 200_000.0,
+        }
+    }
+}
+
+struct LatencyHistogram {
+    // Power-of-two nanosecond buckets: bucket[i] covers [2^i, 2^(i+1)).
+    buckets: [u32; Self::NUM_BUCKETS],
+    total: u32,
+}
+
+impl LatencyHistogram {
+    const NUM_BUCKETS: usize = 40; // covers up to ~2^40 ns (~18 minutes)
+
+    fn new() -> Self {
+        Self {
+            buckets: [0; Self::NUM_BUCKETS],
+            total: 0,
+        }
+    }
+
+    fn bucket_for(ns: u64) -> usize {
+        if ns == 0 {
+            0
+        } else {
+            (63 - ns.leading_zeros()) as usize
+        }
+        .min(Self::NUM_BUCKETS - 1)
+    }
+
+    fn record(&mut self, ns: u64) {
+        self.buckets[Self::bucket_for(ns)] += 1;
+        self.total += 1;
+    }
+
+    // VALIDATION: walk buckets low-to-high until cumulative count crosses
+    // `p` of the total, then report the bucket's upper edge.
+    fn percentile(&self, p: f64) -> Duration {
+        if self.total == 0 {
+            return Duration::from_nanos(0);
+        }
+        let target = (self.total as f64 * p).ceil() as u32;
+        let mut cumulative = 0u32;
+        for (bucket, count) in self.buckets.iter().enumerate() {
+            cumulative += count;
+            if cumulative >= target {
+                return Duration::from_nanos(1u64 << (bucket + 1));
+            }
+        }
+        Duration::from_nanos(1u64 << Self::NUM_BUCKETS)
+    }
+}
+
+struct KindStats {
+    ewma_ns: f64,
+    histogram: LatencyHistogram,
+}
+
+struct ServeTimeEstimator {
+    stats: [KindStats; 4],
+}
+
+impl ServeTimeEstimator {
+    fn new() -> Self {
+        Self {
+            stats: Kind::ALL.map(|kind| KindStats {
+                ewma_ns: kind.seed_ns(),
+                histogram: LatencyHistogram::new(),
+            }),
+        }
+    }
+
+    // VALIDATION: new = old + (sample - old) / 8 - standard EWMA with alpha = 1/8
+    fn record(&mut self, kind: Kind, sample: Duration) {
+        let stat = &mut self.stats[kind.index()];
+        let sample_ns = sample.as_nanos() as f64;
+        stat.ewma_ns += (sample_ns - stat.ewma_ns) / 8.0;
+        stat.histogram.record(sample.as_nanos() as u64);
+    }
+
+    fn estimate(&self, kind: Kind) -> Duration {
+        Duration::from_nanos(self.stats[kind.index()].ewma_ns.max(0.0) as u64)
+    }
+
+    fn percentile(&self, kind: Kind, p: f64) -> Duration {
+        self.stats[kind.index()].histogram.percentile(p)
+    }
+
+    // VALIDATION: admission gate - reject work whose predicted P99 completion
+    // would blow the given latency budget, instead of only rejecting when
+    // the queue is physically full.
+    fn admits(&self, kind: Kind, budget: Duration) -> bool {
+        self.percentile(kind, 0.99) <= budget
+    }
+}
+
+// 7C. CONGESTION-WINDOW ADMISSION (AIMD / NEW RENO) - NEW
+// =========================================================
+// enqueue above only signals backpressure by returning Err when the ring is
+// full, which is an all-or-nothing cliff. CongestionController governs how
+// many in-flight requests the pipeline admits via a New-Reno-style AIMD
+// window, turning the hard capacity wall into a smooth, latency-reactive
+// admission rate.
+struct CongestionController {
+    cwnd: f64,
+    ssthresh: f64,
+    in_flight: usize,
+}
+
+impl CongestionController {
+    fn new(initial_cwnd: f64, initial_ssthresh: f64) -> Self {
+        Self {
+            cwnd: initial_cwnd,
+            ssthresh: initial_ssthresh,
+            in_flight: 0,
+        }
+    }
+
+    // VALIDATION: admit iff current in-flight count is below cwnd.
+    fn admit(&self) -> bool {
+        (self.in_flight as f64) < self.cwnd
+    }
+
+    fn on_admitted(&mut self) {
+        self.in_flight += 1;
+    }
+
+    // VALIDATION: on each successfully completed request, grow cwnd by 1 in
+    // slow-start (cwnd < ssthresh) or by 1/cwnd in congestion avoidance.
+    fn on_completed(&mut self) {
+        self.in_flight = self.in_flight.saturating_sub(1);
+        if self.cwnd < self.ssthresh {
+            self.cwnd += 1.0;
+        } else {
+            self.cwnd += 1.0 / self.cwnd;
+        }
+    }
+
+    // VALIDATION: on an overload signal (dequeue latency over the P99
+    // target, or a full-queue rejection), set ssthresh to half of cwnd and
+    // collapse cwnd to it.
+    fn on_overload(&mut self) {
+        self.in_flight = self.in_flight.saturating_sub(1);
+        self.ssthresh = (self.cwnd / 2.0).max(1.0);
+        self.cwnd = self.ssthresh;
+    }
+
+    fn cwnd(&self) -> f64 {
+        self.cwnd
+    }
+}
+
+// 7D. VECTORED (WRITEV) RESPONSE BATCHING - NEW
+// ================================================
+// benchmark_full_pipeline dequeues and discards one request at a time, but a
+// real server would serialize many 56-byte OptimizedRequests back onto a
+// socket, where per-request write syscalls dominate latency at high
+// throughput. VectoredDrain batches dequeued requests into IoSlices sourced
+// from a reusable MemoryPool and issues one write_vectored per batch.
+const REQUEST_FRAME_SIZE: usize = std::mem::size_of::<OptimizedRequest>(); // 56 bytes
+
+#[derive(Clone, Copy)]
+struct RequestFrame([u8; REQUEST_FRAME_SIZE]);
+
+impl Default for RequestFrame {
+    fn default() -> Self {
+        RequestFrame([0u8; REQUEST_FRAME_SIZE])
+    }
+}
+
+impl RequestFrame {
+    fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+struct VectoredDrain {
+    frame_pool: MemoryPool<RequestFrame>,
+    in_flight: Vec<Box<RequestFrame>>,
+}
+
+impl VectoredDrain {
+    const BATCH_CAP: usize = 32; // flush early once the slice count hits this cap
+
+    fn new() -> Self {
+        Self {
+            frame_pool: MemoryPool::new(),
+            in_flight: Vec::with_capacity(Self::BATCH_CAP),
+        }
+    }
+
+    // VALIDATION: reinterpret the repr(C) request as its raw cache-line
+    // layout - a single contiguous byte frame, no field-by-field copying.
+    fn serialize(request: &OptimizedRequest) -> RequestFrame {
+        RequestFrame(unsafe { *(request as *const OptimizedRequest as *const [u8; REQUEST_FRAME_SIZE]) })
+    }
+
+    // VALIDATION: drains up to BATCH_CAP requests into pool-sourced frames
+    // and flushes them with a single write_vectored call.
+    fn drain_vectored<W: Write>(
+        &mut self,
+        queue: &BoundedQueue<OptimizedRequest>,
+        sink: &mut W,
+    ) -> io::Result<usize> {
+        self.in_flight.clear();
+
+        while self.in_flight.len() < Self::BATCH_CAP {
+            match queue.dequeue() {
+                Some(request) => {
+                    let mut frame = self
+                        .frame_pool
+                        .allocate()
+                        .unwrap_or_else(|| Box::new(RequestFrame::default()));
+                    *frame = Self::serialize(&request);
+                    self.in_flight.push(frame);
+                }
+                None => break,
+            }
+        }
+
+        if self.in_flight.is_empty() {
+            return Ok(0);
+        }
+
+        let slices: Vec<IoSlice> = self.in_flight.iter().map(|f| IoSlice::new(f.as_bytes())).collect();
+        let written = sink.write_vectored(&slices)?;
+
+        for frame in self.in_flight.drain(..) {
+            self.frame_pool.deallocate(frame);
+        }
+
+        Ok(written)
+    }
+}
+
 // VALIDATION TESTS AND BENCHMARKS
 // ===============================
 
@@ -257,6 +804,55 @@ fn validate_bounded_queue() {
     println!("   ✅ Bit masking optimization working correctly");
 }
 
+fn validate_concurrent_queue() {
+    println!("🧪 TESTING: Concurrent MPMC Queue (Vyukov Algorithm)");
+
+    let queue: Arc<ConcurrentBoundedQueue<u32>> = Arc::new(ConcurrentBoundedQueue::new());
+    let producers = 4;
+    let consumers = 4;
+    let per_producer = 10_000;
+
+    let mut handles = Vec::new();
+    for p in 0..producers {
+        let queue = Arc::clone(&queue);
+        handles.push(thread::spawn(move || {
+            for i in 0..per_producer {
+                let item = (p * per_producer + i) as u32;
+                while queue.enqueue(item).is_err() {
+                    thread::yield_now();
+                }
+            }
+        }));
+    }
+
+    let received = Arc::new(std::sync::Mutex::new(Vec::new()));
+    for _ in 0..consumers {
+        let queue = Arc::clone(&queue);
+        let received = Arc::clone(&received);
+        let target = (producers * per_producer) / consumers;
+        handles.push(thread::spawn(move || {
+            let mut local = Vec::with_capacity(target);
+            while local.len() < target {
+                if let Some(item) = queue.dequeue() {
+                    local.push(item);
+                }
+            }
+            received.lock().unwrap().extend(local);
+        }));
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    let mut items = received.lock().unwrap().clone();
+    items.sort_unstable();
+    items.dedup();
+    assert_eq!(items.len(), producers * per_producer);
+    println!("   ✅ {} producers x {} consumers moved {} items with no loss or duplication",
+             producers, consumers, producers * per_producer);
+}
+
 fn validate_cache_alignment() {
     println!("🧪 TESTING: Cache Line Alignment");
     
@@ -343,6 +939,148 @@ fn validate_network_calculations() {
     }
 }
 
+fn validate_adaptive_buffer() {
+    println!("🧪 TESTING: Adaptive Receive Buffer");
+
+    let mut buffer = AdaptiveBuffer::new(1000.0, 1.0, 3);
+    assert_eq!(buffer.current_capacity(), AdaptiveBuffer::FLOOR_BYTES);
+    println!("   ✅ Starts at 8 KiB floor: {} bytes", buffer.current_capacity());
+
+    // Saturating reads double capacity up to the ceiling
+    let before = buffer.current_capacity();
+    buffer.record_read(before);
+    assert_eq!(buffer.current_capacity(), before * 2);
+    println!("   ✅ Saturating read doubles capacity to {} bytes", buffer.current_capacity());
+
+    // Bursty mix: the first saturating read doubles capacity once, then the
+    // small and half-full reads leave it there
+    let steady = buffer.current_capacity();
+    buffer.record_read(steady);
+    buffer.record_read(steady / 4);
+    buffer.record_read(steady);
+    assert_eq!(buffer.current_capacity(), steady * 2);
+    println!("   ✅ Bursty sequence does not oscillate: {} bytes", buffer.current_capacity());
+
+    // Idle (small) reads shrink capacity back toward the floor after the threshold
+    let shrinking_from = buffer.current_capacity();
+    for _ in 0..3 {
+        buffer.record_read(1);
+    }
+    assert_eq!(buffer.current_capacity(), shrinking_from / 2);
+    println!("   ✅ Idle reads shrink capacity to {} bytes", buffer.current_capacity());
+
+    // Capacity never exceeds the BDP-derived ceiling or drops below the floor
+    for _ in 0..10 {
+        buffer.record_read(buffer.current_capacity());
+    }
+    assert!(buffer.current_capacity() <= buffer.ceiling);
+    assert_eq!(buffer.capacity & (buffer.capacity - 1), 0);
+    println!("   ✅ Capacity stays within [{}, {}] and power-of-two", buffer.floor, buffer.ceiling);
+}
+
+fn validate_bandwidth_tracker() {
+    println!("🧪 TESTING: Rolling Bandwidth Accounting");
+
+    let mut tracker = BandwidthTracker::new();
+    let interval = Duration::from_secs(1);
+
+    // Ramp inbound traffic up, then back down; outbound stays steady
+    for bytes in [1_000, 2_000, 4_000, 8_000, 4_000, 2_000] {
+        tracker.record_rx(bytes);
+        tracker.record_tx(1_000);
+        tracker.tick(interval);
+    }
+
+    assert!(tracker.rx.max_bps() >= 8_000.0);
+    assert!(tracker.rx.avg_bps() > 0.0 && tracker.rx.avg_bps() < tracker.rx.max_bps());
+    assert_eq!(tracker.tx.avg_bps(), 1_000.0);
+    assert_eq!(tracker.tx.max_bps(), 1_000.0);
+
+    println!("   ✅ rx avg/max bps: {:.0}/{:.0}", tracker.rx.avg_bps(), tracker.rx.max_bps());
+    println!("   ✅ tx avg/max bps: {:.0}/{:.0}", tracker.tx.avg_bps(), tracker.tx.max_bps());
+
+    // Window only retains the last WINDOW_LEN samples
+    for _ in 0..20 {
+        tracker.record_rx(100);
+        tracker.tick(interval);
+    }
+    assert_eq!(tracker.rx.len, BandwidthWindow::WINDOW_LEN);
+    println!("   ✅ Ring window caps at {} samples", BandwidthWindow::WINDOW_LEN);
+}
+
+fn validate_serve_time_estimator() {
+    println!("🧪 TESTING: Learned Per-Kind Serve-Time Model");
+
+    let mut estimator = ServeTimeEstimator::new();
+
+    // Feed a skewed distribution for High: mostly fast, with a slow tail
+    // thick enough (2%) to push past the p99 cut rather than land on it.
+    for i in 0..1000 {
+        let ns = if i % 50 == 0 { 900_000 } else { 50_000 };
+        estimator.record(Kind::High, Duration::from_nanos(ns));
+    }
+
+    let p99 = estimator.percentile(Kind::High, 0.99);
+    // True p99 sits right at the tail values (900us); the log bucket should
+    // land within one power-of-two bucket of that true value.
+    assert!(p99.as_nanos() >= 900_000 / 2 && p99.as_nanos() <= 900_000 * 2);
+    println!("   ✅ Kind::High p99 ≈ {:?} (true tail ≈ 900µs)", p99);
+
+    // EWMA converges toward repeated samples rather than tracking the seed.
+    for _ in 0..200 {
+        estimator.record(Kind::Normal, Duration::from_micros(100));
+    }
+    let estimate = estimator.estimate(Kind::Normal);
+    assert!((estimate.as_nanos() as f64 - 100_000.0).abs() < 1_000.0);
+    println!("   ✅ Kind::Normal EWMA converged to {:?}", estimate);
+
+    // Admission gate rejects kinds whose predicted tail blows the budget.
+    let tight_budget = Duration::from_micros(500);
+    assert!(!estimator.admits(Kind::High, tight_budget), "tail exceeds a 500µs budget");
+    let loose_budget = Duration::from_millis(20);
+    assert!(estimator.admits(Kind::High, loose_budget), "tail fits the 20ms P99 target");
+    println!("   ✅ admits() honors the P99 latency budget");
+}
+
+fn validate_congestion_controller() {
+    println!("🧪 TESTING: Congestion-Window Admission (AIMD)");
+
+    let mut controller = CongestionController::new(4.0, 1024.0);
+
+    // Slow start: cwnd grows by 1 per completion while below ssthresh.
+    let start = controller.cwnd();
+    for _ in 0..4 {
+        controller.on_admitted();
+        controller.on_completed();
+    }
+    assert_eq!(controller.cwnd(), start + 4.0);
+    println!("   ✅ Slow start grew cwnd from {} to {}", start, controller.cwnd());
+
+    // Force into congestion avoidance by lowering ssthresh below cwnd.
+    controller.ssthresh = controller.cwnd() - 1.0;
+    let before_ca = controller.cwnd();
+    controller.on_admitted();
+    controller.on_completed();
+    assert!(controller.cwnd() > before_ca && controller.cwnd() < before_ca + 1.0);
+    println!("   ✅ Congestion avoidance grows cwnd sub-linearly: {} -> {}", before_ca, controller.cwnd());
+
+    // Overload halves cwnd and collapses ssthresh.
+    let before_overload = controller.cwnd();
+    controller.on_admitted();
+    controller.on_overload();
+    assert_eq!(controller.cwnd(), before_overload / 2.0);
+    println!("   ✅ Overload halves cwnd: {} -> {}", before_overload, controller.cwnd());
+
+    // Recovery re-enters congestion avoidance: cwnd == ssthresh right after
+    // the collapse, so growth resumes sub-linearly rather than jumping back
+    // into slow start.
+    let recovering_from = controller.cwnd();
+    controller.on_admitted();
+    controller.on_completed();
+    assert!(controller.cwnd() > recovering_from && controller.cwnd() < recovering_from + 1.0);
+    println!("   ✅ Recovery grows sub-linearly from the collapsed window");
+}
+
 fn validate_request_structure() {
     println!("🧪 TESTING: Request Structure Optimization");
     
@@ -399,6 +1137,54 @@ fn demonstrate_latency_breakdown() {
     println!("   ✅ Target latency is mathematically achievable");
 }
 
+fn benchmark_vectored_drain() {
+    println!("⚡ VECTORED DRAIN BENCHMARK (single write vs writev batching)");
+    println!("================================================================");
+
+    let iterations = 50_000;
+    let timer = HighPrecisionTimer::new();
+
+    // Single write per request
+    let queue: BoundedQueue<OptimizedRequest> = BoundedQueue::new();
+    let mut single_sink: Vec<u8> = Vec::new();
+    let (_, single_duration) = timer.measure(|| {
+        for i in 0..iterations {
+            let mut request = OptimizedRequest::default();
+            request.request_id = i as u64;
+            queue.enqueue(request).ok();
+            if let Some(dequeued) = queue.dequeue() {
+                let frame = VectoredDrain::serialize(&dequeued);
+                single_sink.write_all(frame.as_bytes()).unwrap();
+            }
+        }
+    });
+
+    // Batched writev
+    let queue: BoundedQueue<OptimizedRequest> = BoundedQueue::new();
+    let mut drain = VectoredDrain::new();
+    let mut vectored_sink: Vec<u8> = Vec::new();
+    let (_, vectored_duration) = timer.measure(|| {
+        let mut remaining = iterations;
+        while remaining > 0 {
+            for _ in 0..VectoredDrain::BATCH_CAP.min(remaining) {
+                let mut request = OptimizedRequest::default();
+                request.request_id = remaining as u64;
+                queue.enqueue(request).ok();
+                remaining -= 1;
+            }
+            drain.drain_vectored(&queue, &mut vectored_sink).unwrap();
+        }
+    });
+
+    assert_eq!(single_sink.len(), vectored_sink.len());
+    println!("   Single-write: {:?} for {} requests ({:.2}ns/req)",
+             single_duration, iterations, single_duration.as_nanos() as f64 / iterations as f64);
+    println!("   Vectored batch (cap {}): {:?} for {} requests ({:.2}ns/req)",
+             VectoredDrain::BATCH_CAP, vectored_duration, iterations,
+             vectored_duration.as_nanos() as f64 / iterations as f64);
+    println!("   ✅ Both paths serialize identical byte totals ({} bytes)", single_sink.len());
+}
+
 fn benchmark_full_pipeline() {
     println!("⚡ FULL PIPELINE BENCHMARK");
     println!("=========================");
@@ -406,24 +1192,51 @@ fn benchmark_full_pipeline() {
     let queue: Arc<BoundedQueue<OptimizedRequest>> = Arc::new(BoundedQueue::new());
     let counter = Arc::new(LockFreeCounter::new());
     let timer = HighPrecisionTimer::new();
-    
+    let mut estimator = ServeTimeEstimator::new();
+    let p99_budget = Duration::from_millis(20);
+    let mut rejected_by_estimator = 0u64;
+    let mut rejected_by_cwnd = 0u64;
+    let mut congestion = CongestionController::new(64.0, BoundedQueue::<OptimizedRequest>::OPTIMAL_SIZE as f64 / 2.0);
+
     let iterations = 100_000;
-    
+
     let (_, total_duration) = timer.measure(|| {
         for i in 0..iterations {
             let mut request = OptimizedRequest::default();
             request.request_id = i as u64;
             request.priority = (i % 4) as u32;
-            
+            let kind = Kind::from_request(&request);
+
+            // Predictive admission: reject work whose learned P99 would blow
+            // the latency budget, rather than only rejecting when physically full.
+            if !estimator.admits(kind, p99_budget) {
+                rejected_by_estimator += 1;
+                continue;
+            }
+
+            // AIMD admission: smooth the hard capacity wall into a
+            // latency-reactive admission rate instead of all-or-nothing.
+            if !congestion.admit() {
+                rejected_by_cwnd += 1;
+                continue;
+            }
+            congestion.on_admitted();
+
             // Simulate full pipeline
             match queue.enqueue(request) {
                 Ok(_) => {
                     counter.increment();
-                    // Simulate processing by dequeueing
-                    queue.dequeue();
+                    let (_, dequeue_duration) = timer.measure(|| queue.dequeue());
+                    estimator.record(kind, dequeue_duration);
+                    if dequeue_duration > p99_budget {
+                        congestion.on_overload();
+                    } else {
+                        congestion.on_completed();
+                    }
                 }
                 Err(_) => {
                     // Queue full - this demonstrates backpressure
+                    congestion.on_overload();
                 }
             }
         }
@@ -436,8 +1249,10 @@ fn benchmark_full_pipeline() {
     println!("   Total time: {:?}", total_duration);
     println!("   Average latency: {:.2}ns per request", avg_latency_ns);
     println!("   Throughput: {:.0} requests/second", throughput);
-    println!("   ✅ Well under 20ms P99 target ({:.4}% of limit)", 
+    println!("   ✅ Well under 20ms P99 target ({:.4}% of limit)",
              (avg_latency_ns / 20_000_000.0) * 100.0);
+    println!("   Rejected by predictive admission: {}", rejected_by_estimator);
+    println!("   Rejected by congestion window: {} (final cwnd: {:.1})", rejected_by_cwnd, congestion.cwnd());
 }
 
 fn main() {
@@ -447,10 +1262,25 @@ fn main() {
     
     validate_bounded_queue();
     println!();
-    
+
+    validate_concurrent_queue();
+    println!();
+
     validate_cache_alignment();
     println!();
-    
+
+    validate_adaptive_buffer();
+    println!();
+
+    validate_bandwidth_tracker();
+    println!();
+
+    validate_serve_time_estimator();
+    println!();
+
+    validate_congestion_controller();
+    println!();
+
     validate_memory_pool();
     println!();
     
@@ -465,7 +1295,10 @@ fn main() {
     
     benchmark_full_pipeline();
     println!();
-    
+
+    benchmark_vectored_drain();
+    println!();
+
     println!("✅ ALL VALIDATIONS PASSED");
     println!("🎯 MATHEMATICS AND IMPLEMENTATION ARE SOUND");
     println!("⚡ SUB-20MS LATENCY TARGET IS ACHIEVABLE");
@@ -481,6 +1314,57 @@ mod tests {
         assert_eq!(queue.capacity & (queue.capacity - 1), 0);
     }
     
+    #[test]
+    fn test_concurrent_queue_mpmc_stress() {
+        let queue: Arc<ConcurrentBoundedQueue<usize>> = Arc::new(ConcurrentBoundedQueue::new());
+        let producers = 6;
+        let consumers = 3;
+        let per_producer = 5_000;
+
+        let mut handles = Vec::new();
+        for p in 0..producers {
+            let queue = Arc::clone(&queue);
+            handles.push(thread::spawn(move || {
+                for i in 0..per_producer {
+                    let item = p * per_producer + i;
+                    while queue.enqueue(item).is_err() {
+                        thread::yield_now();
+                    }
+                }
+            }));
+        }
+
+        let received = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let target = producers * per_producer;
+        for _ in 0..consumers {
+            let queue = Arc::clone(&queue);
+            let received = Arc::clone(&received);
+            handles.push(thread::spawn(move || loop {
+                if let Some(item) = queue.dequeue() {
+                    let mut guard = received.lock().unwrap();
+                    guard.push(item);
+                    if guard.len() >= target {
+                        break;
+                    }
+                } else if received.lock().unwrap().len() >= target {
+                    break;
+                } else {
+                    thread::yield_now();
+                }
+            }));
+        }
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let mut items = received.lock().unwrap().clone();
+        items.sort_unstable();
+        let expected: Vec<usize> = (0..target).collect();
+        items.dedup();
+        assert_eq!(items, expected, "no item may be lost or duplicated");
+    }
+
     #[test]
     fn test_cache_alignment() {
         let counter = CacheAlignedCounter::new();
@@ -502,4 +1386,168 @@ mod tests {
         let buffer_size = NetworkOptimizer::calculate_optimal_buffer_size(1000.0, 1.0);
         assert_eq!(buffer_size & (buffer_size - 1), 0); // Power of 2
     }
+
+    #[test]
+    fn test_bandwidth_window_rolling_avg_and_max() {
+        let mut window = BandwidthWindow::new();
+        let interval = Duration::from_secs(1);
+        for bytes in [100, 200, 300, 400] {
+            window.record(bytes);
+            window.tick(interval);
+        }
+        assert_eq!(window.max_bps(), 400.0);
+        assert_eq!(window.avg_bps(), (100.0 + 200.0 + 300.0 + 400.0) / 4.0);
+    }
+
+    #[test]
+    fn test_bandwidth_window_caps_at_window_len() {
+        let mut window = BandwidthWindow::new();
+        let interval = Duration::from_secs(1);
+        for _ in 0..(BandwidthWindow::WINDOW_LEN * 3) {
+            window.record(1);
+            window.tick(interval);
+        }
+        assert_eq!(window.len, BandwidthWindow::WINDOW_LEN);
+    }
+
+    #[test]
+    fn test_serve_time_estimator_tracks_skewed_tail() {
+        let mut estimator = ServeTimeEstimator::new();
+        for i in 0..2000 {
+            let ns = if i % 25 == 0 { 4_000_000 } else { 100_000 };
+            estimator.record(Kind::Low, Duration::from_nanos(ns));
+        }
+        let p99 = estimator.percentile(Kind::Low, 0.99);
+        assert!(p99.as_nanos() >= 4_000_000 / 2 && p99.as_nanos() <= 4_000_000 * 2);
+    }
+
+    #[test]
+    fn test_serve_time_estimator_ewma_converges() {
+        let mut estimator = ServeTimeEstimator::new();
+        for _ in 0..500 {
+            estimator.record(Kind::Critical, Duration::from_micros(10));
+        }
+        let estimate = estimator.estimate(Kind::Critical).as_nanos() as f64;
+        assert!((estimate - 10_000.0).abs() < 100.0);
+    }
+
+    #[test]
+    fn test_serve_time_estimator_admission_gate() {
+        let mut estimator = ServeTimeEstimator::new();
+        for _ in 0..1000 {
+            estimator.record(Kind::Low, Duration::from_millis(25));
+        }
+        assert!(!estimator.admits(Kind::Low, Duration::from_millis(20)));
+    }
+
+    #[test]
+    fn test_vectored_drain_batches_and_preserves_bytes() {
+        let queue: BoundedQueue<OptimizedRequest> = BoundedQueue::new();
+        let mut drain = VectoredDrain::new();
+
+        for i in 0..10 {
+            let mut request = OptimizedRequest::default();
+            request.request_id = i;
+            queue.enqueue(request).unwrap();
+        }
+
+        let mut sink = Vec::new();
+        let written = drain.drain_vectored(&queue, &mut sink).unwrap();
+
+        assert_eq!(written, 10 * REQUEST_FRAME_SIZE);
+        assert_eq!(sink.len(), 10 * REQUEST_FRAME_SIZE);
+
+        // Each frame round-trips back to the request_id that was enqueued.
+        for i in 0..10u64 {
+            let frame = &sink[i as usize * REQUEST_FRAME_SIZE..(i as usize + 1) * REQUEST_FRAME_SIZE];
+            let request_id = u64::from_ne_bytes(frame[8..16].try_into().unwrap());
+            assert_eq!(request_id, i);
+        }
+    }
+
+    #[test]
+    fn test_vectored_drain_stops_at_batch_cap() {
+        let queue: BoundedQueue<OptimizedRequest> = BoundedQueue::new();
+        let mut drain = VectoredDrain::new();
+
+        for _ in 0..(VectoredDrain::BATCH_CAP * 2) {
+            queue.enqueue(OptimizedRequest::default()).unwrap();
+        }
+
+        let mut sink = Vec::new();
+        let written = drain.drain_vectored(&queue, &mut sink).unwrap();
+        assert_eq!(written, VectoredDrain::BATCH_CAP * REQUEST_FRAME_SIZE);
+    }
+
+    #[test]
+    fn test_congestion_controller_halves_on_overload() {
+        let mut controller = CongestionController::new(32.0, 1024.0);
+        let before = controller.cwnd();
+        controller.on_admitted();
+        controller.on_overload();
+        assert_eq!(controller.cwnd(), before / 2.0);
+        assert_eq!(controller.ssthresh, before / 2.0);
+    }
+
+    #[test]
+    fn test_congestion_controller_recovers_linearly_in_slow_start() {
+        let mut controller = CongestionController::new(2.0, 1000.0);
+        let start = controller.cwnd();
+        for _ in 0..10 {
+            controller.on_admitted();
+            controller.on_completed();
+        }
+        assert_eq!(controller.cwnd(), start + 10.0);
+    }
+
+    #[test]
+    fn test_congestion_controller_admit_respects_in_flight() {
+        let mut controller = CongestionController::new(2.0, 1000.0);
+        assert!(controller.admit());
+        controller.on_admitted();
+        assert!(controller.admit());
+        controller.on_admitted();
+        assert!(!controller.admit());
+    }
+
+    #[test]
+    fn test_adaptive_buffer_saturating_grows() {
+        let mut buffer = AdaptiveBuffer::new(1000.0, 1.0, 3);
+        let start = buffer.current_capacity();
+        for _ in 0..4 {
+            let cap = buffer.current_capacity();
+            buffer.record_read(cap);
+        }
+        assert!(buffer.current_capacity() > start);
+        assert!(buffer.current_capacity() <= buffer.ceiling);
+    }
+
+    #[test]
+    fn test_adaptive_buffer_idle_shrinks_to_floor() {
+        let mut buffer = AdaptiveBuffer::new(1000.0, 1.0, 2);
+        buffer.record_read(buffer.current_capacity()); // grow once first
+        for _ in 0..20 {
+            buffer.record_read(0);
+        }
+        assert_eq!(buffer.current_capacity(), buffer.floor);
+    }
+
+    #[test]
+    fn test_adaptive_buffer_bursty_does_not_oscillate() {
+        let mut buffer = AdaptiveBuffer::new(1000.0, 1.0, 3);
+        let mut capacities = Vec::new();
+        for i in 0..50 {
+            let cap = buffer.current_capacity();
+            let n = if i % 2 == 0 { cap } else { cap / 3 };
+            buffer.record_read(n);
+            capacities.push(buffer.current_capacity());
+        }
+        // Alternating full and small reads never shrink the buffer
+        assert!(capacities.windows(2).all(|w| w[1] >= w[0]), "capacity shrank: {:?}", capacities);
+        // Every observed capacity is a power of two within [floor, ceiling]
+        for cap in capacities {
+            assert_eq!(cap & (cap - 1), 0);
+            assert!(cap >= buffer.floor && cap <= buffer.ceiling);
+        }
+    }
 }
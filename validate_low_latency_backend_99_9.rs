@@ -2,9 +2,9 @@
 // Complete sub-20ms deterministic latency with enterprise-grade safety
 
 use std::collections::VecDeque;
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicU64, AtomicU8, AtomicUsize, Ordering};
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::mem;
 
 // SAFETY: CPU Feature Detection for Production
@@ -17,35 +17,68 @@ struct CpuFeatures {
     cache_line_size: usize,
 }
 
-impl CpuFe    // Test bounds checking
-    for i in 0..(queue.capacity - 1) { // Leave one spot free for circular buffer
-        assert!(queue.enqueue(i as u32).is_ok());
-    }
-    assert!(queue.enqueue(999).is_err()); // Should fail when fulls {
+impl CpuFeatures {
     fn detect() -> Self {
-        let mut features = Self {
-            has_rdtsc: false,
-            has_prefetch: false,
-            has_avx: false,
-            cache_line_size: 64, // Default
-        };
+        #[cfg(target_arch = "x86_64")]
+        {
+            Self::detect_x86_64()
+        }
+
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            Self {
+                has_rdtsc: false,
+                has_prefetch: false,
+                has_avx: false,
+                cache_line_size: 64, // Conservative default off x86_64
+            }
+        }
+    }
 
-        // Safe CPU feature detection - simplified for stable Rust
-        // In production, would use proper CPUID detection
-        features.has_rdtsc = true; // Assume available on modern systems
-        features.has_prefetch = true; // Assume available on modern systems
-        features.has_avx = true; // Assume available on modern systems
+    // PRODUCTION: Real CPUID-based feature detection (x86_64 only)
+    #[cfg(target_arch = "x86_64")]
+    fn detect_x86_64() -> Self {
+        use std::arch::x86_64::__cpuid;
 
-        // Detect cache line size (production-grade)
-        features.cache_line_size = Self::detect_cache_line_size();
+        // SAFETY: CPUID leaf 1 is supported by every x86_64 CPU (the
+        // architecture guarantees at least leaf 1), so this is always valid.
+        let leaf1 = unsafe { __cpuid(1) };
+        let has_rdtsc = (leaf1.edx & (1 << 4)) != 0; // EDX bit 4: TSC
+        let has_avx = (leaf1.ecx & (1 << 28)) != 0; // ECX bit 28: AVX
+        // CLFLUSH line size, in 8-byte units (EBX bits 15:8).
+        let clflush_line_size = ((leaf1.ebx >> 8) & 0xff) as usize * 8;
 
-        features
+        Self {
+            has_rdtsc,
+            has_prefetch: true, // SSE PREFETCHh* has been baseline on x86_64 since its inception
+            has_avx,
+            cache_line_size: Self::detect_cache_line_size(clflush_line_size),
+        }
     }
 
-    fn detect_cache_line_size() -> usize {
-        // Use CPUID to detect actual cache line size
-        // This is a simplified version - production would use raw CPUID
-        64 // Most modern x86_64 systems use 64-byte cache lines
+    // PRODUCTION: Real cache line size via CPUID, falling back to the
+    // CLFLUSH line size reported by leaf 1 and finally to 64 bytes.
+    #[cfg(target_arch = "x86_64")]
+    fn detect_cache_line_size(clflush_line_size: usize) -> usize {
+        use std::arch::x86_64::{__cpuid, __get_cpuid_max};
+
+        // SAFETY: __get_cpuid_max only reads the CPU's reported max leaf;
+        // querying leaf 0x80000006 is skipped below if unsupported.
+        let (max_extended_leaf, _) = unsafe { __get_cpuid_max(0x8000_0000) };
+        if max_extended_leaf >= 0x8000_0006 {
+            // SAFETY: just confirmed leaf 0x80000006 is supported above.
+            let leaf6 = unsafe { __cpuid(0x8000_0006) };
+            let l2_line_size = (leaf6.ecx & 0xff) as usize; // ECX bits 7:0: L2 cache line size in bytes
+            if l2_line_size > 0 {
+                return l2_line_size;
+            }
+        }
+
+        if clflush_line_size > 0 {
+            clflush_line_size
+        } else {
+            64 // Most modern x86_64 systems use 64-byte cache lines
+        }
     }
 }
 
@@ -188,28 +221,92 @@ impl EnterpriseCacheAlignedCounter {
 struct ProductionHighPrecisionTimer {
     cpu_features: CpuFeatures,
     fallback_timer: Instant,
+    cycles_per_ns: f64,
 }
 
 impl ProductionHighPrecisionTimer {
+    // Long enough for a stable cycles/ns estimate, short enough not to
+    // noticeably delay constructing the timer.
+    const CALIBRATION_WINDOW: Duration = Duration::from_millis(10);
+
     fn new() -> Self {
+        let cpu_features = CpuFeatures::detect();
+        let cycles_per_ns = if cpu_features.has_rdtsc {
+            Self::calibrate()
+        } else {
+            0.0
+        };
+
         Self {
-            cpu_features: CpuFeatures::detect(),
+            cpu_features,
             fallback_timer: Instant::now(),
+            cycles_per_ns,
         }
     }
 
+    // PRODUCTION: Derive TSC cycles-per-nanosecond by spinning against the
+    // OS monotonic clock once at construction, not on the hot path.
+    #[cfg(target_arch = "x86_64")]
+    fn calibrate() -> f64 {
+        let start_instant = Instant::now();
+        let start_tsc = Self::read_tsc();
+
+        while start_instant.elapsed() < Self::CALIBRATION_WINDOW {
+            std::hint::spin_loop();
+        }
+
+        let end_tsc = Self::read_tsc();
+        let elapsed_ns = start_instant.elapsed().as_nanos() as f64;
+
+        if end_tsc > start_tsc && elapsed_ns > 0.0 {
+            (end_tsc - start_tsc) as f64 / elapsed_ns
+        } else {
+            0.0 // Non-monotonic TSC during calibration; treat as unavailable
+        }
+    }
+
+    #[cfg(not(target_arch = "x86_64"))]
+    fn calibrate() -> f64 {
+        0.0
+    }
+
+    // PRODUCTION: Serializing RDTSCP read (the `P` variant waits for prior
+    // instructions to retire, unlike plain RDTSC, so it doesn't get reordered
+    // around the work being timed).
+    #[cfg(target_arch = "x86_64")]
+    fn read_tsc() -> u64 {
+        let mut aux: u32 = 0;
+        // SAFETY: only reached once CpuFeatures::detect has confirmed TSC
+        // support; RDTSCP itself has no memory-safety preconditions.
+        unsafe { std::arch::x86_64::__rdtscp(&mut aux) }
+    }
+
+    #[cfg(not(target_arch = "x86_64"))]
+    fn read_tsc() -> u64 {
+        0
+    }
+
     // PRODUCTION: Safe RDTSC with fallback
     fn rdtsc_safe(&self) -> u64 {
         if self.cpu_features.has_rdtsc {
-            // In production: unsafe { x86_64::_rdtsc() }
-            // For now, use system time as fallback
-            self.fallback_timer.elapsed().as_nanos() as u64
+            Self::read_tsc()
         } else {
             // Fallback to system time
             self.fallback_timer.elapsed().as_nanos() as u64
         }
     }
 
+    // PRODUCTION: Convert a raw TSC cycle delta into wall-clock time using
+    // the calibrated cycles-per-nanosecond ratio. `None` when TSC isn't
+    // available or calibration failed.
+    fn cycles_to_duration(&self, cycles: u64) -> Option<Duration> {
+        if self.cpu_features.has_rdtsc && self.cycles_per_ns > 0.0 {
+            Some(Duration::from_nanos((cycles as f64 / self.cycles_per_ns) as u64))
+        } else {
+            None
+        }
+    }
+
     // PRODUCTION: Measure with multiple timing sources for accuracy
     fn measure_precise<F, R>(&self, f: F) -> (R, Duration, u64)
     where F: FnOnce() -> R {
@@ -221,7 +318,9 @@ impl ProductionHighPrecisionTimer {
         let end_tsc = self.rdtsc_safe();
         let duration_instant = start_instant.elapsed();
 
-        // Use TSC if available and reliable, otherwise use Instant
+        // Use TSC if available and monotonic; a non-monotonic read (CPU
+        // migration between sockets, frequency scaling resetting the
+        // counter) is flagged as unavailable rather than silently clamped.
         let cycles = if self.cpu_features.has_rdtsc && end_tsc > start_tsc {
             end_tsc - start_tsc
         } else {
@@ -234,85 +333,203 @@ impl ProductionHighPrecisionTimer {
 
 // 4. ENTERPRISE MEMORY POOL WITH MONITORING
 // =========================================
+// A `VecDeque` behind `&mut self` can't be shared across threads via `Arc`
+// without an external lock, which defeats the point of a pool meant to back
+// concurrent allocation paths. This is a Treiber stack instead: the free
+// list is a singly-linked list of pre-allocated nodes, and the head is a
+// single `AtomicUsize` packing a node pointer with a generation tag, pushed
+// and popped via a CAS loop. Because every node's memory is owned by the
+// pool for its whole lifetime (freed only when the pool itself drops), the
+// classic ABA failure - a popped node being freed and a *different* node
+// reallocated at the same address before a stalled thread's CAS lands -
+// can't happen here; the tag still guards against the weaker case of a
+// node being popped and pushed back between a thread's load and its CAS.
+struct Node<T> {
+    value: T,
+    next: *mut Node<T>,
+}
+
+/// An object on loan from the pool. Dropping it without calling
+/// `deallocate` orphans the slot permanently, the same caveat the old
+/// `Box<T>`-based pool had if a caller dropped the box instead of returning it.
+struct PooledObject<T> {
+    node: *mut Node<T>,
+}
+
+impl<T> std::ops::Deref for PooledObject<T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        // SAFETY: `node` came from `allocate`, which only hands out nodes
+        // popped off the stack, so it's live and uniquely owned by this object.
+        unsafe { &(*self.node).value }
+    }
+}
+
+impl<T> std::ops::DerefMut for PooledObject<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: see `Deref` above.
+        unsafe { &mut (*self.node).value }
+    }
+}
+
 struct EnterpriseMemoryPool<T> {
-    free_list: VecDeque<Box<T>>,
+    // Packs a `*mut Node<T>` (low bits) with a generation tag (high bits).
+    head: AtomicUsize,
     total_allocated: AtomicUsize,
     pool_size: usize,
     allocation_failures: AtomicUsize,
     peak_usage: AtomicUsize,
-    allocation_times: VecDeque<Duration>,
+    // Allocation-time tracking is sampled, not recorded on every call, so
+    // the hot path isn't paying for a mutex acquisition on every allocation.
+    allocation_samples: Mutex<VecDeque<Duration>>,
+    sample_counter: AtomicUsize,
+    _marker: std::marker::PhantomData<Box<Node<T>>>,
+}
+
+impl<T> EnterpriseMemoryPool<T> {
+    const TAG_BITS: u32 = 16;
+    const PTR_BITS: u32 = usize::BITS - Self::TAG_BITS;
+    const PTR_MASK: usize = (1 << Self::PTR_BITS) - 1;
+
+    fn pack(ptr: *mut Node<T>, tag: usize) -> usize {
+        ((tag & ((1 << Self::TAG_BITS) - 1)) << Self::PTR_BITS) | (ptr as usize & Self::PTR_MASK)
+    }
+
+    fn unpack(word: usize) -> (*mut Node<T>, usize) {
+        let tag = word >> Self::PTR_BITS;
+        let ptr = (word & Self::PTR_MASK) as *mut Node<T>;
+        (ptr, tag)
+    }
+
+    fn push_node(&self, node: *mut Node<T>) {
+        loop {
+            let old = self.head.load(Ordering::Acquire);
+            let (old_ptr, old_tag) = Self::unpack(old);
+            // SAFETY: `node` is exclusively owned by the caller at this
+            // point (either fresh from `Box::into_raw` or just popped), so
+            // writing its `next` link can't race with anything else.
+            unsafe {
+                (*node).next = old_ptr;
+            }
+            let new = Self::pack(node, old_tag.wrapping_add(1));
+            if self
+                .head
+                .compare_exchange_weak(old, new, Ordering::AcqRel, Ordering::Relaxed)
+                .is_ok()
+            {
+                return;
+            }
+        }
+    }
+
+    fn pop_node(&self) -> Option<*mut Node<T>> {
+        loop {
+            let old = self.head.load(Ordering::Acquire);
+            let (old_ptr, old_tag) = Self::unpack(old);
+            if old_ptr.is_null() {
+                return None;
+            }
+            // SAFETY: `old_ptr` is either still on the stack (so `next` is
+            // valid) or, if another thread raced us, the CAS below fails and
+            // we retry without having used the read.
+            let next = unsafe { (*old_ptr).next };
+            let new = Self::pack(next, old_tag.wrapping_add(1));
+            if self
+                .head
+                .compare_exchange_weak(old, new, Ordering::AcqRel, Ordering::Relaxed)
+                .is_ok()
+            {
+                return Some(old_ptr);
+            }
+        }
+    }
 }
 
 impl<T: Default> EnterpriseMemoryPool<T> {
     const POOL_SIZE: usize = 4096; // 2^12 - CORRECT
     const MONITORING_WINDOW: usize = 1000; // Track last 1000 allocations
+    const SAMPLE_EVERY: usize = 16; // Record 1-in-16 allocation timings
 
     fn new() -> Self {
-        let mut pool = Self {
-            free_list: VecDeque::with_capacity(Self::POOL_SIZE),
+        let pool = Self {
+            head: AtomicUsize::new(0),
             total_allocated: AtomicUsize::new(0),
             pool_size: Self::POOL_SIZE,
             allocation_failures: AtomicUsize::new(0),
             peak_usage: AtomicUsize::new(0),
-            allocation_times: VecDeque::with_capacity(Self::MONITORING_WINDOW),
+            allocation_samples: Mutex::new(VecDeque::with_capacity(Self::MONITORING_WINDOW)),
+            sample_counter: AtomicUsize::new(0),
+            _marker: std::marker::PhantomData,
         };
 
         // Pre-allocate all objects - CORRECT APPROACH
         for _ in 0..Self::POOL_SIZE {
-            pool.free_list.push_back(Box::new(T::default()));
+            let node = Box::into_raw(Box::new(Node {
+                value: T::default(),
+                next: std::ptr::null_mut(),
+            }));
+            pool.push_node(node);
         }
 
         pool
     }
 
-    fn allocate(&mut self) -> Option<Box<T>> {
+    fn allocate(&self) -> Option<PooledObject<T>> {
         let start_time = Instant::now();
 
-        if let Some(obj) = self.free_list.pop_front() {
-            let current_allocated = self.total_allocated.fetch_add(1, Ordering::Relaxed) + 1;
-
-            // Update peak usage
-            let mut current_peak = self.peak_usage.load(Ordering::Relaxed);
-            while current_allocated > current_peak {
-                match self.peak_usage.compare_exchange_weak(
-                    current_peak, current_allocated, Ordering::Relaxed, Ordering::Relaxed
-                ) {
-                    Ok(_) => break,
-                    Err(new_peak) => current_peak = new_peak,
+        match self.pop_node() {
+            Some(node) => {
+                let current_allocated = self.total_allocated.fetch_add(1, Ordering::Relaxed) + 1;
+
+                // Update peak usage
+                let mut current_peak = self.peak_usage.load(Ordering::Relaxed);
+                while current_allocated > current_peak {
+                    match self.peak_usage.compare_exchange_weak(
+                        current_peak, current_allocated, Ordering::Relaxed, Ordering::Relaxed
+                    ) {
+                        Ok(_) => break,
+                        Err(new_peak) => current_peak = new_peak,
+                    }
                 }
-            }
 
-            // Track allocation time
-            let alloc_time = start_time.elapsed();
-            if self.allocation_times.len() >= Self::MONITORING_WINDOW {
-                self.allocation_times.pop_front();
-            }
-            self.allocation_times.push_back(alloc_time);
+                // Sampled allocation-time tracking, to keep the common case lock-free.
+                if self.sample_counter.fetch_add(1, Ordering::Relaxed) % Self::SAMPLE_EVERY == 0 {
+                    let alloc_time = start_time.elapsed();
+                    let mut samples = self.allocation_samples.lock().unwrap();
+                    if samples.len() >= Self::MONITORING_WINDOW {
+                        samples.pop_front();
+                    }
+                    samples.push_back(alloc_time);
+                }
 
-            Some(obj)
-        } else {
-            self.allocation_failures.fetch_add(1, Ordering::Relaxed);
-            None
+                Some(PooledObject { node })
+            }
+            None => {
+                self.allocation_failures.fetch_add(1, Ordering::Relaxed);
+                None
+            }
         }
     }
 
-    fn deallocate(&mut self, obj: Box<T>) {
+    fn deallocate(&self, obj: PooledObject<T>) {
         self.total_allocated.fetch_sub(1, Ordering::Relaxed);
-        self.free_list.push_back(obj);
+        self.push_node(obj.node);
     }
 
     // PRODUCTION: Get comprehensive pool statistics
     fn get_stats(&self) -> PoolStats {
-        let avg_alloc_time = if !self.allocation_times.is_empty() {
-            self.allocation_times.iter().sum::<Duration>() / self.allocation_times.len() as u32
+        let samples = self.allocation_samples.lock().unwrap();
+        let avg_alloc_time = if !samples.is_empty() {
+            samples.iter().sum::<Duration>() / samples.len() as u32
         } else {
             Duration::from_nanos(0)
         };
+        let total_allocated = self.total_allocated.load(Ordering::Relaxed);
 
         PoolStats {
-            total_allocated: self.total_allocated.load(Ordering::Relaxed),
+            total_allocated,
             pool_size: self.pool_size,
-            free_objects: self.free_list.len(),
+            free_objects: self.pool_size.saturating_sub(total_allocated),
             allocation_failures: self.allocation_failures.load(Ordering::Relaxed),
             peak_usage: self.peak_usage.load(Ordering::Relaxed),
             avg_allocation_time: avg_alloc_time,
@@ -320,6 +537,26 @@ impl<T: Default> EnterpriseMemoryPool<T> {
     }
 }
 
+impl<T> Drop for EnterpriseMemoryPool<T> {
+    fn drop(&mut self) {
+        while let Some(node) = self.pop_node() {
+            // SAFETY: every node still on the stack was allocated via
+            // `Box::into_raw` in `new` and never freed elsewhere.
+            unsafe {
+                drop(Box::<Node<T>>::from_raw(node));
+            }
+        }
+    }
+}
+
+// SAFETY: all access to the free-list nodes goes through the atomic,
+// CAS-guarded `head` pointer - a node is never reachable from two threads
+// at once - so sharing the pool across threads is sound whenever `T`
+// itself is. The auto-trait machinery can't see this because `Node<T>`
+// stores a raw `next` pointer, which is conservatively neither `Send` nor `Sync`.
+unsafe impl<T: Send> Send for EnterpriseMemoryPool<T> {}
+unsafe impl<T: Send> Sync for EnterpriseMemoryPool<T> {}
+
 #[derive(Debug)]
 struct PoolStats {
     total_allocated: usize,
@@ -394,17 +631,355 @@ struct CounterStats {
 
 // 6. PRODUCTION NETWORK OPTIMIZER WITH VALIDATION
 // ===============================================
+
+/// Which direction a byte count belongs to, for the rolling bandwidth rings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TrafficDirection {
+    Incoming,
+    Outgoing,
+}
+
+/// A 10-slot ring of per-interval byte counts, giving a rolling ~1s window
+/// of throughput without retaining every individual read/write timestamp.
+struct BandwidthRing {
+    slots: [u64; BandwidthRing::SLOT_COUNT],
+    slot_start: Instant,
+    current_slot: usize,
+}
+
+impl BandwidthRing {
+    const SLOT_COUNT: usize = 10;
+    const SLOT_DURATION: Duration = Duration::from_millis(100); // 10 * 100ms = 1s window
+
+    fn new(now: Instant) -> Self {
+        Self {
+            slots: [0; Self::SLOT_COUNT],
+            slot_start: now,
+            current_slot: 0,
+        }
+    }
+
+    // Rolls the ring forward to `now`, zeroing any slots the clock has
+    // skipped over so an idle period reads back as zero throughput rather
+    // than stale data from a previous window.
+    fn advance(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.slot_start);
+        let slots_elapsed = (elapsed.as_nanos() / Self::SLOT_DURATION.as_nanos()) as usize;
+        if slots_elapsed == 0 {
+            return;
+        }
+
+        let to_clear = slots_elapsed.min(Self::SLOT_COUNT);
+        for i in 1..=to_clear {
+            let idx = (self.current_slot + i) % Self::SLOT_COUNT;
+            self.slots[idx] = 0;
+        }
+        self.current_slot = (self.current_slot + slots_elapsed) % Self::SLOT_COUNT;
+        self.slot_start += Self::SLOT_DURATION * slots_elapsed as u32;
+    }
+
+    fn record(&mut self, now: Instant, len: u64) {
+        self.advance(now);
+        self.slots[self.current_slot] += len;
+    }
+
+    fn avg_bytes_per_sec(&self) -> f64 {
+        let total: u64 = self.slots.iter().sum();
+        total as f64 / (Self::SLOT_COUNT as f64 * Self::SLOT_DURATION.as_secs_f64())
+    }
+
+    fn max_bytes_per_sec(&self) -> f64 {
+        let busiest_slot = self.slots.iter().copied().max().unwrap_or(0);
+        busiest_slot as f64 / Self::SLOT_DURATION.as_secs_f64()
+    }
+}
+
+/// Snapshot of the rolling bandwidth accounting, in bytes/second.
+#[derive(Debug, Clone, Copy)]
+struct BandwidthStats {
+    incoming_avg_bps: f64,
+    incoming_max_bps: f64,
+    outgoing_avg_bps: f64,
+    outgoing_max_bps: f64,
+}
+
+// 6b. DELAY-GRADIENT CONGESTION CONTROL (GCC-STYLE)
+// ==================================================
+// A simplified version of Google Congestion Control's delay-based
+// estimator: a single-state Kalman filter tracks the trend of inter-sample
+// delay variation, an adaptive threshold classifies that trend as
+// Underuse/Normal/Overuse (narrowing toward the estimate while calm,
+// widening once the estimate reaches it), and a multiplicative
+// increase/decrease rate controller reacts to the signal. This drives the BDP safety margin from observed congestion
+// instead of a fixed 1.5x guess.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CongestionSignal {
+    Underuse,
+    Normal,
+    Overuse,
+}
+
+struct KalmanGradientEstimator {
+    estimate_ms: f64,  // m_hat: filtered delay-gradient estimate
+    estimate_var: f64, // var_v_hat: variance of that estimate
+    threshold_ms: f64, // del_var_th: adaptive decision threshold
+    last_delay_ms: Option<f64>,
+    last_update: Option<Instant>,
+}
+
+impl KalmanGradientEstimator {
+    const PROCESS_NOISE: f64 = 1e-3; // Q: expected drift of the true gradient per sample
+    const MEASUREMENT_NOISE: f64 = 10.0; // variance of a single noisy delay sample
+    const THRESHOLD_MIN_MS: f64 = 6.0;
+    const THRESHOLD_MAX_MS: f64 = 600.0;
+    const THRESHOLD_GAIN_NEAR_BOUNDARY: f64 = 0.0087; // k_d when |m_hat| >= threshold
+    const THRESHOLD_GAIN_CALM: f64 = 0.039; // k_d when |m_hat| < threshold (narrow faster while calm)
+    const DEFAULT_THRESHOLD_MS: f64 = 12.5; // GCC's default del_var_th
+
+    fn new() -> Self {
+        Self {
+            estimate_ms: 0.0,
+            estimate_var: 10.0,
+            threshold_ms: Self::DEFAULT_THRESHOLD_MS,
+            last_delay_ms: None,
+            last_update: None,
+        }
+    }
+
+    /// Feeds in one new delay sample (milliseconds, e.g. measured RTT or
+    /// one-way delay) and returns the resulting congestion classification.
+    fn observe(&mut self, now: Instant, delay_ms: f64) -> CongestionSignal {
+        let gradient = match self.last_delay_ms {
+            Some(prev) => delay_ms - prev,
+            None => 0.0,
+        };
+        self.last_delay_ms = Some(delay_ms);
+
+        // Kalman update of the gradient estimate.
+        let predicted_var = self.estimate_var + Self::PROCESS_NOISE;
+        let gain = predicted_var / (predicted_var + Self::MEASUREMENT_NOISE);
+        let residual = gradient - self.estimate_ms;
+        self.estimate_ms += gain * residual;
+        self.estimate_var = (1.0 - gain) * predicted_var;
+
+        // Adaptive threshold: tracks the estimate's magnitude, clamping the
+        // time step so a first or long-delayed sample can't blow it up.
+        let delta_t_ms = self
+            .last_update
+            .map(|prev| now.duration_since(prev).as_secs_f64() * 1000.0)
+            .unwrap_or(0.0)
+            .min(100.0);
+        self.last_update = Some(now);
+
+        let abs_estimate = self.estimate_ms.abs();
+        let k_d = if abs_estimate < self.threshold_ms {
+            Self::THRESHOLD_GAIN_CALM
+        } else {
+            Self::THRESHOLD_GAIN_NEAR_BOUNDARY
+        };
+        self.threshold_ms += k_d * (abs_estimate - self.threshold_ms) * delta_t_ms;
+        self.threshold_ms = self.threshold_ms.clamp(Self::THRESHOLD_MIN_MS, Self::THRESHOLD_MAX_MS);
+
+        if self.estimate_ms > self.threshold_ms {
+            CongestionSignal::Overuse
+        } else if self.estimate_ms < -self.threshold_ms {
+            CongestionSignal::Underuse
+        } else {
+            CongestionSignal::Normal
+        }
+    }
+}
+
+/// Reacts to the Kalman estimator's signal with a multiplicative
+/// increase/decrease target rate
+/// and a congestion-aware BDP safety factor, both exposed lock-free.
+struct AdaptiveCongestionController {
+    estimator: Mutex<KalmanGradientEstimator>,
+    signal: AtomicU8, // 0 = Underuse, 1 = Normal, 2 = Overuse
+    safety_factor_bits: AtomicU64, // f64::to_bits - no stable AtomicF64
+    target_rate_bps: AtomicU64,
+    target_buffer_bytes: AtomicUsize,
+}
+
+impl AdaptiveCongestionController {
+    const MIN_SAFETY_FACTOR: f64 = 1.1;
+    const MAX_SAFETY_FACTOR: f64 = 3.0;
+    const DEFAULT_SAFETY_FACTOR: f64 = 1.5; // matches the old fixed margin as a starting point
+    const OVERUSE_RATE_DECREASE: f64 = 0.85; // multiplicative decrease
+    const NORMAL_RATE_INCREASE: f64 = 1.05; // multiplicative increase while under the threshold
+    const DEFAULT_RATE_BPS: u64 = 1_250_000; // 10Mbps, a conservative starting target
+
+    fn new() -> Self {
+        Self {
+            estimator: Mutex::new(KalmanGradientEstimator::new()),
+            signal: AtomicU8::new(1), // Normal
+            safety_factor_bits: AtomicU64::new(Self::DEFAULT_SAFETY_FACTOR.to_bits()),
+            target_rate_bps: AtomicU64::new(Self::DEFAULT_RATE_BPS),
+            target_buffer_bytes: AtomicUsize::new(0),
+        }
+    }
+
+    // PRODUCTION: Feed in a fresh delay sample and update the congestion
+    // signal, safety factor, and target send rate from it.
+    fn observe_delay_sample(&self, delay_ms: f64) {
+        let signal = self.estimator.lock().unwrap().observe(Instant::now(), delay_ms);
+        self.signal.store(Self::signal_to_u8(signal), Ordering::Relaxed);
+
+        let mut rate = self.target_rate_bps.load(Ordering::Relaxed) as f64;
+        let mut safety_factor = f64::from_bits(self.safety_factor_bits.load(Ordering::Relaxed));
+
+        match signal {
+            CongestionSignal::Overuse => {
+                rate *= Self::OVERUSE_RATE_DECREASE;
+                safety_factor = (safety_factor + 0.25).min(Self::MAX_SAFETY_FACTOR);
+            }
+            CongestionSignal::Normal => {
+                rate *= Self::NORMAL_RATE_INCREASE;
+                safety_factor = (safety_factor - 0.02).max(Self::MIN_SAFETY_FACTOR);
+            }
+            CongestionSignal::Underuse => {
+                // Hold the rate steady; relax the safety margin back toward baseline.
+                safety_factor = (safety_factor - 0.05).max(Self::MIN_SAFETY_FACTOR);
+            }
+        }
+
+        self.target_rate_bps.store(rate.max(1.0) as u64, Ordering::Relaxed);
+        self.safety_factor_bits.store(safety_factor.to_bits(), Ordering::Relaxed);
+    }
+
+    fn signal(&self) -> CongestionSignal {
+        Self::u8_to_signal(self.signal.load(Ordering::Relaxed))
+    }
+
+    fn safety_factor(&self) -> f64 {
+        f64::from_bits(self.safety_factor_bits.load(Ordering::Relaxed))
+    }
+
+    fn target_rate_bps(&self) -> u64 {
+        self.target_rate_bps.load(Ordering::Relaxed)
+    }
+
+    fn set_target_buffer_bytes(&self, bytes: usize) {
+        self.target_buffer_bytes.store(bytes, Ordering::Relaxed);
+    }
+
+    fn target_buffer_bytes(&self) -> usize {
+        self.target_buffer_bytes.load(Ordering::Relaxed)
+    }
+
+    fn signal_to_u8(signal: CongestionSignal) -> u8 {
+        match signal {
+            CongestionSignal::Underuse => 0,
+            CongestionSignal::Normal => 1,
+            CongestionSignal::Overuse => 2,
+        }
+    }
+
+    fn u8_to_signal(value: u8) -> CongestionSignal {
+        match value {
+            0 => CongestionSignal::Underuse,
+            2 => CongestionSignal::Overuse,
+            _ => CongestionSignal::Normal,
+        }
+    }
+}
+
 struct ProductionNetworkOptimizer {
     cpu_features: CpuFeatures,
+    incoming: Mutex<BandwidthRing>,
+    outgoing: Mutex<BandwidthRing>,
+    // Mirrors of the ring-derived stats, refreshed on every `record_bytes`
+    // so readers can load them without taking the ring's lock. Stored as
+    // `f64::to_bits` since there's no stable `AtomicF64`.
+    incoming_avg_bandwidth: AtomicU64,
+    incoming_max_bandwidth: AtomicU64,
+    outgoing_avg_bandwidth: AtomicU64,
+    outgoing_max_bandwidth: AtomicU64,
+    congestion: AdaptiveCongestionController,
 }
 
 impl ProductionNetworkOptimizer {
     fn new() -> Self {
+        let now = Instant::now();
         Self {
             cpu_features: CpuFeatures::detect(),
+            incoming: Mutex::new(BandwidthRing::new(now)),
+            outgoing: Mutex::new(BandwidthRing::new(now)),
+            incoming_avg_bandwidth: AtomicU64::new(0),
+            incoming_max_bandwidth: AtomicU64::new(0),
+            outgoing_avg_bandwidth: AtomicU64::new(0),
+            outgoing_max_bandwidth: AtomicU64::new(0),
+            congestion: AdaptiveCongestionController::new(),
         }
     }
 
+    // PRODUCTION: Feed a fresh delay sample (e.g. measured RTT, in
+    // milliseconds) into the congestion controller.
+    fn record_delay_sample(&self, delay_ms: f64) {
+        self.congestion.observe_delay_sample(delay_ms);
+    }
+
+    fn congestion_signal(&self) -> CongestionSignal {
+        self.congestion.signal()
+    }
+
+    fn target_send_rate_bps(&self) -> u64 {
+        self.congestion.target_rate_bps()
+    }
+
+    fn target_buffer_bytes(&self) -> usize {
+        self.congestion.target_buffer_bytes()
+    }
+
+    // PRODUCTION: Record bytes observed in one direction and refresh the
+    // atomically-readable rolling bandwidth stats for it.
+    fn record_bytes(&self, direction: TrafficDirection, len: usize) {
+        let now = Instant::now();
+        let (ring, avg_atomic, max_atomic) = match direction {
+            TrafficDirection::Incoming => (
+                &self.incoming,
+                &self.incoming_avg_bandwidth,
+                &self.incoming_max_bandwidth,
+            ),
+            TrafficDirection::Outgoing => (
+                &self.outgoing,
+                &self.outgoing_avg_bandwidth,
+                &self.outgoing_max_bandwidth,
+            ),
+        };
+
+        let mut ring = ring.lock().unwrap();
+        ring.record(now, len as u64);
+        avg_atomic.store(ring.avg_bytes_per_sec().to_bits(), Ordering::Relaxed);
+        max_atomic.store(ring.max_bytes_per_sec().to_bits(), Ordering::Relaxed);
+    }
+
+    // PRODUCTION: Lock-free snapshot of the rolling bandwidth stats.
+    fn bandwidth_stats(&self) -> BandwidthStats {
+        BandwidthStats {
+            incoming_avg_bps: f64::from_bits(self.incoming_avg_bandwidth.load(Ordering::Relaxed)),
+            incoming_max_bps: f64::from_bits(self.incoming_max_bandwidth.load(Ordering::Relaxed)),
+            outgoing_avg_bps: f64::from_bits(self.outgoing_avg_bandwidth.load(Ordering::Relaxed)),
+            outgoing_max_bps: f64::from_bits(self.outgoing_max_bandwidth.load(Ordering::Relaxed)),
+        }
+    }
+
+    // PRODUCTION: Same BDP calculation as `calculate_optimal_buffer_size_comprehensive`,
+    // but driven by live measured throughput and the congestion controller's
+    // adaptive safety factor instead of a fixed 1.5x margin.
+    fn calculate_optimal_buffer_size_from_measurements(&self, rtt_ms: f64) -> BufferOptimization {
+        let stats = self.bandwidth_stats();
+        let measured_bytes_per_sec = stats.incoming_avg_bps.max(stats.outgoing_avg_bps);
+        let measured_mbps = measured_bytes_per_sec * 8.0 / 1_000_000.0;
+        let optimization = self.calculate_optimal_buffer_size_with_safety_factor(
+            measured_mbps,
+            rtt_ms,
+            self.congestion.safety_factor(),
+        );
+        self.congestion.set_target_buffer_bytes(optimization.recommended_buffer_bytes);
+        optimization
+    }
+
     // PRODUCTION: Enhanced kernel bypass calculation with validation
     fn calculate_kernel_bypass_benefit_detailed(&self) -> KernelBypassAnalysis {
         const KERNEL_PROCESSING_US: f64 = 75.0;
@@ -424,8 +999,23 @@ impl ProductionNetworkOptimizer {
         }
     }
 
-    // PRODUCTION: Bandwidth Delay Product with comprehensive validation
+    // PRODUCTION: Bandwidth Delay Product with comprehensive validation,
+    // using a fixed 1.5x safety margin. Kept for callers (and existing
+    // tests) that want a deterministic estimate from a supplied bandwidth
+    // figure rather than the congestion-aware live measurement path.
     fn calculate_optimal_buffer_size_comprehensive(&self, bandwidth_mbps: f64, rtt_ms: f64) -> BufferOptimization {
+        self.calculate_optimal_buffer_size_with_safety_factor(bandwidth_mbps, rtt_ms, 1.5)
+    }
+
+    // PRODUCTION: Bandwidth Delay Product with comprehensive validation and
+    // a caller-supplied safety factor, so the congestion-aware path can
+    // substitute an adaptive margin for the fixed 1.5x default.
+    fn calculate_optimal_buffer_size_with_safety_factor(
+        &self,
+        bandwidth_mbps: f64,
+        rtt_ms: f64,
+        safety_factor: f64,
+    ) -> BufferOptimization {
         // Input validation
         if bandwidth_mbps <= 0.0 || rtt_ms <= 0.0 {
             return BufferOptimization {
@@ -444,7 +1034,6 @@ impl ProductionNetworkOptimizer {
         let bdp_bytes = bandwidth_bps * rtt_sec;
 
         // Apply safety factor for burst traffic
-        let safety_factor = 1.5; // 50% safety margin
         let recommended_size = (bdp_bytes * safety_factor) as usize;
         let optimal_size = recommended_size.next_power_of_two();
 
@@ -488,6 +1077,238 @@ struct BufferOptimization {
     cache_aligned: bool,
 }
 
+// 6c. BACKGROUND SYSTEM MONITOR (LINUX HOST METRICS)
+// ===================================================
+// Everything above this point only sees its own in-process counters. This
+// periodically samples the host itself - interface byte/drop counters from
+// `/proc/net/dev`, UDP/TCP error counters from `/proc/net/snmp`, and memory
+// totals from `/proc/meminfo` - and correlates the observed interface rate
+// with `ProductionNetworkOptimizer`'s BDP calculation so an undersized
+// buffer shows up as a logged warning instead of silent drops.
+#[derive(Debug, Clone, Copy, Default)]
+struct HostNetworkStats {
+    rx_bytes: u64,
+    tx_bytes: u64,
+    rx_drops: u64,
+    tx_drops: u64,
+    udp_in_errors: u64,
+    tcp_retrans_segs: u64,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct HostMemoryStats {
+    total_bytes: u64,
+    free_bytes: u64,
+    available_bytes: u64,
+}
+
+/// Snapshot of the latest host samples. `network` and `memory` each sit
+/// behind their own `Mutex` and are replaced whole by `SystemMonitor`'s
+/// sampling loop (the only writer), so a reader sees each struct
+/// internally consistent; the two can come from different sampling times.
+/// Readers take those locks; only `samples_taken` is a plain atomic.
+#[derive(Default)]
+struct HostStatsSnapshot {
+    network: Mutex<HostNetworkStats>,
+    memory: Mutex<HostMemoryStats>,
+    samples_taken: AtomicUsize,
+}
+
+/// Background service that samples host-level network and memory metrics
+/// on its own staggered schedule (network ~2s, memory ~5s) driven by an
+/// "elapsed since last sample" check each loop iteration rather than a
+/// single fixed sleep, so the two cadences don't have to share a divisor.
+/// No-op on non-Linux targets: no thread is spawned and `samples_taken`
+/// stays 0.
+struct SystemMonitor {
+    stats: Arc<HostStatsSnapshot>,
+    stop: Arc<std::sync::atomic::AtomicBool>,
+    handle: Option<std::thread::JoinHandle<()>>,
+}
+
+impl SystemMonitor {
+    const NETWORK_SAMPLE_INTERVAL: Duration = Duration::from_secs(2);
+    const MEMORY_SAMPLE_INTERVAL: Duration = Duration::from_secs(5);
+    const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+    fn start(network_optimizer: Arc<ProductionNetworkOptimizer>) -> Self {
+        let stats = Arc::new(HostStatsSnapshot::default());
+        let stop = Arc::new(std::sync::atomic::AtomicBool::new(false));
+
+        #[cfg(target_os = "linux")]
+        let handle = {
+            let stats = stats.clone();
+            let stop = stop.clone();
+            Some(std::thread::spawn(move || {
+                Self::run_linux(stats, stop, network_optimizer);
+            }))
+        };
+        #[cfg(not(target_os = "linux"))]
+        let handle = {
+            let _ = network_optimizer;
+            None
+        };
+
+        Self { stats, stop, handle }
+    }
+
+    fn snapshot(&self) -> (HostNetworkStats, HostMemoryStats) {
+        (*self.stats.network.lock().unwrap(), *self.stats.memory.lock().unwrap())
+    }
+
+    fn samples_taken(&self) -> usize {
+        self.stats.samples_taken.load(Ordering::Relaxed)
+    }
+
+    fn stop(mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    fn run_linux(
+        stats: Arc<HostStatsSnapshot>,
+        stop: Arc<std::sync::atomic::AtomicBool>,
+        network_optimizer: Arc<ProductionNetworkOptimizer>,
+    ) {
+        let mut last_network_sample = Instant::now() - Self::NETWORK_SAMPLE_INTERVAL;
+        let mut last_memory_sample = Instant::now() - Self::MEMORY_SAMPLE_INTERVAL;
+        let mut previous_network: Option<HostNetworkStats> = None;
+
+        while !stop.load(Ordering::Relaxed) {
+            let now = Instant::now();
+
+            if now.duration_since(last_network_sample) >= Self::NETWORK_SAMPLE_INTERVAL {
+                last_network_sample = now;
+                if let Some(current) = Self::sample_network() {
+                    Self::correlate_with_buffer_sizing(&previous_network, &current, &network_optimizer);
+                    *stats.network.lock().unwrap() = current;
+                    previous_network = Some(current);
+                    stats.samples_taken.fetch_add(1, Ordering::Relaxed);
+                }
+            }
+
+            if now.duration_since(last_memory_sample) >= Self::MEMORY_SAMPLE_INTERVAL {
+                last_memory_sample = now;
+                if let Some(memory) = Self::sample_memory() {
+                    *stats.memory.lock().unwrap() = memory;
+                }
+            }
+
+            std::thread::sleep(Self::POLL_INTERVAL);
+        }
+    }
+
+    // PRODUCTION: Parse `/proc/net/dev` (per-interface rx/tx byte and drop
+    // counters) and `/proc/net/snmp` (UDP/TCP error counters), summed across
+    // interfaces/protocols since this is a host-wide health signal, not a
+    // per-interface one.
+    #[cfg(target_os = "linux")]
+    fn sample_network() -> Option<HostNetworkStats> {
+        let dev = std::fs::read_to_string("/proc/net/dev").ok()?;
+        let mut stats = HostNetworkStats::default();
+
+        for line in dev.lines().skip(2) {
+            let Some((iface, rest)) = line.split_once(':') else { continue };
+            if iface.trim() == "lo" {
+                continue; // Loopback doesn't reflect real network conditions.
+            }
+            let fields: Vec<&str> = rest.split_whitespace().collect();
+            if fields.len() < 10 {
+                continue;
+            }
+            stats.rx_bytes += fields[0].parse().unwrap_or(0);
+            stats.rx_drops += fields[3].parse().unwrap_or(0);
+            stats.tx_bytes += fields[8].parse().unwrap_or(0);
+            stats.tx_drops += fields[11].parse().unwrap_or(0);
+        }
+
+        if let Ok(snmp) = std::fs::read_to_string("/proc/net/snmp") {
+            stats.udp_in_errors = Self::snmp_field(&snmp, "Udp:", "InErrors").unwrap_or(0);
+            stats.tcp_retrans_segs = Self::snmp_field(&snmp, "Tcp:", "RetransSegs").unwrap_or(0);
+        }
+
+        Some(stats)
+    }
+
+    // `/proc/net/snmp` pairs a header line (field names) with a value line
+    // (same prefix, same column order) - find the named column by index.
+    #[cfg(target_os = "linux")]
+    fn snmp_field(snmp: &str, prefix: &str, field: &str) -> Option<u64> {
+        let mut lines = snmp.lines();
+        while let Some(header) = lines.next() {
+            if !header.starts_with(prefix) {
+                continue;
+            }
+            let values = lines.next()?;
+            let names: Vec<&str> = header.split_whitespace().skip(1).collect();
+            let values: Vec<&str> = values.split_whitespace().skip(1).collect();
+            let idx = names.iter().position(|n| *n == field)?;
+            return values.get(idx)?.parse().ok();
+        }
+        None
+    }
+
+    #[cfg(target_os = "linux")]
+    fn sample_memory() -> Option<HostMemoryStats> {
+        let meminfo = std::fs::read_to_string("/proc/meminfo").ok()?;
+        let mut stats = HostMemoryStats::default();
+        for line in meminfo.lines() {
+            let Some((key, value)) = line.split_once(':') else { continue };
+            let kb: u64 = value.trim().trim_end_matches(" kB").trim().parse().unwrap_or(0);
+            match key {
+                "MemTotal" => stats.total_bytes = kb * 1024,
+                "MemFree" => stats.free_bytes = kb * 1024,
+                "MemAvailable" => stats.available_bytes = kb * 1024,
+                _ => {}
+            }
+        }
+        Some(stats)
+    }
+
+    // PRODUCTION: New packet drops on the interface, seen alongside the
+    // network optimizer's own BDP-derived buffer recommendation, are a sign
+    // the buffer is undersized for the traffic actually observed rather
+    // than the link simply being saturated upstream.
+    #[cfg(target_os = "linux")]
+    fn correlate_with_buffer_sizing(
+        previous: &Option<HostNetworkStats>,
+        current: &HostNetworkStats,
+        network_optimizer: &ProductionNetworkOptimizer,
+    ) {
+        let Some(previous) = previous else { return };
+        let new_drops = current.rx_drops.saturating_sub(previous.rx_drops)
+            + current.tx_drops.saturating_sub(previous.tx_drops);
+        if new_drops == 0 {
+            return;
+        }
+
+        let interval_secs = Self::NETWORK_SAMPLE_INTERVAL.as_secs_f64();
+        let rx_bytes_per_sec = (current.rx_bytes.saturating_sub(previous.rx_bytes)) as f64 / interval_secs;
+        let optimization = network_optimizer.calculate_optimal_buffer_size_from_measurements(1.0);
+
+        if optimization.is_valid {
+            eprintln!(
+                "‚ö†Ô∏è  host reports {} new packet drop(s) over the last {:?} \
+                 (measured throughput {:.0} B/s, recommended buffer {} bytes) - \
+                 consider growing the network buffer",
+                new_drops, Self::NETWORK_SAMPLE_INTERVAL, rx_bytes_per_sec, optimization.recommended_buffer_bytes,
+            );
+        }
+    }
+}
+
+impl Drop for SystemMonitor {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
 // 7. PRODUCTION OPTIMIZED REQUEST STRUCTURE
 // ========================================
 #[repr(C)] // Guarantee C-style layout for predictable binary structure
@@ -565,6 +1386,120 @@ impl ProductionOptimizedRequest {
     }
 }
 
+// 7b. STATISTICAL BENCHMARK HARNESS
+// ==================================
+// Replaces the old "run 100k iterations once, divide total time" approach
+// with a proper micro-benchmark: a warmup phase (discarded, so JIT/cache/TLB
+// warm-up doesn't skew results), then timed sample rounds whose inner
+// iteration count is auto-scaled so each round comfortably exceeds the
+// timer's resolution, reporting min/median/mean/stddev and ops/sec instead
+// of a single number that one stalled iteration can dominate.
+#[derive(Debug, Clone, Copy)]
+enum BenchmarkClock {
+    WallClock,
+    Tsc,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct BenchmarkStats {
+    samples: usize,
+    iterations_per_sample: u64,
+    min_ns: f64,
+    median_ns: f64,
+    mean_ns: f64,
+    stddev_ns: f64,
+    ops_per_sec: f64,
+}
+
+struct BenchmarkHarness {
+    timer: ProductionHighPrecisionTimer,
+    clock: BenchmarkClock,
+}
+
+impl BenchmarkHarness {
+    const WARMUP_ROUNDS: usize = 3;
+    const SAMPLE_ROUNDS: usize = 20;
+    const MIN_ROUND_DURATION: Duration = Duration::from_millis(5);
+    // Trim the slowest and fastest `OUTLIER_TRIM_FRACTION` of samples before
+    // computing mean/stddev, so a single scheduler stall or a lucky
+    // best-case round doesn't dominate the summary statistics.
+    const OUTLIER_TRIM_FRACTION: f64 = 0.1;
+
+    fn new(clock: BenchmarkClock) -> Self {
+        Self {
+            timer: ProductionHighPrecisionTimer::new(),
+            clock,
+        }
+    }
+
+    fn time_round(&self, iterations: u64, mut op: impl FnMut()) -> Duration {
+        match self.clock {
+            BenchmarkClock::WallClock => {
+                let start = Instant::now();
+                for _ in 0..iterations {
+                    op();
+                }
+                start.elapsed()
+            }
+            BenchmarkClock::Tsc => {
+                let start_instant = Instant::now();
+                let start = self.timer.rdtsc_safe();
+                for _ in 0..iterations {
+                    op();
+                }
+                let end = self.timer.rdtsc_safe();
+                self.timer
+                    .cycles_to_duration(end.saturating_sub(start))
+                    .unwrap_or_else(|| start_instant.elapsed())
+            }
+        }
+    }
+
+    /// Runs `op` through a warmup phase (to find an iteration count whose
+    /// round clears `MIN_ROUND_DURATION`) and then `SAMPLE_ROUNDS` timed
+    /// rounds, returning summary statistics over the per-operation latency.
+    fn run(&self, mut op: impl FnMut()) -> BenchmarkStats {
+        // Warmup: double the per-round iteration count until a round clears
+        // the minimum duration, so timer resolution doesn't dominate the
+        // measurement; the warmup rounds themselves are discarded.
+        let mut iterations: u64 = 1;
+        for _ in 0..Self::WARMUP_ROUNDS {
+            loop {
+                let elapsed = self.time_round(iterations, &mut op);
+                if elapsed >= Self::MIN_ROUND_DURATION || iterations >= 1 << 24 {
+                    break;
+                }
+                iterations *= 2;
+            }
+        }
+
+        let mut per_op_ns: Vec<f64> = (0..Self::SAMPLE_ROUNDS)
+            .map(|_| self.time_round(iterations, &mut op).as_nanos() as f64 / iterations as f64)
+            .collect();
+        per_op_ns.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let trim = ((per_op_ns.len() as f64) * Self::OUTLIER_TRIM_FRACTION) as usize;
+        let trimmed = &per_op_ns[trim..per_op_ns.len() - trim.min(per_op_ns.len() - 1)];
+
+        let min_ns = trimmed.first().copied().unwrap_or(0.0);
+        let mean_ns = trimmed.iter().sum::<f64>() / trimmed.len() as f64;
+        let median_ns = trimmed[trimmed.len() / 2];
+        let variance = trimmed.iter().map(|v| (v - mean_ns).powi(2)).sum::<f64>() / trimmed.len() as f64;
+        let stddev_ns = variance.sqrt();
+        let ops_per_sec = if mean_ns > 0.0 { 1_000_000_000.0 / mean_ns } else { 0.0 };
+
+        BenchmarkStats {
+            samples: trimmed.len(),
+            iterations_per_sample: iterations,
+            min_ns,
+            median_ns,
+            mean_ns,
+            stddev_ns,
+            ops_per_sec,
+        }
+    }
+}
+
 // 8. COMPREHENSIVE VALIDATION AND BENCHMARKING
 // ============================================
 
@@ -621,7 +1556,7 @@ fn validate_enterprise_monitoring() {
     println!("   ‚úÖ Timestamp tracking: {} last access", last_time);
 
     // Test memory pool monitoring
-    let mut pool: EnterpriseMemoryPool<ProductionOptimizedRequest> = EnterpriseMemoryPool::new();
+    let pool: EnterpriseMemoryPool<ProductionOptimizedRequest> = EnterpriseMemoryPool::new();
 
     let mut allocations = Vec::new();
     for _ in 0..100 {
@@ -670,6 +1605,49 @@ fn validate_network_optimization() {
         println!("      ‚Ä¢ Cache aligned: {}", optimization.cache_aligned);
         println!("      ‚Ä¢ Safety factor: {:.1}x", optimization.safety_factor);
     }
+
+    // Test rolling bandwidth accounting driving the same BDP calculation
+    for _ in 0..20 {
+        optimizer.record_bytes(TrafficDirection::Incoming, 64 * 1024);
+        optimizer.record_bytes(TrafficDirection::Outgoing, 16 * 1024);
+    }
+    let bandwidth_stats = optimizer.bandwidth_stats();
+    println!("   ‚úÖ Measured bandwidth:");
+    println!("      ‚Ä¢ Incoming avg: {:.0} B/s (peak {:.0} B/s)", bandwidth_stats.incoming_avg_bps, bandwidth_stats.incoming_max_bps);
+    println!("      ‚Ä¢ Outgoing avg: {:.0} B/s (peak {:.0} B/s)", bandwidth_stats.outgoing_avg_bps, bandwidth_stats.outgoing_max_bps);
+
+    let measured_optimization = optimizer.calculate_optimal_buffer_size_from_measurements(1.0);
+    println!("      ‚Ä¢ Buffer sized from live measurements: {} bytes", measured_optimization.recommended_buffer_bytes);
+
+    // Test delay-gradient congestion control driving the safety factor
+    for delay_ms in [10.0, 11.0, 40.0, 90.0, 12.0, 11.5] {
+        optimizer.record_delay_sample(delay_ms);
+    }
+    let adaptive_optimization = optimizer.calculate_optimal_buffer_size_from_measurements(1.0);
+    println!("   ‚úÖ Congestion control:");
+    println!("      ‚Ä¢ Signal: {:?}", optimizer.congestion_signal());
+    println!("      ‚Ä¢ Target send rate: {} bps", optimizer.target_send_rate_bps());
+    println!("      ‚Ä¢ Adaptive safety factor: {:.2}x", adaptive_optimization.safety_factor);
+}
+
+fn validate_system_monitor() {
+    println!("üñ•Ô∏è  SYSTEM MONITOR VALIDATION");
+    println!("=============================");
+
+    let network_optimizer = Arc::new(ProductionNetworkOptimizer::new());
+    let monitor = SystemMonitor::start(network_optimizer);
+
+    // Give the background thread time to take at least one network sample.
+    std::thread::sleep(SystemMonitor::NETWORK_SAMPLE_INTERVAL + Duration::from_millis(500));
+
+    let (network, memory) = monitor.snapshot();
+    println!("   ‚úÖ Samples taken: {}", monitor.samples_taken());
+    println!("      ‚Ä¢ RX: {} bytes, {} drops", network.rx_bytes, network.rx_drops);
+    println!("      ‚Ä¢ TX: {} bytes, {} drops", network.tx_bytes, network.tx_drops);
+    println!("      ‚Ä¢ TCP retransmits: {}", network.tcp_retrans_segs);
+    println!("      ‚Ä¢ Memory: {} / {} bytes free", memory.free_bytes, memory.total_bytes);
+
+    monitor.stop();
 }
 
 fn validate_request_structure() {
@@ -704,63 +1682,95 @@ fn validate_request_structure() {
     println!("   ‚úÖ Invalid request rejection: PASSED");
 }
 
+fn print_benchmark_stats(label: &str, stats: &BenchmarkStats) {
+    println!("   \u{1F4CA} {}:", label);
+    println!("      \u{2022} Samples: {} rounds x {} iterations", stats.samples, stats.iterations_per_sample);
+    println!("      \u{2022} Min: {:.2}ns  Median: {:.2}ns  Mean: {:.2}ns  StdDev: {:.2}ns",
+        stats.min_ns, stats.median_ns, stats.mean_ns, stats.stddev_ns);
+    println!("      \u{2022} Throughput: {:.0} ops/second", stats.ops_per_sec);
+}
+
 fn benchmark_production_performance() {
-    println!("‚ö° PRODUCTION PERFORMANCE BENCHMARK");
+    println!("\u{26A1} PRODUCTION PERFORMANCE BENCHMARK");
     println!("===================================");
 
-    let queue: Arc<SafeBoundedQueue<ProductionOptimizedRequest>> = Arc::new(SafeBoundedQueue::new());
-    let counter: Arc<EnterpriseCacheAlignedCounter> = Arc::new(EnterpriseCacheAlignedCounter::new());
-    let timer = ProductionHighPrecisionTimer::new();
-
-    let iterations = 100_000;
-
-    let (_, duration, cycles) = timer.measure_precise(|| {
-        for i in 0..iterations {
-            let mut request = ProductionOptimizedRequest::default();
-            request.request_id = i as u64;
-            request.priority = (i % 4) as u32;
-
-            // Simulate full pipeline with safety checks
-            match queue.enqueue(request) {
-                Ok(_) => {
-                    counter.increment();
-                    // Simulate processing by dequeueing
-                    if let Some(_) = queue.dequeue() {
-                        // Processing would happen here
-                    }
-                }
-                Err(_) => {
-                    // Queue full - this demonstrates backpressure
-                }
+    let harness = BenchmarkHarness::new(BenchmarkClock::WallClock);
+
+    // Full pipeline: enqueue, increment, dequeue, yielding the thread while
+    // the congestion controller signals Overuse. It does not pace to
+    // `target_send_rate_bps`, and this benchmark feeds no delay samples, so
+    // the signal stays at its initial Normal.
+    let queue: SafeBoundedQueue<ProductionOptimizedRequest> = SafeBoundedQueue::new();
+    let counter = EnterpriseCacheAlignedCounter::new();
+    let network_optimizer = ProductionNetworkOptimizer::new();
+    let mut next_request_id: u64 = 0;
+    let mut last_throttle_check = Instant::now();
+
+    let pipeline_stats = harness.run(|| {
+        // Re-check the congestion signal periodically rather than on every single
+        // iteration, so the backpressure check itself doesn't dominate the
+        // measured per-op cost.
+        if last_throttle_check.elapsed() >= Duration::from_millis(1) {
+            last_throttle_check = Instant::now();
+            if network_optimizer.congestion_signal() == CongestionSignal::Overuse {
+                std::thread::yield_now();
             }
         }
-    });
 
-    let avg_latency_ns = duration.as_nanos() as f64 / iterations as f64;
-    let throughput = iterations as f64 / duration.as_secs_f64();
+        let mut request = ProductionOptimizedRequest::default();
+        request.request_id = next_request_id;
+        request.priority = (next_request_id % 4) as u32;
+        next_request_id += 1;
 
-    println!("   üìä Benchmark Results:");
-    println!("   ‚Ä¢ Iterations: {}", iterations);
-    println!("   ‚Ä¢ Total time: {:?}", duration);
-    println!("   ‚Ä¢ Average latency: {:.2}ns per request", avg_latency_ns);
-    println!("   ‚Ä¢ Throughput: {:.0} requests/second", throughput);
-    println!("   ‚Ä¢ CPU cycles (if available): {}", cycles);
+        match queue.enqueue(request) {
+            Ok(_) => {
+                counter.increment();
+                let _ = queue.dequeue();
+            }
+            Err(_) => {
+                // Queue full - this demonstrates backpressure
+            }
+        }
+    });
+    print_benchmark_stats("Full pipeline (enqueue + increment + dequeue)", &pipeline_stats);
 
     // Validate performance targets
     let target_latency_ns = 20_000_000.0; // 20ms target
-    let performance_ratio = avg_latency_ns / target_latency_ns;
+    let performance_ratio = pipeline_stats.mean_ns / target_latency_ns;
 
-    println!("   üéØ Performance vs Target:");
-    println!("   ‚Ä¢ Target latency: {}ns", target_latency_ns as u64);
-    println!("   ‚Ä¢ Actual latency: {:.0}ns", avg_latency_ns);
-    println!("   ‚Ä¢ Performance ratio: {:.2}% of target", performance_ratio * 100.0);
-    println!("   ‚Ä¢ Safety factor: {:.0}x", 1.0 / performance_ratio);
+    println!("   \u{1F3AF} Performance vs Target:");
+    println!("   \u{2022} Target latency: {}ns", target_latency_ns as u64);
+    println!("   \u{2022} Actual latency: {:.0}ns", pipeline_stats.mean_ns);
+    println!("   \u{2022} Performance ratio: {:.2}% of target", performance_ratio * 100.0);
 
     if performance_ratio < 1.0 {
-        println!("   ‚úÖ TARGET ACHIEVED: Sub-20ms latency confirmed!");
+        println!("   \u{2705} TARGET ACHIEVED: Sub-20ms latency confirmed!");
     } else {
-        println!("   ‚ö†Ô∏è  Target not met, but still excellent performance");
+        println!("   \u{26A0}\u{FE0F}  Target not met, but still excellent performance");
     }
+
+    // Micro-benchmarks, run through the same harness so their numbers are
+    // directly comparable to the full pipeline and to each other.
+    let queue_only: SafeBoundedQueue<u32> = SafeBoundedQueue::new();
+    let queue_stats = harness.run(|| {
+        let _ = queue_only.enqueue(1);
+        let _ = queue_only.dequeue();
+    });
+    print_benchmark_stats("SafeBoundedQueue enqueue+dequeue", &queue_stats);
+
+    let counter_only = EnterpriseCacheAlignedCounter::new();
+    let counter_stats = harness.run(|| {
+        counter_only.increment();
+    });
+    print_benchmark_stats("EnterpriseCacheAlignedCounter increment", &counter_stats);
+
+    let pool: EnterpriseMemoryPool<ProductionOptimizedRequest> = EnterpriseMemoryPool::new();
+    let pool_stats = harness.run(|| {
+        if let Some(obj) = pool.allocate() {
+            pool.deallocate(obj);
+        }
+    });
+    print_benchmark_stats("EnterpriseMemoryPool allocate+deallocate", &pool_stats);
 }
 
 fn demonstrate_comprehensive_latency_breakdown() {
@@ -823,6 +1833,9 @@ fn main() {
     validate_network_optimization();
     println!();
 
+    validate_system_monitor();
+    println!();
+
     validate_request_structure();
     println!();
 
@@ -858,6 +1871,26 @@ mod tests {
         assert!(features.cache_line_size > 0);
     }
 
+    #[test]
+    fn test_high_precision_timer_calibration() {
+        let timer = ProductionHighPrecisionTimer::new();
+
+        // Calibration only produces a usable ratio when TSC is available.
+        assert_eq!(timer.cpu_features.has_rdtsc, timer.cycles_per_ns > 0.0);
+
+        let (_, duration, cycles) = timer.measure_precise(|| {
+            std::thread::sleep(Duration::from_millis(1));
+        });
+        assert!(duration >= Duration::from_millis(1));
+
+        if timer.cpu_features.has_rdtsc {
+            assert!(cycles > 0);
+            assert!(timer.cycles_to_duration(cycles).is_some());
+        } else {
+            assert!(timer.cycles_to_duration(cycles).is_none());
+        }
+    }
+
     #[test]
     fn test_safe_queue_operations() {
         let queue: SafeBoundedQueue<u32> = SafeBoundedQueue::new();
@@ -888,7 +1921,7 @@ mod tests {
 
     #[test]
     fn test_memory_pool_monitoring() {
-        let mut pool: EnterpriseMemoryPool<ProductionOptimizedRequest> = EnterpriseMemoryPool::new();
+        let pool: EnterpriseMemoryPool<ProductionOptimizedRequest> = EnterpriseMemoryPool::new();
 
         let mut allocations = Vec::new();
         for _ in 0..50 {
@@ -907,6 +1940,45 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_memory_pool_never_double_issues_a_slot_under_contention() {
+        use std::collections::HashSet;
+        use std::sync::Mutex as StdMutex;
+
+        let pool: Arc<EnterpriseMemoryPool<ProductionOptimizedRequest>> =
+            Arc::new(EnterpriseMemoryPool::new());
+        let outstanding: Arc<StdMutex<HashSet<usize>>> = Arc::new(StdMutex::new(HashSet::new()));
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let pool = Arc::clone(&pool);
+                let outstanding = Arc::clone(&outstanding);
+                std::thread::spawn(move || {
+                    for _ in 0..2000 {
+                        if let Some(obj) = pool.allocate() {
+                            let addr = obj.node as usize;
+                            assert!(
+                                outstanding.lock().unwrap().insert(addr),
+                                "slot {addr} handed out twice concurrently"
+                            );
+                            std::thread::yield_now();
+                            assert!(outstanding.lock().unwrap().remove(&addr));
+                            pool.deallocate(obj);
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let stats = pool.get_stats();
+        assert_eq!(stats.total_allocated, 0);
+        assert!(outstanding.lock().unwrap().is_empty());
+    }
+
     #[test]
     fn test_request_validation() {
         let request = ProductionOptimizedRequest::default();
@@ -931,6 +2003,111 @@ mod tests {
         assert!(buffer_opt.recommended_buffer_bytes > 0);
     }
 
+    #[test]
+    fn test_bandwidth_ring_tracks_rolling_throughput() {
+        let optimizer = ProductionNetworkOptimizer::new();
+
+        for _ in 0..10 {
+            optimizer.record_bytes(TrafficDirection::Incoming, 100_000);
+        }
+
+        let stats = optimizer.bandwidth_stats();
+        assert!(stats.incoming_avg_bps > 0.0);
+        assert!(stats.incoming_max_bps >= stats.incoming_avg_bps);
+        // No outgoing traffic was recorded.
+        assert_eq!(stats.outgoing_avg_bps, 0.0);
+
+        let optimization = optimizer.calculate_optimal_buffer_size_from_measurements(1.0);
+        assert!(optimization.is_valid);
+        assert!(optimization.recommended_buffer_bytes > 0);
+    }
+
+    #[test]
+    fn test_bandwidth_ring_ages_out_idle_slots() {
+        let now = Instant::now();
+        let mut ring = BandwidthRing::new(now);
+        ring.record(now, 1_000_000);
+        assert!(ring.avg_bytes_per_sec() > 0.0);
+
+        // Advance well past the full window; the old burst should no
+        // longer contribute to the rolling average.
+        let later = now + Duration::from_secs(5);
+        ring.advance(later);
+        assert_eq!(ring.avg_bytes_per_sec(), 0.0);
+    }
+
+    #[test]
+    fn test_kalman_gradient_estimator_flags_sustained_delay_increase() {
+        let mut estimator = KalmanGradientEstimator::new();
+        let now = Instant::now();
+
+        // A long run of steadily increasing delay should eventually be
+        // classified as Overuse once the gradient estimate clears the
+        // adaptive threshold.
+        let mut signal = CongestionSignal::Normal;
+        for i in 0..50 {
+            signal = estimator.observe(now + Duration::from_millis(i * 10), 10.0 + i as f64 * 10.0);
+        }
+        assert_eq!(signal, CongestionSignal::Overuse);
+    }
+
+    #[test]
+    fn test_kalman_gradient_estimator_is_normal_for_stable_delay() {
+        let mut estimator = KalmanGradientEstimator::new();
+        let now = Instant::now();
+
+        let mut signal = CongestionSignal::Overuse;
+        for i in 0..50 {
+            signal = estimator.observe(now + Duration::from_millis(i * 10), 20.0);
+        }
+        assert_eq!(signal, CongestionSignal::Normal);
+    }
+
+    #[test]
+    fn test_adaptive_congestion_controller_raises_safety_factor_on_overuse() {
+        let optimizer = ProductionNetworkOptimizer::new();
+        let baseline = optimizer.congestion.safety_factor();
+
+        for i in 0..30 {
+            optimizer.record_delay_sample(10.0 + i as f64 * 20.0);
+        }
+
+        assert_eq!(optimizer.congestion_signal(), CongestionSignal::Overuse);
+        assert!(optimizer.congestion.safety_factor() > baseline);
+
+        // Needs measured throughput too, or the BDP calculation short-circuits as invalid.
+        optimizer.record_bytes(TrafficDirection::Incoming, 100_000);
+        let optimization = optimizer.calculate_optimal_buffer_size_from_measurements(1.0);
+        assert!((optimization.safety_factor - optimizer.congestion.safety_factor()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_benchmark_harness_reports_sane_wall_clock_stats() {
+        let harness = BenchmarkHarness::new(BenchmarkClock::WallClock);
+        let counter = std::sync::atomic::AtomicUsize::new(0);
+
+        let stats = harness.run(|| {
+            counter.fetch_add(1, Ordering::Relaxed);
+        });
+
+        assert!(stats.samples > 0);
+        assert!(stats.iterations_per_sample >= 1);
+        assert!(stats.min_ns <= stats.median_ns);
+        assert!(stats.median_ns <= stats.mean_ns + stats.stddev_ns);
+        assert!(stats.ops_per_sec > 0.0);
+        // Every sample round actually ran, on top of the discarded warmup rounds.
+        assert!(counter.load(Ordering::Relaxed) as u64 >= stats.iterations_per_sample * BenchmarkHarness::SAMPLE_ROUNDS as u64);
+    }
+
+    #[test]
+    fn test_benchmark_harness_auto_scales_iterations_past_min_round_duration() {
+        let harness = BenchmarkHarness::new(BenchmarkClock::WallClock);
+        // A no-op closure needs many iterations per round to clear
+        // `MIN_ROUND_DURATION`; a single iteration would not.
+        let stats = harness.run(|| {});
+        assert!(stats.iterations_per_sample > 1);
+    }
+
     #[test]
     fn test_lock_free_counter() {
         let counter = ProductionLockFreeCounter::new();
@@ -943,4 +2120,21 @@ mod tests {
         assert_eq!(stats.current_value, 1000);
         assert_eq!(stats.total_operations, 1000);
     }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_snmp_field_finds_named_column_in_header_value_pair() {
+        let snmp = "Ip: Forwarding DefaultTTL\nIp: 1 64\n\
+                    Udp: InDatagrams InErrors OutDatagrams\nUdp: 100 7 90\n";
+        assert_eq!(SystemMonitor::snmp_field(snmp, "Udp:", "InErrors"), Some(7));
+        assert_eq!(SystemMonitor::snmp_field(snmp, "Ip:", "DefaultTTL"), Some(64));
+        assert_eq!(SystemMonitor::snmp_field(snmp, "Tcp:", "RetransSegs"), None);
+    }
+
+    #[test]
+    fn test_system_monitor_stop_joins_background_thread_cleanly() {
+        let network_optimizer = Arc::new(ProductionNetworkOptimizer::new());
+        let monitor = SystemMonitor::start(network_optimizer);
+        monitor.stop();
+    }
 }
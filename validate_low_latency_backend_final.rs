@@ -1,10 +1,17 @@
 // Low Latency Backend Mathematics - 99.9% Production-Ready Implementation
 // Complete sub-20ms deterministic latency with enterprise-grade safety
 
-use std::collections::VecDeque;
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::alloc::{alloc, Layout};
+use std::cell::UnsafeCell;
+use std::collections::{HashMap, VecDeque};
+use std::net::{SocketAddr, UdpSocket};
+use std::mem::MaybeUninit;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::sync::mpsc;
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use std::sync::Arc;
+use std::thread::{self, JoinHandle};
 use std::mem;
 
 // SAFETY: CPU Feature Detection for Production
@@ -17,60 +24,274 @@ struct CpuFeatures {
 }
 
 impl CpuFeatures {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
     fn detect() -> Self {
-        let mut features = Self {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::__cpuid;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::__cpuid;
+
+        let leaf1 = __cpuid(1);
+        let has_rdtsc = leaf1.edx & (1 << 4) != 0;
+        let has_avx = leaf1.ecx & (1 << 28) != 0;
+        // No dedicated PREFETCH feature bit exists; SSE (EDX bit 25) implies
+        // the prefetch instructions this code comments reference.
+        let has_prefetch = leaf1.edx & (1 << 25) != 0;
+
+        Self {
+            has_rdtsc,
+            has_prefetch,
+            has_avx,
+            cache_line_size: Self::detect_cache_line_size(),
+        }
+    }
+
+    #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+    fn detect() -> Self {
+        Self {
             has_rdtsc: false,
             has_prefetch: false,
             has_avx: false,
             cache_line_size: 64,
-        };
-
-        features.has_rdtsc = true;
-        features.has_prefetch = true;
-        features.has_avx = true;
-        features.cache_line_size = Self::detect_cache_line_size();
+        }
+    }
 
-        features
+    // PRODUCTION: Deterministic cache parameter leaf (EAX=4, sub-leaf 0 is
+    // the L1 data cache): "System Coherency Line Size" is EBX[11:0] + 1.
+    // Falls back to 64 bytes if the leaf reports no cache (EAX == 0),
+    // which some virtualized CPUs do.
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    fn detect_cache_line_size() -> usize {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::__cpuid_count;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::__cpuid_count;
+
+        let cache_params = __cpuid_count(4, 0);
+        if cache_params.eax == 0 {
+            return 64; // No cache reported at this sub-leaf.
+        }
+        ((cache_params.ebx & 0xFFF) + 1) as usize
     }
 
+    #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
     fn detect_cache_line_size() -> usize {
         64
     }
 }
 
+/// Startup hardware capability probe, built on top of [`CpuFeatures`]: a
+/// normalized CPU score and a measured memory-bandwidth estimate, in
+/// addition to the cache-line size `CpuFeatures` already detects. Lets
+/// `demonstrate_comprehensive_latency_breakdown` calibrate its latency model
+/// against the machine this binary actually runs on instead of one fixed
+/// set of constants, so the reported safety factor is honest on both a
+/// laptop and a production server.
+struct HardwareProfile {
+    cpu_features: CpuFeatures,
+    cpu_score: f64,
+    memory_bandwidth_gib_per_sec: f64,
+}
+
+impl HardwareProfile {
+    // Iterations/second achieved by `measure_cpu_score`'s loop on the
+    // baseline development machine the original fixed latency constants in
+    // `demonstrate_comprehensive_latency_breakdown` were tuned against. A
+    // score of `1.0` means "about as fast as that machine".
+    const REFERENCE_CPU_SCORE_ITERS_PER_SEC: f64 = 200_000_000.0;
+    // Sequential memory throughput on that same baseline machine.
+    const REFERENCE_MEMORY_BANDWIDTH_GIB_PER_SEC: f64 = 10.0;
+
+    fn probe() -> Self {
+        Self {
+            cpu_features: CpuFeatures::detect(),
+            cpu_score: Self::measure_cpu_score(),
+            memory_bandwidth_gib_per_sec: Self::measure_memory_bandwidth(),
+        }
+    }
+
+    /// Tight integer/float loop timed over a fixed iteration count,
+    /// normalized against `REFERENCE_CPU_SCORE_ITERS_PER_SEC`.
+    fn measure_cpu_score() -> f64 {
+        const ITERATIONS: u64 = 20_000_000;
+
+        let start = Instant::now();
+        let mut acc_i: u64 = 0;
+        let mut acc_f: f64 = 0.0;
+        for i in 0..ITERATIONS {
+            acc_i = acc_i.wrapping_add(i).wrapping_mul(2654435761);
+            acc_f += (i as f64).sqrt();
+        }
+        // Keep the optimizer from proving the loop has no observable effect
+        // and eliminating it.
+        std::hint::black_box((acc_i, acc_f));
+
+        let elapsed = start.elapsed().as_secs_f64().max(1e-9);
+        (ITERATIONS as f64 / elapsed) / Self::REFERENCE_CPU_SCORE_ITERS_PER_SEC
+    }
+
+    /// Sums a buffer several times the size of a typical L2 cache, over
+    /// several passes, so the measurement reflects main-memory bandwidth
+    /// rather than cache hits.
+    fn measure_memory_bandwidth() -> f64 {
+        const BUFFER_BYTES: usize = 32 * 1024 * 1024;
+        const PASSES: usize = 4;
+
+        let buffer = vec![0xA5u8; BUFFER_BYTES];
+        let start = Instant::now();
+        let mut sum: u64 = 0;
+        for _ in 0..PASSES {
+            for &byte in &buffer {
+                sum = sum.wrapping_add(byte as u64);
+            }
+        }
+        std::hint::black_box(sum);
+
+        let elapsed = start.elapsed().as_secs_f64().max(1e-9);
+        let total_gib = (BUFFER_BYTES * PASSES) as f64 / (1024.0 * 1024.0 * 1024.0);
+        total_gib / elapsed
+    }
+
+    fn cache_line_size(&self) -> usize {
+        self.cpu_features.cache_line_size
+    }
+}
+
+// FAULT INJECTION (FAILPOINTS)
+//
+// The queue/pool/counter below all have defensive branches (bounds
+// rejection, allocation failure, overflow detection) that real traffic
+// rarely exercises. This registry lets tests force those branches
+// deterministically by name instead of trying to manufacture real resource
+// exhaustion. Compiles to nothing unless the `failpoints` feature is on.
+#[cfg(feature = "failpoints")]
+mod failpoints {
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    #[derive(Debug, Clone, Copy)]
+    struct FailpointConfig {
+        probability: f64,
+        hit_count: u64,
+    }
+
+    static REGISTRY: Mutex<Option<HashMap<String, FailpointConfig>>> = Mutex::new(None);
+
+    fn with_registry<R>(f: impl FnOnce(&mut HashMap<String, FailpointConfig>) -> R) -> R {
+        let mut guard = REGISTRY.lock().unwrap();
+        let map = guard.get_or_insert_with(HashMap::new);
+        f(map)
+    }
+
+    /// Arm a named failpoint so it fires with the given probability
+    /// (`1.0` = always) the next time it is checked.
+    pub fn arm(name: &str, probability: f64) {
+        with_registry(|map| {
+            map.insert(
+                name.to_string(),
+                FailpointConfig {
+                    probability,
+                    hit_count: 0,
+                },
+            );
+        });
+    }
+
+    /// Disarm a named failpoint; `should_fire` returns `false` for it again.
+    pub fn disarm(name: &str) {
+        with_registry(|map| {
+            map.remove(name);
+        });
+    }
+
+    // SplitMix64 step: cheap, deterministic, good enough for test-only
+    // fault injection (not a cryptographic use), and avoids pulling in a
+    // `rand` dependency just for this.
+    fn pseudo_random(seed: u64) -> f64 {
+        let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+        (z >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    pub fn should_fire(name: &str) -> bool {
+        with_registry(|map| {
+            let Some(config) = map.get_mut(name) else {
+                return false;
+            };
+            config.hit_count += 1;
+            if config.probability >= 1.0 {
+                return true;
+            }
+            pseudo_random(config.hit_count) < config.probability
+        })
+    }
+}
+
+#[cfg(feature = "failpoints")]
+macro_rules! fail_point {
+    ($name:expr) => {
+        $crate::failpoints::should_fire($name)
+    };
+}
+
+#[cfg(not(feature = "failpoints"))]
+macro_rules! fail_point {
+    ($name:expr) => {
+        false
+    };
+}
+
 // 1. PRODUCTION-GRADE BOUNDED QUEUE WITH SAFETY
-struct SafeBoundedQueue<T> {
-    buffer: Box<[Option<T>]>,
+//
+// Bounded MPMC ring using Vyukov's per-slot sequence-number scheme: every
+// slot carries its own `seq`, so enqueuers racing on `tail` (and dequeuers
+// racing on `head`) detect ownership of a slot via a CAS instead of relying
+// on a single-producer/single-consumer assumption.
+struct QueueSlot<T> {
+    seq: AtomicUsize,
+    cell: UnsafeCell<MaybeUninit<T>>,
+}
+
+pub struct SafeBoundedQueue<T> {
+    buffer: Box<[QueueSlot<T>]>,
     head: AtomicUsize,
     tail: AtomicUsize,
     capacity: usize,
+    mask: usize,
     cpu_features: CpuFeatures,
 }
 
+// SAFETY: access to each slot's `UnsafeCell` is gated by a CAS on `head`/
+// `tail` plus the slot's own `seq`, so only one thread ever holds a slot at
+// a time - the same contract `Sync` requires as long as `T: Send`.
+unsafe impl<T: Send> Sync for SafeBoundedQueue<T> {}
+
 impl<T> SafeBoundedQueue<T> {
     const OPTIMAL_SIZE: usize = 1024;
 
-    fn new() -> Self {
+    pub fn new() -> Self {
         let cpu_features = CpuFeatures::detect();
-        let mut buffer_vec = Vec::with_capacity(Self::OPTIMAL_SIZE);
-        for _ in 0..Self::OPTIMAL_SIZE {
-            buffer_vec.push(None);
-        }
+        let buffer: Box<[QueueSlot<T>]> = (0..Self::OPTIMAL_SIZE)
+            .map(|i| QueueSlot {
+                seq: AtomicUsize::new(i),
+                cell: UnsafeCell::new(MaybeUninit::uninit()),
+            })
+            .collect();
 
         Self {
-            buffer: buffer_vec.into_boxed_slice(),
+            buffer,
             head: AtomicUsize::new(0),
             tail: AtomicUsize::new(0),
             capacity: Self::OPTIMAL_SIZE,
+            mask: Self::OPTIMAL_SIZE - 1,
             cpu_features,
         }
     }
 
-    fn enqueue(&self, item: T) -> Result<(), T> {
-        let current_tail = self.tail.load(Ordering::Acquire);
-        let next_tail = (current_tail + 1) & (self.capacity - 1);
-
-        if next_tail == self.head.load(Ordering::Acquire) {
+    pub fn enqueue(&self, item: T) -> Result<(), T> {
+        if fail_point!("queue.enqueue.bounds") {
             return Err(item);
         }
 
@@ -78,80 +299,162 @@ impl<T> SafeBoundedQueue<T> {
             // Prefetch implementation would go here
         }
 
-        unsafe {
-            let buffer_ptr = self.buffer.as_ptr() as *mut Option<T>;
-            let target_ptr = buffer_ptr.add(current_tail);
-
-            if target_ptr >= buffer_ptr && target_ptr < buffer_ptr.add(self.capacity) {
-                *target_ptr = Some(item);
+        let mut tail = self.tail.load(Ordering::Relaxed);
+        loop {
+            let slot = &self.buffer[tail & self.mask];
+            let seq = slot.seq.load(Ordering::Acquire);
+            let diff = seq as isize - tail as isize;
+
+            if diff == 0 {
+                match self.tail.compare_exchange_weak(
+                    tail,
+                    tail + 1,
+                    Ordering::Relaxed,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => {
+                        unsafe {
+                            (*slot.cell.get()).write(item);
+                        }
+                        slot.seq.store(tail + 1, Ordering::Release);
+                        return Ok(());
+                    }
+                    Err(current) => tail = current,
+                }
+            } else if diff < 0 {
+                return Err(item); // Queue is full.
             } else {
-                return Err(item);
+                tail = self.tail.load(Ordering::Relaxed);
             }
         }
-
-        self.tail.store(next_tail, Ordering::Release);
-        Ok(())
     }
 
-    fn dequeue(&self) -> Option<T> {
-        let current_head = self.head.load(Ordering::Acquire);
-
-        if current_head == self.tail.load(Ordering::Acquire) {
-            return None;
-        }
-
+    pub fn dequeue(&self) -> Option<T> {
         if self.cpu_features.has_prefetch {
             // Prefetch implementation would go here
         }
 
-        unsafe {
-            let buffer_ptr = self.buffer.as_ptr() as *mut Option<T>;
-            let source_ptr = buffer_ptr.add(current_head);
-
-            if source_ptr >= buffer_ptr && source_ptr < buffer_ptr.add(self.capacity) {
-                let item = (*source_ptr).take();
-                let next_head = (current_head + 1) & (self.capacity - 1);
-                self.head.store(next_head, Ordering::Release);
-                item
+        let mut head = self.head.load(Ordering::Relaxed);
+        loop {
+            let slot = &self.buffer[head & self.mask];
+            let seq = slot.seq.load(Ordering::Acquire);
+            let diff = seq as isize - (head + 1) as isize;
+
+            if diff == 0 {
+                match self.head.compare_exchange_weak(
+                    head,
+                    head + 1,
+                    Ordering::Relaxed,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => {
+                        let item = unsafe { (*slot.cell.get()).assume_init_read() };
+                        slot.seq.store(head + self.capacity, Ordering::Release);
+                        return Some(item);
+                    }
+                    Err(current) => head = current,
+                }
+            } else if diff < 0 {
+                return None; // Queue is empty.
             } else {
-                None
+                head = self.head.load(Ordering::Relaxed);
+            }
+        }
+    }
+}
+
+impl<T> Drop for SafeBoundedQueue<T> {
+    fn drop(&mut self) {
+        // Single-threaded by the time `Drop` runs, so every slot between
+        // `head` and `tail` still holds a live, un-dequeued item that needs
+        // its destructor run explicitly (`MaybeUninit` never drops on its
+        // own).
+        let head = *self.head.get_mut();
+        let tail = *self.tail.get_mut();
+        let mut idx = head;
+        while idx != tail {
+            let slot = &mut self.buffer[idx & self.mask];
+            unsafe {
+                slot.cell.get_mut().assume_init_drop();
             }
+            idx = idx.wrapping_add(1);
         }
     }
 }
 
 // 2. ENTERPRISE-GRADE CACHE ALIGNED STRUCTURES
 #[repr(align(64))]
-struct EnterpriseCacheAlignedCounter {
-    value: AtomicUsize,
+pub struct EnterpriseCacheAlignedCounter {
+    // `value` (high 32 bits) and `operations_count` (low 32 bits) packed
+    // into one word and updated via a single compare-exchange, so a reader
+    // can never observe them disagree the way two independent `AtomicUsize`
+    // updates could momentarily allow.
+    packed: AtomicU64,
     _padding: [u8; 64 - 8],
-    operations_count: AtomicUsize,
     last_access_time: AtomicUsize,
+    overflow_count: AtomicUsize,
 }
 
 impl EnterpriseCacheAlignedCounter {
-    fn new() -> Self {
+    const FIELD_BITS: u32 = 32;
+    const FIELD_MASK: u64 = 0xFFFF_FFFF;
+
+    pub fn new() -> Self {
         Self {
-            value: AtomicUsize::new(0),
+            packed: AtomicU64::new(0),
             _padding: [0; 56],
-            operations_count: AtomicUsize::new(0),
             last_access_time: AtomicUsize::new(0),
+            overflow_count: AtomicUsize::new(0),
         }
     }
 
-    fn increment(&self) -> usize {
-        self.operations_count.fetch_add(1, Ordering::Relaxed);
+    fn pack(value: u32, operations_count: u32) -> u64 {
+        ((value as u64) << Self::FIELD_BITS) | operations_count as u64
+    }
+
+    fn unpack(word: u64) -> (u32, u32) {
+        ((word >> Self::FIELD_BITS) as u32, (word & Self::FIELD_MASK) as u32)
+    }
+
+    pub fn increment(&self) -> usize {
         self.last_access_time.store(
             SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as usize,
             Ordering::Relaxed
         );
-        self.value.fetch_add(1, Ordering::Relaxed)
+
+        if fail_point!("counter.overflow") {
+            self.overflow_count.fetch_add(1, Ordering::Relaxed);
+            let (value, _) = Self::unpack(self.packed.load(Ordering::Relaxed));
+            return value as usize;
+        }
+
+        let mut current = self.packed.load(Ordering::Relaxed);
+        loop {
+            let (value, operations_count) = Self::unpack(current);
+            let overflowed = value == u32::MAX || operations_count == u32::MAX;
+            let next = Self::pack(value.wrapping_add(1), operations_count.wrapping_add(1));
+
+            match self.packed.compare_exchange_weak(current, next, Ordering::Relaxed, Ordering::Relaxed) {
+                Ok(_) => {
+                    if overflowed {
+                        self.overflow_count.fetch_add(1, Ordering::Relaxed);
+                    }
+                    return value as usize;
+                }
+                Err(observed) => current = observed,
+            }
+        }
+    }
+
+    pub fn overflow_count(&self) -> usize {
+        self.overflow_count.load(Ordering::Relaxed)
     }
 
     fn get_stats(&self) -> (usize, usize, usize) {
+        let (value, operations_count) = Self::unpack(self.packed.load(Ordering::Relaxed));
         (
-            self.value.load(Ordering::Relaxed),
-            self.operations_count.load(Ordering::Relaxed),
+            value as usize,
+            operations_count as usize,
             self.last_access_time.load(Ordering::Relaxed)
         )
     }
@@ -161,20 +464,95 @@ impl EnterpriseCacheAlignedCounter {
 struct ProductionHighPrecisionTimer {
     cpu_features: CpuFeatures,
     fallback_timer: Instant,
+    // Only set once CPUID confirms the invariant-TSC flag; a TSC that can
+    // stop/reset under frequency scaling or C-states isn't safe to use as a
+    // latency source, so we fall back to `Instant` instead.
+    has_invariant_tsc: bool,
+    cycles_per_ns: f64,
 }
 
 impl ProductionHighPrecisionTimer {
+    // Long enough for a stable cycles/ns estimate, short enough not to
+    // noticeably delay constructing the timer.
+    const CALIBRATION_WINDOW: Duration = Duration::from_millis(10);
+    // Beyond this, the TSC-derived duration and `Instant`'s disagree enough
+    // that something (frequency scaling, a non-invariant TSC slipping past
+    // detection, CPU migration) is undermining the calibration.
+    const DRIFT_TOLERANCE: f64 = 0.05;
+
     fn new() -> Self {
+        let cpu_features = CpuFeatures::detect();
+        let has_invariant_tsc = cpu_features.has_rdtsc && Self::detect_invariant_tsc();
+        let cycles_per_ns = if has_invariant_tsc { Self::calibrate() } else { 0.0 };
+
         Self {
-            cpu_features: CpuFeatures::detect(),
+            cpu_features,
             fallback_timer: Instant::now(),
+            has_invariant_tsc,
+            cycles_per_ns,
+        }
+    }
+
+    // PRODUCTION: CPUID extended leaf 0x8000_0007, EDX bit 8 - "invariant
+    // TSC", meaning the counter ticks at a fixed rate regardless of P-state/
+    // C-state transitions. Without this, raw TSC deltas aren't a reliable
+    // time source.
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    fn detect_invariant_tsc() -> bool {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::__cpuid;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::__cpuid;
+
+        let leaf = __cpuid(0x8000_0007);
+        leaf.edx & (1 << 8) != 0
+    }
+
+    #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+    fn detect_invariant_tsc() -> bool {
+        false
+    }
+
+    // PRODUCTION: Derive TSC cycles-per-nanosecond by spinning against the
+    // OS monotonic clock once at construction, not on the hot path.
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    fn calibrate() -> f64 {
+        let start_instant = Instant::now();
+        let start_tsc = Self::read_tsc();
+
+        while start_instant.elapsed() < Self::CALIBRATION_WINDOW {
+            std::hint::spin_loop();
+        }
+
+        let end_tsc = Self::read_tsc();
+        let elapsed_ns = start_instant.elapsed().as_nanos() as f64;
+
+        if end_tsc > start_tsc && elapsed_ns > 0.0 {
+            (end_tsc - start_tsc) as f64 / elapsed_ns
+        } else {
+            0.0 // Non-monotonic TSC during calibration; treat as unavailable.
         }
     }
 
+    #[cfg(target_arch = "x86_64")]
+    fn read_tsc() -> u64 {
+        unsafe { std::arch::x86_64::_rdtsc() }
+    }
+
+    #[cfg(target_arch = "x86")]
+    fn read_tsc() -> u64 {
+        unsafe { std::arch::x86::_rdtsc() }
+    }
+
     fn rdtsc_safe(&self) -> u64 {
-        self.fallback_timer.elapsed().as_nanos() as u64
+        if self.has_invariant_tsc {
+            Self::read_tsc()
+        } else {
+            self.fallback_timer.elapsed().as_nanos() as u64
+        }
     }
 
+    // PRODUCTION: Measure with multiple timing sources for accuracy
     fn measure_precise<F, R>(&self, f: F) -> (R, Duration, u64)
     where F: FnOnce() -> R {
         let start_instant = Instant::now();
@@ -185,50 +563,162 @@ impl ProductionHighPrecisionTimer {
         let end_tsc = self.rdtsc_safe();
         let duration_instant = start_instant.elapsed();
 
-        let cycles = if end_tsc > start_tsc {
+        let cycles = if self.has_invariant_tsc && end_tsc > start_tsc {
             end_tsc - start_tsc
         } else {
             0
         };
 
+        // Cross-check the TSC-derived duration against `Instant`'s; wide
+        // disagreement means something is undermining the calibration
+        // (frequency scaling the detection missed, CPU migration mid-measure).
+        if cycles > 0 && self.cycles_per_ns > 0.0 {
+            let tsc_duration_ns = cycles as f64 / self.cycles_per_ns;
+            let instant_ns = duration_instant.as_nanos() as f64;
+            if instant_ns > 0.0 {
+                let drift = (tsc_duration_ns - instant_ns).abs() / instant_ns;
+                if drift > Self::DRIFT_TOLERANCE {
+                    eprintln!(
+                        "warning: TSC/Instant drift of {:.1}% exceeds tolerance ({:.0}ns TSC vs {:.0}ns Instant)",
+                        drift * 100.0, tsc_duration_ns, instant_ns,
+                    );
+                }
+            }
+        }
+
         (result, duration_instant, cycles)
     }
 }
 
+// Raw fallible allocation for one `T::default()`, bypassing `Box::new`'s
+// infallible path (which aborts the process via the global OOM handler).
+// The returned pointer is null-checked before anything is written through
+// it, and handed to `Box::from_raw` so normal `Box` drop semantics (freeing
+// via the same global allocator and layout) still apply.
+#[derive(Debug)]
+pub struct AllocError;
+
+impl std::fmt::Display for AllocError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "allocation failed: out of memory")
+    }
+}
+
+impl std::error::Error for AllocError {}
+
+fn try_box_default<T: Default>() -> Result<Box<T>, AllocError> {
+    let layout = Layout::new::<T>();
+    debug_assert_ne!(layout.size(), 0, "try_box_default called with a zero-sized type");
+
+    // SAFETY: `layout` is non-zero-sized, and the pointer is checked for
+    // null before any read/write through it.
+    let ptr = unsafe { alloc(layout) } as *mut T;
+    if ptr.is_null() {
+        return Err(AllocError);
+    }
+
+    // SAFETY: `ptr` is non-null and was just allocated with `layout`, so
+    // writing a fresh `T` into it and handing ownership to `Box` is sound.
+    unsafe {
+        ptr.write(T::default());
+        Ok(Box::from_raw(ptr))
+    }
+}
+
 // 4. ENTERPRISE MEMORY POOL WITH MONITORING
-struct EnterpriseMemoryPool<T> {
+pub struct EnterpriseMemoryPool<T> {
     free_list: VecDeque<Box<T>>,
     total_allocated: AtomicUsize,
     pool_size: usize,
+    max_capacity: usize,
     allocation_failures: AtomicUsize,
     peak_usage: AtomicUsize,
     allocation_times: VecDeque<Duration>,
+    growth_events: AtomicUsize,
 }
 
 impl<T: Default> EnterpriseMemoryPool<T> {
-    const POOL_SIZE: usize = 4096;
+    pub const POOL_SIZE: usize = 4096;
+    // Bounded growth policy: doubling is allowed up to 16x the initial
+    // size, so a sustained load spike can't grow the pool unboundedly.
+    const MAX_POOL_SIZE: usize = Self::POOL_SIZE * 16;
     const MONITORING_WINDOW: usize = 1000;
 
-    fn new() -> Self {
+    pub fn new() -> Self {
+        Self::try_new().expect(
+            "EnterpriseMemoryPool::new: initial allocation failed; use try_new to handle this without aborting",
+        )
+    }
+
+    pub fn try_new() -> Result<Self, AllocError> {
+        let mut free_list = VecDeque::new();
+        free_list.try_reserve(Self::POOL_SIZE).map_err(|_| AllocError)?;
+
         let mut pool = Self {
-            free_list: VecDeque::with_capacity(Self::POOL_SIZE),
+            free_list,
             total_allocated: AtomicUsize::new(0),
             pool_size: Self::POOL_SIZE,
+            max_capacity: Self::MAX_POOL_SIZE,
             allocation_failures: AtomicUsize::new(0),
             peak_usage: AtomicUsize::new(0),
             allocation_times: VecDeque::with_capacity(Self::MONITORING_WINDOW),
+            growth_events: AtomicUsize::new(0),
         };
 
         for _ in 0..Self::POOL_SIZE {
-            pool.free_list.push_back(Box::new(T::default()));
+            pool.free_list.push_back(try_box_default()?);
+        }
+
+        Ok(pool)
+    }
+
+    // Doubles capacity, bounded by `max_capacity`. Returns the number of
+    // objects actually added (which may fall short of the doubling target
+    // if the allocator runs out partway through), or `AllocError` if
+    // nothing could be added at all.
+    fn try_grow(&mut self) -> Result<usize, AllocError> {
+        if self.pool_size >= self.max_capacity {
+            return Err(AllocError);
+        }
+
+        let target_size = (self.pool_size * 2).min(self.max_capacity);
+        let additional = target_size - self.pool_size;
+        self.free_list.try_reserve(additional).map_err(|_| AllocError)?;
+
+        let mut grown = 0;
+        for _ in 0..additional {
+            match try_box_default::<T>() {
+                Ok(obj) => {
+                    self.free_list.push_back(obj);
+                    grown += 1;
+                }
+                Err(_) => break,
+            }
+        }
+
+        if grown == 0 {
+            return Err(AllocError);
         }
 
-        pool
+        self.pool_size += grown;
+        self.growth_events.fetch_add(1, Ordering::Relaxed);
+        Ok(grown)
     }
 
-    fn allocate(&mut self) -> Option<Box<T>> {
+    pub fn allocate(&mut self) -> Option<Box<T>> {
         let start_time = Instant::now();
 
+        if fail_point!("pool.allocate.fail") {
+            self.allocation_failures.fetch_add(1, Ordering::Relaxed);
+            return None;
+        }
+
+        if self.free_list.is_empty() {
+            // Try to grow under pressure instead of immediately failing, so
+            // a transient load spike doesn't stall the latency path.
+            let _ = self.try_grow();
+        }
+
         if let Some(obj) = self.free_list.pop_front() {
             let current_allocated = self.total_allocated.fetch_add(1, Ordering::Relaxed) + 1;
 
@@ -255,7 +745,7 @@ impl<T: Default> EnterpriseMemoryPool<T> {
         }
     }
 
-    fn deallocate(&mut self, obj: Box<T>) {
+    pub fn deallocate(&mut self, obj: Box<T>) {
         self.total_allocated.fetch_sub(1, Ordering::Relaxed);
         self.free_list.push_back(obj);
     }
@@ -274,6 +764,7 @@ impl<T: Default> EnterpriseMemoryPool<T> {
             allocation_failures: self.allocation_failures.load(Ordering::Relaxed),
             peak_usage: self.peak_usage.load(Ordering::Relaxed),
             avg_allocation_time: avg_alloc_time,
+            growth_events: self.growth_events.load(Ordering::Relaxed),
         }
     }
 }
@@ -286,6 +777,7 @@ struct PoolStats {
     allocation_failures: usize,
     peak_usage: usize,
     avg_allocation_time: Duration,
+    growth_events: usize,
 }
 
 // 5. PRODUCTION NETWORK OPTIMIZER WITH VALIDATION
@@ -355,6 +847,56 @@ impl ProductionNetworkOptimizer {
             cache_aligned: cache_aligned_size == optimal_size,
         }
     }
+
+    // Same BDP math as `calculate_optimal_buffer_size_comprehensive`, but
+    // fed from `SystemMonitorService`'s live host counters instead of
+    // caller-supplied constants, so the recommendation tracks real
+    // conditions.
+    fn calculate_optimal_buffer_size_from_live_metrics(
+        &self,
+        monitor: &SystemMonitorService,
+    ) -> BufferOptimization {
+        let network = monitor.network_stats();
+        let bandwidth_bytes_per_sec = network.rx_bytes_per_sec + network.tx_bytes_per_sec;
+        let bandwidth_mbps = bandwidth_bytes_per_sec * 8.0 / 1_000_000.0;
+        let rtt_ms = monitor.smoothed_rtt_ms();
+
+        self.calculate_optimal_buffer_size_comprehensive(bandwidth_mbps, rtt_ms)
+    }
+
+    // Same static BDP calculation, but scaled by `network_monitor`'s observed
+    // UDP buffer-error trend: growing while the kernel is dropping
+    // datagrams, easing back toward the static recommendation once errors
+    // have stopped. Kept as a separate method (rather than changing
+    // `calculate_optimal_buffer_size_comprehensive`'s signature) so existing
+    // callers that only have static bandwidth/RTT figures are unaffected.
+    fn calculate_optimal_buffer_size_adaptive(
+        &self,
+        bandwidth_mbps: f64,
+        rtt_ms: f64,
+        network_monitor: &SystemNetworkMonitor,
+    ) -> BufferOptimization {
+        let base = self.calculate_optimal_buffer_size_comprehensive(bandwidth_mbps, rtt_ms);
+        if !base.is_valid {
+            return base;
+        }
+
+        let scale = network_monitor.buffer_scale();
+        let scaled_size = ((base.recommended_buffer_bytes as f64) * scale) as usize;
+        let optimal_size = scaled_size.next_power_of_two();
+
+        let cache_aligned_size = if optimal_size % self.cpu_features.cache_line_size == 0 {
+            optimal_size
+        } else {
+            ((optimal_size / self.cpu_features.cache_line_size) + 1) * self.cpu_features.cache_line_size
+        };
+
+        BufferOptimization {
+            recommended_buffer_bytes: cache_aligned_size,
+            cache_aligned: cache_aligned_size == optimal_size,
+            ..base
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -378,89 +920,938 @@ struct BufferOptimization {
     cache_aligned: bool,
 }
 
-// 6. PRODUCTION OPTIMIZED REQUEST STRUCTURE
-#[repr(C)]
-struct ProductionOptimizedRequest {
-    timestamp: u64,
-    request_id: u64,
-    priority: u32,
-    flags: u32,
-    sequence_number: u64,
-    correlation_id: u64,
-    timeout_ms: u32,
-    retry_count: u32,
-    metadata: [u8; 16],
+// 5b. BACKGROUND LIVE-METRICS SAMPLER (LINUX HOST COUNTERS)
+//
+// Feeds `ProductionNetworkOptimizer` from observed conditions instead of
+// caller-supplied constants: per-interface throughput from `/proc/net/dev`,
+// TCP retransmits from `/proc/net/snmp`, memory from `/proc/meminfo`, and
+// CPU utilization from `/proc/stat`. Linux-only; a no-op stub elsewhere.
+#[derive(Debug, Clone, Copy, Default)]
+struct LiveNetworkStats {
+    rx_bytes_per_sec: f64,
+    tx_bytes_per_sec: f64,
+    tcp_retransmits_per_sec: f64,
 }
 
-impl Default for ProductionOptimizedRequest {
-    fn default() -> Self {
-        static SEQUENCE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+#[derive(Debug, Clone, Copy, Default)]
+struct LiveMemoryStats {
+    total_bytes: u64,
+    available_bytes: u64,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct LiveCpuStats {
+    usage_fraction: f64,
+}
+
+struct LiveMetricsSnapshot {
+    network: Mutex<LiveNetworkStats>,
+    memory: Mutex<LiveMemoryStats>,
+    cpu: Mutex<LiveCpuStats>,
+    smoothed_rtt_ms_bits: AtomicU64,
+    samples_taken: AtomicUsize,
+}
 
+impl LiveMetricsSnapshot {
+    fn new(initial_rtt_ms: f64) -> Self {
         Self {
-            timestamp: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos() as u64,
-            request_id: SEQUENCE_COUNTER.fetch_add(1, Ordering::Relaxed) as u64,
-            priority: 0,
-            flags: 0,
-            sequence_number: SEQUENCE_COUNTER.load(Ordering::Relaxed) as u64,
-            correlation_id: 0,
-            timeout_ms: 5000,
-            retry_count: 0,
-            metadata: [0; 16],
+            network: Mutex::new(LiveNetworkStats::default()),
+            memory: Mutex::new(LiveMemoryStats::default()),
+            cpu: Mutex::new(LiveCpuStats::default()),
+            smoothed_rtt_ms_bits: AtomicU64::new(initial_rtt_ms.to_bits()),
+            samples_taken: AtomicUsize::new(0),
         }
     }
 }
 
-impl ProductionOptimizedRequest {
-    fn is_valid(&self) -> bool {
-        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos() as u64;
-        let max_age_ns = 300_000_000_000;
-
-        if self.timestamp > now + 1_000_000_000 ||
-           now.saturating_sub(self.timestamp) > max_age_ns {
-            return false;
+// Shared by every `/proc/net/snmp` reader in this file: the format is a
+// pair of lines per protocol, a header naming the columns and a value line
+// in the same order, so a field is found by name rather than by a
+// hardcoded column index.
+#[cfg(target_os = "linux")]
+fn proc_net_snmp_field(snmp: &str, proto: &str, field: &str) -> Option<u64> {
+    let mut lines = snmp.lines();
+    while let Some(header) = lines.next() {
+        let value_line = lines.next()?;
+        if !header.starts_with(proto) {
+            continue;
         }
+        let names: Vec<&str> = header.split_whitespace().skip(1).collect();
+        let values: Vec<&str> = value_line.split_whitespace().skip(1).collect();
+        let idx = names.iter().position(|n| *n == field)?;
+        return values.get(idx)?.parse().ok();
+    }
+    None
+}
 
-        if self.priority > 255 {
-            return false;
-        }
+#[cfg(target_os = "linux")]
+struct SystemMonitorService {
+    stats: Arc<LiveMetricsSnapshot>,
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
 
-        if self.timeout_ms < 1 || self.timeout_ms > 300_000 {
-            return false;
-        }
+#[cfg(target_os = "linux")]
+impl SystemMonitorService {
+    const NETWORK_SAMPLE_INTERVAL: Duration = Duration::from_secs(2);
+    const MEMORY_SAMPLE_INTERVAL: Duration = Duration::from_secs(5);
+    const POLL_INTERVAL: Duration = Duration::from_millis(200);
+    const BASE_RTT_MS: f64 = 1.0;
+    // Retransmits are the only delay-correlated signal `/proc/net/snmp`
+    // exposes, so they stand in for a missing direct RTT sample.
+    const RTT_MS_PER_RETRANSMIT: f64 = 5.0;
+    const RTT_EMA_ALPHA: f64 = 0.2;
+
+    fn start() -> Self {
+        let stats = Arc::new(LiveMetricsSnapshot::new(Self::BASE_RTT_MS));
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let thread_stats = stats.clone();
+        let thread_stop = stop.clone();
+        let handle = thread::spawn(move || Self::run(thread_stats, thread_stop));
 
-        true
+        Self {
+            stats,
+            stop,
+            handle: Some(handle),
+        }
     }
 
-    fn priority_score(&self) -> u64 {
-        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos() as u64;
-        let age_penalty = now.saturating_sub(self.timestamp);
+    fn run(stats: Arc<LiveMetricsSnapshot>, stop: Arc<AtomicBool>) {
+        let mut last_network = Instant::now() - Self::NETWORK_SAMPLE_INTERVAL;
+        let mut last_memory = Instant::now() - Self::MEMORY_SAMPLE_INTERVAL;
+        let mut prev_network: Option<(u64, u64, u64)> = None;
+        let mut prev_cpu: Option<(u64, u64)> = None;
 
-        (self.priority as u64 * 1_000_000_000) + (u64::MAX - age_penalty)
+        while !stop.load(Ordering::Relaxed) {
+            let now = Instant::now();
+
+            if now.duration_since(last_network) >= Self::NETWORK_SAMPLE_INTERVAL {
+                last_network = now;
+                prev_network = Self::sample_network(&stats, prev_network);
+            }
+
+            if now.duration_since(last_memory) >= Self::MEMORY_SAMPLE_INTERVAL {
+                last_memory = now;
+                Self::sample_memory(&stats);
+                prev_cpu = Self::sample_cpu(&stats, prev_cpu);
+            }
+
+            stats.samples_taken.fetch_add(1, Ordering::Relaxed);
+            thread::sleep(Self::POLL_INTERVAL);
+        }
     }
-}
 
-// VALIDATION FUNCTIONS
-fn validate_production_safety() {
-    println!("üõ°Ô∏è PRODUCTION SAFETY VALIDATION");
-    println!("================================");
+    fn sample_network(
+        stats: &LiveMetricsSnapshot,
+        prev: Option<(u64, u64, u64)>,
+    ) -> Option<(u64, u64, u64)> {
+        let dev = std::fs::read_to_string("/proc/net/dev").ok()?;
+        let mut rx_total = 0u64;
+        let mut tx_total = 0u64;
+        for line in dev.lines().skip(2) {
+            let Some((iface, rest)) = line.split_once(':') else {
+                continue;
+            };
+            if iface.trim() == "lo" {
+                continue;
+            }
+            let fields: Vec<&str> = rest.split_whitespace().collect();
+            if fields.len() < 9 {
+                continue;
+            }
+            rx_total += fields[0].parse::<u64>().unwrap_or(0);
+            tx_total += fields[8].parse::<u64>().unwrap_or(0);
+        }
 
-    let cpu_features = CpuFeatures::detect();
-    println!("   ‚úÖ CPU Features Detected:");
-    println!("      ‚Ä¢ RDTSC: {}", cpu_features.has_rdtsc);
-    println!("      ‚Ä¢ Prefetch: {}", cpu_features.has_prefetch);
-    println!("      ‚Ä¢ AVX: {}", cpu_features.has_avx);
-    println!("      ‚Ä¢ Cache Line Size: {} bytes", cpu_features.cache_line_size);
+        let snmp = std::fs::read_to_string("/proc/net/snmp").ok()?;
+        let retrans_total = proc_net_snmp_field(&snmp, "Tcp", "RetransSegs").unwrap_or(0);
 
-    let queue: SafeBoundedQueue<u32> = SafeBoundedQueue::new();
+        if let Some((prev_rx, prev_tx, prev_retrans)) = prev {
+            let elapsed = Self::NETWORK_SAMPLE_INTERVAL.as_secs_f64();
+            let rx_per_sec = rx_total.saturating_sub(prev_rx) as f64 / elapsed;
+            let tx_per_sec = tx_total.saturating_sub(prev_tx) as f64 / elapsed;
+            let retrans_per_sec = retrans_total.saturating_sub(prev_retrans) as f64 / elapsed;
 
-    // Test bounds checking
-    for i in 0..(queue.capacity - 1) {
-        assert!(queue.enqueue(i as u32).is_ok());
+            {
+                let mut network = stats.network.lock().unwrap();
+                network.rx_bytes_per_sec = rx_per_sec;
+                network.tx_bytes_per_sec = tx_per_sec;
+                network.tcp_retransmits_per_sec = retrans_per_sec;
+            }
+
+            let instantaneous_rtt_ms = Self::BASE_RTT_MS + retrans_per_sec * Self::RTT_MS_PER_RETRANSMIT;
+            let prev_smoothed = f64::from_bits(stats.smoothed_rtt_ms_bits.load(Ordering::Relaxed));
+            let smoothed =
+                Self::RTT_EMA_ALPHA * instantaneous_rtt_ms + (1.0 - Self::RTT_EMA_ALPHA) * prev_smoothed;
+            stats.smoothed_rtt_ms_bits.store(smoothed.to_bits(), Ordering::Relaxed);
+        }
+
+        Some((rx_total, tx_total, retrans_total))
     }
-    assert!(queue.enqueue(999).is_err());
+
+    fn sample_memory(stats: &LiveMetricsSnapshot) {
+        let Ok(meminfo) = std::fs::read_to_string("/proc/meminfo") else {
+            return;
+        };
+        let mut total_kb = 0u64;
+        let mut available_kb = 0u64;
+        for line in meminfo.lines() {
+            if let Some(rest) = line.strip_prefix("MemTotal:") {
+                total_kb = Self::parse_kb(rest);
+            } else if let Some(rest) = line.strip_prefix("MemAvailable:") {
+                available_kb = Self::parse_kb(rest);
+            }
+        }
+
+        let mut memory = stats.memory.lock().unwrap();
+        memory.total_bytes = total_kb * 1024;
+        memory.available_bytes = available_kb * 1024;
+    }
+
+    fn parse_kb(field: &str) -> u64 {
+        field.split_whitespace().next().and_then(|v| v.parse().ok()).unwrap_or(0)
+    }
+
+    fn sample_cpu(stats: &LiveMetricsSnapshot, prev: Option<(u64, u64)>) -> Option<(u64, u64)> {
+        let stat = std::fs::read_to_string("/proc/stat").ok()?;
+        let first_line = stat.lines().next()?;
+        let fields: Vec<u64> = first_line
+            .split_whitespace()
+            .skip(1)
+            .filter_map(|f| f.parse().ok())
+            .collect();
+        if fields.len() < 4 {
+            return None;
+        }
+        let idle = fields[3] + fields.get(4).copied().unwrap_or(0); // idle + iowait
+        let total: u64 = fields.iter().sum();
+
+        if let Some((prev_idle, prev_total)) = prev {
+            let idle_delta = idle.saturating_sub(prev_idle);
+            let total_delta = total.saturating_sub(prev_total);
+            if total_delta > 0 {
+                let usage = 1.0 - (idle_delta as f64 / total_delta as f64);
+                let mut cpu = stats.cpu.lock().unwrap();
+                cpu.usage_fraction = usage.clamp(0.0, 1.0);
+            }
+        }
+
+        Some((idle, total))
+    }
+
+    fn network_stats(&self) -> LiveNetworkStats {
+        *self.stats.network.lock().unwrap()
+    }
+
+    fn memory_stats(&self) -> LiveMemoryStats {
+        *self.stats.memory.lock().unwrap()
+    }
+
+    fn cpu_stats(&self) -> LiveCpuStats {
+        *self.stats.cpu.lock().unwrap()
+    }
+
+    fn smoothed_rtt_ms(&self) -> f64 {
+        f64::from_bits(self.stats.smoothed_rtt_ms_bits.load(Ordering::Relaxed))
+    }
+
+    fn samples_taken(&self) -> usize {
+        self.stats.samples_taken.load(Ordering::Relaxed)
+    }
+
+    fn stop(mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Drop for SystemMonitorService {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+struct SystemMonitorService;
+
+#[cfg(not(target_os = "linux"))]
+impl SystemMonitorService {
+    fn start() -> Self {
+        Self
+    }
+
+    fn network_stats(&self) -> LiveNetworkStats {
+        LiveNetworkStats::default()
+    }
+
+    fn memory_stats(&self) -> LiveMemoryStats {
+        LiveMemoryStats::default()
+    }
+
+    fn cpu_stats(&self) -> LiveCpuStats {
+        LiveCpuStats::default()
+    }
+
+    fn smoothed_rtt_ms(&self) -> f64 {
+        1.0
+    }
+
+    fn samples_taken(&self) -> usize {
+        0
+    }
+
+    fn stop(self) {}
+}
+
+// 5c. LIVE UDP/SOCKET-BUFFER ERROR MONITOR FEEDING ADAPTIVE BUFFER SIZING
+//
+// `SystemMonitorService` above answers "how much bandwidth and delay is
+// there". This subsystem answers a narrower question that the buffer-size
+// calculation actually needs: "is the kernel dropping datagrams because our
+// recommended buffer is too small". It tracks UDP socket buffer errors from
+// `/proc/net/snmp` and per-interface drop counters from `/proc/net/dev`, and
+// folds the trend into a scale factor that turns
+// `calculate_optimal_buffer_size_comprehensive`'s static recommendation into
+// a closed-loop adaptive one.
+fn load_f64(bits: &AtomicU64) -> f64 {
+    f64::from_bits(bits.load(Ordering::Relaxed))
+}
+
+fn store_f64(bits: &AtomicU64, value: f64) {
+    bits.store(value.to_bits(), Ordering::Relaxed);
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct NetworkCounterSnapshot {
+    rx_bytes_per_sec: f64,
+    tx_bytes_per_sec: f64,
+    rx_drops_per_sec: f64,
+    tx_drops_per_sec: f64,
+    udp_in_datagrams_per_sec: f64,
+    udp_rcvbuf_errors_per_sec: f64,
+    udp_sndbuf_errors_per_sec: f64,
+    udp_in_errors_per_sec: f64,
+}
+
+struct NetworkCounterStats {
+    rx_bytes_per_sec_bits: AtomicU64,
+    tx_bytes_per_sec_bits: AtomicU64,
+    rx_drops_per_sec_bits: AtomicU64,
+    tx_drops_per_sec_bits: AtomicU64,
+    udp_in_datagrams_per_sec_bits: AtomicU64,
+    udp_rcvbuf_errors_per_sec_bits: AtomicU64,
+    udp_sndbuf_errors_per_sec_bits: AtomicU64,
+    udp_in_errors_per_sec_bits: AtomicU64,
+    buffer_scale_bits: AtomicU64,
+    consecutive_error_free_samples: AtomicUsize,
+    samples_taken: AtomicUsize,
+}
+
+impl NetworkCounterStats {
+    fn new() -> Self {
+        Self {
+            rx_bytes_per_sec_bits: AtomicU64::new(0f64.to_bits()),
+            tx_bytes_per_sec_bits: AtomicU64::new(0f64.to_bits()),
+            rx_drops_per_sec_bits: AtomicU64::new(0f64.to_bits()),
+            tx_drops_per_sec_bits: AtomicU64::new(0f64.to_bits()),
+            udp_in_datagrams_per_sec_bits: AtomicU64::new(0f64.to_bits()),
+            udp_rcvbuf_errors_per_sec_bits: AtomicU64::new(0f64.to_bits()),
+            udp_sndbuf_errors_per_sec_bits: AtomicU64::new(0f64.to_bits()),
+            udp_in_errors_per_sec_bits: AtomicU64::new(0f64.to_bits()),
+            buffer_scale_bits: AtomicU64::new(SystemNetworkMonitor::MIN_SCALE.to_bits()),
+            consecutive_error_free_samples: AtomicUsize::new(0),
+            samples_taken: AtomicUsize::new(0),
+        }
+    }
+
+    fn snapshot(&self) -> NetworkCounterSnapshot {
+        NetworkCounterSnapshot {
+            rx_bytes_per_sec: load_f64(&self.rx_bytes_per_sec_bits),
+            tx_bytes_per_sec: load_f64(&self.tx_bytes_per_sec_bits),
+            rx_drops_per_sec: load_f64(&self.rx_drops_per_sec_bits),
+            tx_drops_per_sec: load_f64(&self.tx_drops_per_sec_bits),
+            udp_in_datagrams_per_sec: load_f64(&self.udp_in_datagrams_per_sec_bits),
+            udp_rcvbuf_errors_per_sec: load_f64(&self.udp_rcvbuf_errors_per_sec_bits),
+            udp_sndbuf_errors_per_sec: load_f64(&self.udp_sndbuf_errors_per_sec_bits),
+            udp_in_errors_per_sec: load_f64(&self.udp_in_errors_per_sec_bits),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct RawNetworkCounters {
+    rx_bytes: u64,
+    tx_bytes: u64,
+    rx_drops: u64,
+    tx_drops: u64,
+    udp_in_datagrams: u64,
+    udp_rcvbuf_errors: u64,
+    udp_sndbuf_errors: u64,
+    udp_in_errors: u64,
+}
+
+#[cfg(target_os = "linux")]
+struct SystemNetworkMonitor {
+    stats: Arc<NetworkCounterStats>,
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+#[cfg(target_os = "linux")]
+impl SystemNetworkMonitor {
+    const DEFAULT_SAMPLE_INTERVAL: Duration = Duration::from_secs(2);
+    const MIN_SCALE: f64 = 1.0;
+    const MAX_SCALE: f64 = 8.0;
+    const SCALE_UP_FACTOR: f64 = 1.5;
+    const SCALE_DOWN_FACTOR: f64 = 0.9;
+    // Only ease the scale back down after this many consecutive error-free
+    // samples, so a single quiet tick doesn't immediately undo headroom that
+    // was just earned.
+    const ZERO_ERROR_SAMPLES_BEFORE_SCALE_DOWN: usize = 3;
+
+    fn start(sample_interval: Duration) -> Self {
+        let stats = Arc::new(NetworkCounterStats::new());
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let thread_stats = stats.clone();
+        let thread_stop = stop.clone();
+        let handle = thread::spawn(move || Self::run(thread_stats, thread_stop, sample_interval));
+
+        Self {
+            stats,
+            stop,
+            handle: Some(handle),
+        }
+    }
+
+    fn run(stats: Arc<NetworkCounterStats>, stop: Arc<AtomicBool>, sample_interval: Duration) {
+        let mut prev: Option<RawNetworkCounters> = None;
+
+        while !stop.load(Ordering::Relaxed) {
+            prev = Self::sample(&stats, prev, sample_interval);
+            stats.samples_taken.fetch_add(1, Ordering::Relaxed);
+            thread::sleep(sample_interval);
+        }
+    }
+
+    fn sample(
+        stats: &NetworkCounterStats,
+        prev: Option<RawNetworkCounters>,
+        sample_interval: Duration,
+    ) -> Option<RawNetworkCounters> {
+        let dev = std::fs::read_to_string("/proc/net/dev").ok()?;
+        let mut rx_bytes = 0u64;
+        let mut tx_bytes = 0u64;
+        let mut rx_drops = 0u64;
+        let mut tx_drops = 0u64;
+        for line in dev.lines().skip(2) {
+            let Some((iface, rest)) = line.split_once(':') else {
+                continue;
+            };
+            if iface.trim() == "lo" {
+                continue;
+            }
+            let fields: Vec<&str> = rest.split_whitespace().collect();
+            if fields.len() < 16 {
+                continue;
+            }
+            rx_bytes += fields[0].parse::<u64>().unwrap_or(0);
+            rx_drops += fields[3].parse::<u64>().unwrap_or(0);
+            tx_bytes += fields[8].parse::<u64>().unwrap_or(0);
+            tx_drops += fields[11].parse::<u64>().unwrap_or(0);
+        }
+
+        let snmp = std::fs::read_to_string("/proc/net/snmp").ok()?;
+        let udp_in_datagrams = proc_net_snmp_field(&snmp, "Udp", "InDatagrams").unwrap_or(0);
+        let udp_rcvbuf_errors = proc_net_snmp_field(&snmp, "Udp", "RcvbufErrors").unwrap_or(0);
+        let udp_sndbuf_errors = proc_net_snmp_field(&snmp, "Udp", "SndbufErrors").unwrap_or(0);
+        let udp_in_errors = proc_net_snmp_field(&snmp, "Udp", "InErrors").unwrap_or(0);
+
+        let current = RawNetworkCounters {
+            rx_bytes,
+            tx_bytes,
+            rx_drops,
+            tx_drops,
+            udp_in_datagrams,
+            udp_rcvbuf_errors,
+            udp_sndbuf_errors,
+            udp_in_errors,
+        };
+
+        if let Some(prev) = prev {
+            let elapsed = sample_interval.as_secs_f64();
+            let rate = |now: u64, before: u64| now.saturating_sub(before) as f64 / elapsed;
+
+            store_f64(&stats.rx_bytes_per_sec_bits, rate(current.rx_bytes, prev.rx_bytes));
+            store_f64(&stats.tx_bytes_per_sec_bits, rate(current.tx_bytes, prev.tx_bytes));
+            store_f64(&stats.rx_drops_per_sec_bits, rate(current.rx_drops, prev.rx_drops));
+            store_f64(&stats.tx_drops_per_sec_bits, rate(current.tx_drops, prev.tx_drops));
+            store_f64(
+                &stats.udp_in_datagrams_per_sec_bits,
+                rate(current.udp_in_datagrams, prev.udp_in_datagrams),
+            );
+            let rcvbuf_errors_per_sec = rate(current.udp_rcvbuf_errors, prev.udp_rcvbuf_errors);
+            let sndbuf_errors_per_sec = rate(current.udp_sndbuf_errors, prev.udp_sndbuf_errors);
+            store_f64(&stats.udp_rcvbuf_errors_per_sec_bits, rcvbuf_errors_per_sec);
+            store_f64(&stats.udp_sndbuf_errors_per_sec_bits, sndbuf_errors_per_sec);
+            store_f64(
+                &stats.udp_in_errors_per_sec_bits,
+                rate(current.udp_in_errors, prev.udp_in_errors),
+            );
+
+            Self::update_scale(stats, rcvbuf_errors_per_sec + sndbuf_errors_per_sec);
+        }
+
+        Some(current)
+    }
+
+    fn update_scale(stats: &NetworkCounterStats, buffer_errors_per_sec: f64) {
+        let current_scale = load_f64(&stats.buffer_scale_bits);
+
+        let new_scale = if buffer_errors_per_sec > 0.0 {
+            stats.consecutive_error_free_samples.store(0, Ordering::Relaxed);
+            (current_scale * Self::SCALE_UP_FACTOR).min(Self::MAX_SCALE)
+        } else {
+            let free_samples = stats.consecutive_error_free_samples.fetch_add(1, Ordering::Relaxed) + 1;
+            if free_samples >= Self::ZERO_ERROR_SAMPLES_BEFORE_SCALE_DOWN {
+                (current_scale * Self::SCALE_DOWN_FACTOR).max(Self::MIN_SCALE)
+            } else {
+                current_scale
+            }
+        };
+
+        store_f64(&stats.buffer_scale_bits, new_scale);
+    }
+
+    fn stats(&self) -> NetworkCounterSnapshot {
+        self.stats.snapshot()
+    }
+
+    /// Current multiplier to apply to the static BDP-derived buffer
+    /// recommendation: `1.0` when no buffer errors have been observed,
+    /// growing toward `MAX_SCALE` while they're rising, easing back down
+    /// once a window of samples has been error-free.
+    fn buffer_scale(&self) -> f64 {
+        load_f64(&self.stats.buffer_scale_bits)
+    }
+
+    fn samples_taken(&self) -> usize {
+        self.stats.samples_taken.load(Ordering::Relaxed)
+    }
+
+    fn stop(mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Drop for SystemNetworkMonitor {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+struct SystemNetworkMonitor;
+
+#[cfg(not(target_os = "linux"))]
+impl SystemNetworkMonitor {
+    const MIN_SCALE: f64 = 1.0;
+
+    fn start(_sample_interval: Duration) -> Self {
+        Self
+    }
+
+    fn stats(&self) -> NetworkCounterSnapshot {
+        NetworkCounterSnapshot::default()
+    }
+
+    fn buffer_scale(&self) -> f64 {
+        Self::MIN_SCALE
+    }
+
+    fn samples_taken(&self) -> usize {
+        0
+    }
+
+    fn stop(self) {}
+}
+
+// 6. PRODUCTION OPTIMIZED REQUEST STRUCTURE
+#[repr(C)]
+struct ProductionOptimizedRequest {
+    timestamp: u64,
+    request_id: u64,
+    priority: u32,
+    flags: u32,
+    sequence_number: u64,
+    correlation_id: u64,
+    timeout_ms: u32,
+    retry_count: u32,
+    metadata: [u8; 16],
+}
+
+impl Default for ProductionOptimizedRequest {
+    fn default() -> Self {
+        static SEQUENCE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        Self {
+            timestamp: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos() as u64,
+            request_id: SEQUENCE_COUNTER.fetch_add(1, Ordering::Relaxed) as u64,
+            priority: 0,
+            flags: 0,
+            sequence_number: SEQUENCE_COUNTER.load(Ordering::Relaxed) as u64,
+            correlation_id: 0,
+            timeout_ms: 5000,
+            retry_count: 0,
+            metadata: [0; 16],
+        }
+    }
+}
+
+impl ProductionOptimizedRequest {
+    fn is_valid(&self) -> bool {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos() as u64;
+        let max_age_ns = 300_000_000_000;
+
+        if self.timestamp > now + 1_000_000_000 ||
+           now.saturating_sub(self.timestamp) > max_age_ns {
+            return false;
+        }
+
+        if self.priority > 255 {
+            return false;
+        }
+
+        if self.timeout_ms < 1 || self.timeout_ms > 300_000 {
+            return false;
+        }
+
+        true
+    }
+
+    fn priority_score(&self) -> u64 {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos() as u64;
+        let age_penalty = now.saturating_sub(self.timestamp);
+
+        (self.priority as u64 * 1_000_000_000) + (u64::MAX - age_penalty)
+    }
+
+    /// Wire representation used by `QuicRequestTransport`: every field
+    /// little-endian, in declaration order, with no padding - the same
+    /// layout `#[repr(C)]` already gives this struct, spelled out
+    /// explicitly so it is stable across compilers/targets instead of
+    /// depending on `#[repr(C)]`'s platform-dependent field alignment.
+    const WIRE_SIZE: usize = 64;
+
+    fn to_bytes(&self) -> [u8; Self::WIRE_SIZE] {
+        let mut buf = [0u8; Self::WIRE_SIZE];
+        buf[0..8].copy_from_slice(&self.timestamp.to_le_bytes());
+        buf[8..16].copy_from_slice(&self.request_id.to_le_bytes());
+        buf[16..20].copy_from_slice(&self.priority.to_le_bytes());
+        buf[20..24].copy_from_slice(&self.flags.to_le_bytes());
+        buf[24..32].copy_from_slice(&self.sequence_number.to_le_bytes());
+        buf[32..40].copy_from_slice(&self.correlation_id.to_le_bytes());
+        buf[40..44].copy_from_slice(&self.timeout_ms.to_le_bytes());
+        buf[44..48].copy_from_slice(&self.retry_count.to_le_bytes());
+        buf[48..64].copy_from_slice(&self.metadata);
+        buf
+    }
+
+    fn from_bytes(buf: &[u8; Self::WIRE_SIZE]) -> Self {
+        Self {
+            timestamp: u64::from_le_bytes(buf[0..8].try_into().unwrap()),
+            request_id: u64::from_le_bytes(buf[8..16].try_into().unwrap()),
+            priority: u32::from_le_bytes(buf[16..20].try_into().unwrap()),
+            flags: u32::from_le_bytes(buf[20..24].try_into().unwrap()),
+            sequence_number: u64::from_le_bytes(buf[24..32].try_into().unwrap()),
+            correlation_id: u64::from_le_bytes(buf[32..40].try_into().unwrap()),
+            timeout_ms: u32::from_le_bytes(buf[40..44].try_into().unwrap()),
+            retry_count: u32::from_le_bytes(buf[44..48].try_into().unwrap()),
+            metadata: buf[48..64].try_into().unwrap(),
+        }
+    }
+}
+
+// 7. PLUGGABLE CONGESTION CONTROL + QUIC-STYLE INGESTION TRANSPORT
+//
+// This file has no Cargo.toml of its own (the only one in this tree is the
+// fuzz harness's) to add a real QUIC crate (e.g. `quinn`) to, so
+// `QuicRequestTransport` below is an honest,
+// dependency-free approximation of QUIC's shape: a UDP datagram carrying
+// a `[stream_id: u64 LE][64-byte ProductionOptimizedRequest]` frame. It
+// does NOT implement the real RFC 9000 protocol - no handshake, no TLS
+// 1.3, no 0-RTT, no real multiplexed stream flow control. What it does
+// keep is the part this backlog item actually asks for: per-connection
+// congestion control feeding back from queue backpressure.
+
+/// A pluggable congestion-control algorithm, queried for the current
+/// window and fed ack/loss events exactly like a TCP stack's would be -
+/// except here the "loss" signal comes from `SafeBoundedQueue::enqueue`
+/// failing (the consumer can't keep up) rather than from a dropped
+/// packet. The window is only reported (`QuicRequestTransport::cwnd`):
+/// nothing on the ingest path waits on it, and the request whose enqueue
+/// failed is dropped.
+trait CongestionControl: Send {
+    /// Current congestion window, in requests allowed in flight.
+    fn cwnd(&self) -> u64;
+    fn on_ack(&mut self, acked: u64);
+    fn on_loss(&mut self);
+}
+
+/// Classic slow-start-then-AIMD. Adds the acked count to `cwnd` on every
+/// ack while below `ssthresh` (slow start, doubling it once per window of
+/// acks), then grows by roughly one request per window
+/// once past it (congestion avoidance), and on loss sets `ssthresh` to
+/// half the current window and drops `cwnd` to match (NewReno fast
+/// recovery, rather than Reno's full reset to 1).
+struct NewRenoCongestionControl {
+    cwnd: u64,
+    ssthresh: u64,
+}
+
+impl NewRenoCongestionControl {
+    fn new() -> Self {
+        Self { cwnd: 1, ssthresh: u64::MAX }
+    }
+}
+
+impl CongestionControl for NewRenoCongestionControl {
+    fn cwnd(&self) -> u64 {
+        self.cwnd
+    }
+
+    fn on_ack(&mut self, acked: u64) {
+        let acked = acked.max(1);
+        if self.cwnd < self.ssthresh {
+            self.cwnd = self.cwnd.saturating_add(acked);
+        } else {
+            self.cwnd = self.cwnd.saturating_add((acked / self.cwnd.max(1)).max(1));
+        }
+    }
+
+    fn on_loss(&mut self) {
+        self.ssthresh = (self.cwnd / 2).max(2);
+        self.cwnd = self.ssthresh;
+    }
+}
+
+const CUBIC_C: f64 = 0.4;
+const CUBIC_BETA: f64 = 0.7;
+
+/// RFC 8312 CUBIC, approximated with a logical tick counter standing in
+/// for wall-clock time since the window doesn't otherwise track RTT:
+/// `W(t) = C*(t-K)^3 + w_max`, reset on loss with `w_max` pinned to the
+/// pre-loss window and `K` recomputed so the curve re-approaches `w_max`
+/// from below.
+struct CubicCongestionControl {
+    cwnd: f64,
+    w_max: f64,
+    k: f64,
+    epoch_start: u64,
+    tick: u64,
+}
+
+impl CubicCongestionControl {
+    fn new() -> Self {
+        Self { cwnd: 1.0, w_max: 1.0, k: 0.0, epoch_start: 0, tick: 0 }
+    }
+}
+
+impl CongestionControl for CubicCongestionControl {
+    fn cwnd(&self) -> u64 {
+        self.cwnd.round().max(1.0) as u64
+    }
+
+    fn on_ack(&mut self, acked: u64) {
+        self.tick += 1;
+        let t = (self.tick - self.epoch_start) as f64;
+        let target = CUBIC_C * (t - self.k).powi(3) + self.w_max;
+
+        if target > self.cwnd {
+            self.cwnd = target;
+        } else {
+            // Below the cubic curve: grow conservatively (TCP-friendly
+            // region) rather than stalling until the curve catches up.
+            self.cwnd += (acked.max(1) as f64) / self.cwnd.max(1.0);
+        }
+    }
+
+    fn on_loss(&mut self) {
+        self.w_max = self.cwnd;
+        self.cwnd = (self.cwnd * CUBIC_BETA).max(2.0);
+        self.k = (self.w_max * (1.0 - CUBIC_BETA) / CUBIC_C).cbrt();
+        self.epoch_start = self.tick;
+    }
+}
+
+/// Ingest/response side of a request pipeline, abstracted so the rest of
+/// the backend doesn't have to know whether requests arrive over the
+/// `QuicRequestTransport` below, a plain TCP listener, or an in-process
+/// test harness.
+trait RequestTransport {
+    /// Returns the next request already delivered to the local queue, if
+    /// any - never blocks.
+    fn poll_recv(&self) -> Option<ProductionOptimizedRequest>;
+    fn send_response(&self, request_id: u64, payload: &[u8]) -> std::io::Result<()>;
+}
+
+/// UDP-backed `RequestTransport` with pluggable congestion control `C`.
+/// A background thread reads `[stream_id][64-byte request]` datagrams
+/// off the socket and attempts to hand each decoded request to a shared
+/// `SafeBoundedQueue`: success acks the congestion controller, and a full
+/// queue reports a loss and drops that request. The controller's window
+/// does not pace the socket reads, so a slow consumer does not slow how
+/// fast requests are accepted; while the queue is full, arriving requests
+/// are dropped.
+struct QuicRequestTransport<C: CongestionControl> {
+    socket: Arc<UdpSocket>,
+    queue: Arc<SafeBoundedQueue<ProductionOptimizedRequest>>,
+    peer_addrs: Arc<Mutex<HashMap<u64, SocketAddr>>>,
+    congestion: Arc<Mutex<C>>,
+    stop: Arc<AtomicBool>,
+    ingest_thread: Option<JoinHandle<()>>,
+}
+
+impl<C: CongestionControl + 'static> QuicRequestTransport<C> {
+    fn bind(addr: &str, congestion: C) -> std::io::Result<Self> {
+        let socket = Arc::new(UdpSocket::bind(addr)?);
+        socket.set_read_timeout(Some(Duration::from_millis(100)))?;
+
+        let queue = Arc::new(SafeBoundedQueue::new());
+        let peer_addrs = Arc::new(Mutex::new(HashMap::new()));
+        let congestion = Arc::new(Mutex::new(congestion));
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let ingest_thread = {
+            let socket = Arc::clone(&socket);
+            let queue = Arc::clone(&queue);
+            let peer_addrs = Arc::clone(&peer_addrs);
+            let congestion = Arc::clone(&congestion);
+            let stop = Arc::clone(&stop);
+
+            thread::spawn(move || {
+                let mut frame = [0u8; 8 + ProductionOptimizedRequest::WIRE_SIZE];
+                while !stop.load(Ordering::Relaxed) {
+                    let (len, from) = match socket.recv_from(&mut frame) {
+                        Ok(received) => received,
+                        Err(ref e)
+                            if e.kind() == std::io::ErrorKind::WouldBlock
+                                || e.kind() == std::io::ErrorKind::TimedOut =>
+                        {
+                            continue;
+                        }
+                        Err(_) => continue,
+                    };
+
+                    if len != frame.len() {
+                        continue; // Malformed frame - drop it silently.
+                    }
+
+                    let payload: [u8; ProductionOptimizedRequest::WIRE_SIZE] =
+                        frame[8..].try_into().unwrap();
+                    let request = ProductionOptimizedRequest::from_bytes(&payload);
+                    let request_id = request.request_id;
+
+                    peer_addrs.lock().unwrap().insert(request_id, from);
+
+                    match queue.enqueue(request) {
+                        Ok(()) => congestion.lock().unwrap().on_ack(1),
+                        Err(_) => congestion.lock().unwrap().on_loss(),
+                    }
+                }
+            })
+        };
+
+        Ok(Self {
+            socket,
+            queue,
+            peer_addrs,
+            congestion,
+            stop,
+            ingest_thread: Some(ingest_thread),
+        })
+    }
+
+    fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        self.socket.local_addr()
+    }
+
+    fn cwnd(&self) -> u64 {
+        self.congestion.lock().unwrap().cwnd()
+    }
+
+    fn stop(mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.ingest_thread.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl<C: CongestionControl> Drop for QuicRequestTransport<C> {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.ingest_thread.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl<C: CongestionControl + 'static> RequestTransport for QuicRequestTransport<C> {
+    fn poll_recv(&self) -> Option<ProductionOptimizedRequest> {
+        self.queue.dequeue()
+    }
+
+    fn send_response(&self, request_id: u64, payload: &[u8]) -> std::io::Result<()> {
+        let addr = *self
+            .peer_addrs
+            .lock()
+            .unwrap()
+            .get(&request_id)
+            .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::NotFound, "unknown request_id"))?;
+        self.socket.send_to(payload, addr)?;
+        Ok(())
+    }
+}
+
+// VALIDATION FUNCTIONS
+fn validate_production_safety() {
+    println!("üõ°Ô∏è PRODUCTION SAFETY VALIDATION");
+    println!("================================");
+
+    let cpu_features = CpuFeatures::detect();
+    println!("   ‚úÖ CPU Features Detected:");
+    println!("      ‚Ä¢ RDTSC: {}", cpu_features.has_rdtsc);
+    println!("      ‚Ä¢ Prefetch: {}", cpu_features.has_prefetch);
+    println!("      ‚Ä¢ AVX: {}", cpu_features.has_avx);
+    println!("      ‚Ä¢ Cache Line Size: {} bytes", cpu_features.cache_line_size);
+
+    let queue: SafeBoundedQueue<u32> = SafeBoundedQueue::new();
+
+    // Test bounds checking. Every slot (not capacity - 1) is usable now that
+    // fullness is detected via per-slot sequence numbers instead of a
+    // reserved empty slot.
+    for i in 0..queue.capacity {
+        assert!(queue.enqueue(i as u32).is_ok());
+    }
+    assert!(queue.enqueue(999).is_err());
 
     // Test dequeue safety
-    for _ in 0..(queue.capacity - 1) {
+    for _ in 0..queue.capacity {
         assert!(queue.dequeue().is_some());
     }
     assert!(queue.dequeue().is_none());
@@ -503,6 +1894,7 @@ fn validate_enterprise_monitoring() {
     println!("      ‚Ä¢ Peak usage: {}", stats.peak_usage);
     println!("      ‚Ä¢ Allocation failures: {}", stats.allocation_failures);
     println!("      ‚Ä¢ Average allocation time: {:?}", stats.avg_allocation_time);
+    println!("      ‚Ä¢ Growth events: {} (capacity now {})", stats.growth_events, stats.pool_size);
 
     while let Some(obj) = allocations.pop() {
         pool.deallocate(obj);
@@ -537,6 +1929,73 @@ fn validate_network_optimization() {
     }
 }
 
+fn validate_live_metrics() {
+    println!("üìê LIVE METRICS VALIDATION");
+    println!("==========================");
+
+    let optimizer = ProductionNetworkOptimizer::new();
+    let monitor = SystemMonitorService::start();
+
+    // Give the background thread time to take at least one network sample
+    // before reading the snapshot back.
+    std::thread::sleep(Duration::from_millis(2_250));
+
+    let network = monitor.network_stats();
+    let memory = monitor.memory_stats();
+    let cpu = monitor.cpu_stats();
+    let optimization = optimizer.calculate_optimal_buffer_size_from_live_metrics(&monitor);
+
+    println!("   ‚úÖ Samples taken: {}", monitor.samples_taken());
+    println!("      ‚Ä¢ RX: {:.0} bytes/sec", network.rx_bytes_per_sec);
+    println!("      ‚Ä¢ TX: {:.0} bytes/sec", network.tx_bytes_per_sec);
+    println!("      ‚Ä¢ TCP retransmits: {:.2}/sec", network.tcp_retransmits_per_sec);
+    println!("      ‚Ä¢ Smoothed RTT estimate: {:.2}ms", monitor.smoothed_rtt_ms());
+    println!("      ‚Ä¢ Memory available: {} / {} bytes", memory.available_bytes, memory.total_bytes);
+    println!("      ‚Ä¢ CPU utilization: {:.1}%", cpu.usage_fraction * 100.0);
+    println!(
+        "   ‚úÖ Live-metrics buffer recommendation: {} bytes (valid: {})",
+        optimization.recommended_buffer_bytes, optimization.is_valid
+    );
+
+    monitor.stop();
+}
+
+fn validate_adaptive_buffer_sizing() {
+    println!("📶 ADAPTIVE BUFFER SIZING VALIDATION");
+    println!("=================================");
+
+    let optimizer = ProductionNetworkOptimizer::new();
+    let network_monitor = SystemNetworkMonitor::start(SystemNetworkMonitor::DEFAULT_SAMPLE_INTERVAL);
+
+    // Give the background thread time to take at least one sample before
+    // reading the buffer-error trend back.
+    std::thread::sleep(Duration::from_millis(2_250));
+
+    let counters = network_monitor.stats();
+    let static_optimization = optimizer.calculate_optimal_buffer_size_comprehensive(100.0, 20.0);
+    let adaptive_optimization =
+        optimizer.calculate_optimal_buffer_size_adaptive(100.0, 20.0, &network_monitor);
+
+    println!("   ✅ Samples taken: {}", network_monitor.samples_taken());
+    println!(
+        "      • RX: {:.0} bytes/sec  TX: {:.0} bytes/sec",
+        counters.rx_bytes_per_sec, counters.tx_bytes_per_sec
+    );
+    println!("      • RX drops: {:.2}/sec  TX drops: {:.2}/sec", counters.rx_drops_per_sec, counters.tx_drops_per_sec);
+    println!("      • UDP InDatagrams: {:.2}/sec", counters.udp_in_datagrams_per_sec);
+    println!(
+        "      • UDP RcvbufErrors: {:.2}/sec  SndbufErrors: {:.2}/sec  InErrors: {:.2}/sec",
+        counters.udp_rcvbuf_errors_per_sec, counters.udp_sndbuf_errors_per_sec, counters.udp_in_errors_per_sec
+    );
+    println!("      • Current buffer scale: {:.2}x", network_monitor.buffer_scale());
+    println!(
+        "   ✅ Static buffer recommendation: {} bytes -> adaptive: {} bytes",
+        static_optimization.recommended_buffer_bytes, adaptive_optimization.recommended_buffer_bytes
+    );
+
+    network_monitor.stop();
+}
+
 fn validate_request_structure() {
     println!("üì¶ REQUEST STRUCTURE VALIDATION");
     println!("===============================");
@@ -566,84 +2025,320 @@ fn validate_request_structure() {
     println!("   ‚úÖ Invalid request rejection: PASSED");
 }
 
+fn validate_quic_transport() {
+    println!("📡 QUIC-STYLE TRANSPORT VALIDATION");
+    println!("================================");
+    println!("   (UDP framing approximation - not the real RFC 9000 protocol)");
+
+    let transport = QuicRequestTransport::bind("127.0.0.1:0", NewRenoCongestionControl::new())
+        .expect("failed to bind QUIC transport socket");
+    let server_addr = transport.local_addr().expect("transport has no local address");
+
+    let client = UdpSocket::bind("127.0.0.1:0").expect("failed to bind client socket");
+    client.set_read_timeout(Some(Duration::from_secs(2))).unwrap();
+
+    const REQUEST_COUNT: u64 = 5;
+    let mut sent_ids = Vec::with_capacity(REQUEST_COUNT as usize);
+
+    for i in 0..REQUEST_COUNT {
+        let request = ProductionOptimizedRequest {
+            request_id: i,
+            priority: (i % 4) as u32,
+            ..ProductionOptimizedRequest::default()
+        };
+        sent_ids.push(request.request_id);
+
+        let mut frame = [0u8; 8 + ProductionOptimizedRequest::WIRE_SIZE];
+        frame[0..8].copy_from_slice(&i.to_le_bytes());
+        frame[8..].copy_from_slice(&request.to_bytes());
+        client.send_to(&frame, server_addr).expect("failed to send request frame");
+    }
+
+    // Give the background ingest thread time to drain the socket.
+    let mut received = Vec::with_capacity(REQUEST_COUNT as usize);
+    let deadline = Instant::now() + Duration::from_secs(2);
+    while received.len() < REQUEST_COUNT as usize && Instant::now() < deadline {
+        if let Some(request) = transport.poll_recv() {
+            received.push(request.request_id);
+        } else {
+            thread::yield_now();
+        }
+    }
+
+    received.sort_unstable();
+    assert_eq!(received, sent_ids, "not every sent request was delivered to the queue");
+    println!("   ✅ Delivered {}/{} requests through the transport", received.len(), REQUEST_COUNT);
+
+    transport.send_response(0, b"ack").expect("failed to send response");
+    let mut response_buf = [0u8; 16];
+    let (len, _) = client.recv_from(&mut response_buf).expect("failed to receive response");
+    assert_eq!(&response_buf[..len], b"ack");
+    println!("   ✅ Routed response back to the originating client socket");
+    println!("   • NewReno congestion window after {} acks: {}", REQUEST_COUNT, transport.cwnd());
+
+    transport.stop();
+
+    // Exercise both congestion-control implementations directly - the
+    // transport above only demonstrates NewReno wired end-to-end, but the
+    // request asks for a pluggable interface supporting both.
+    let mut new_reno = NewRenoCongestionControl::new();
+    let mut cubic = CubicCongestionControl::new();
+    for _ in 0..10 {
+        new_reno.on_ack(1);
+        cubic.on_ack(1);
+    }
+    println!("   • NewReno cwnd after 10 acks (no loss): {}", new_reno.cwnd());
+    println!("   • CUBIC cwnd after 10 acks (no loss): {}", cubic.cwnd());
+
+    new_reno.on_loss();
+    cubic.on_loss();
+    println!("   • NewReno cwnd after a loss event: {}", new_reno.cwnd());
+    println!("   • CUBIC cwnd after a loss event: {}", cubic.cwnd());
+}
+
+/// One benchmark's summary statistics over every recorded sample, in
+/// nanoseconds. Kept separate from the live [`LatencySampler`] so it can be
+/// serialized and compared run-over-run without dragging the sampler itself
+/// along.
+struct PerformanceTestResult {
+    name: String,
+    mean: f64,
+    std_dev: f64,
+    min: f64,
+    max: f64,
+    p50: f64,
+    p99: f64,
+    p999: f64,
+}
+
+impl PerformanceTestResult {
+    fn to_json(&self) -> String {
+        format!(
+            "{{\"name\":\"{}\",\"mean\":{:.2},\"std_dev\":{:.2},\"min\":{:.2},\"max\":{:.2},\"p50\":{:.2},\"p99\":{:.2},\"p999\":{:.2}}}",
+            self.name, self.mean, self.std_dev, self.min, self.max, self.p50, self.p99, self.p999
+        )
+    }
+}
+
+/// A benchmark report for one run of this binary. Written out as JSON so CI
+/// can diff successive runs instead of eyeballing console output.
+struct MetricsReport {
+    git_revision: String,
+    git_commit_date: String,
+    date: String,
+    results: Vec<PerformanceTestResult>,
+}
+
+impl MetricsReport {
+    fn to_json(&self) -> String {
+        let results_json: Vec<String> = self.results.iter().map(PerformanceTestResult::to_json).collect();
+        format!(
+            "{{\"git_revision\":\"{}\",\"git_commit_date\":\"{}\",\"date\":\"{}\",\"results\":[{}]}}",
+            self.git_revision, self.git_commit_date, self.date, results_json.join(",")
+        )
+    }
+
+    fn write_to_file(&self, path: &str) -> std::io::Result<()> {
+        std::fs::write(path, self.to_json())
+    }
+}
+
+/// Shells out to `git` for revision metadata. Falls back to `"unknown"`
+/// rather than failing the benchmark when run outside a git checkout (e.g.
+/// from an extracted release tarball).
+fn git_command_output(args: &[&str]) -> String {
+    std::process::Command::new("git")
+        .args(args)
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// No `chrono` dependency is available here, so the report date is just the
+/// Unix timestamp of report generation - still enough to order runs.
+fn report_date_string() -> String {
+    let secs = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+    secs.to_string()
+}
+
+/// Runs an operation across `rounds` independent samples of `iterations_per_round`
+/// calls each, recording every iteration's latency so percentiles - not just a
+/// single average - can be reported.
+struct LatencySampler {
+    rounds: usize,
+    iterations_per_round: usize,
+}
+
+impl LatencySampler {
+    fn new(rounds: usize, iterations_per_round: usize) -> Self {
+        Self { rounds, iterations_per_round }
+    }
+
+    fn run(&self, name: &str, mut op: impl FnMut()) -> PerformanceTestResult {
+        let mut samples_ns: Vec<u64> = Vec::with_capacity(self.rounds * self.iterations_per_round);
+
+        for _ in 0..self.rounds {
+            for _ in 0..self.iterations_per_round {
+                let start = Instant::now();
+                op();
+                samples_ns.push(start.elapsed().as_nanos() as u64);
+            }
+        }
+
+        Self::summarize(name, samples_ns)
+    }
+
+    fn percentile(sorted_ns: &[u64], p: f64) -> f64 {
+        let n = sorted_ns.len();
+        let idx = ((p * n as f64).ceil() as usize).saturating_sub(1).min(n - 1);
+        sorted_ns[idx] as f64
+    }
+
+    fn summarize(name: &str, mut samples_ns: Vec<u64>) -> PerformanceTestResult {
+        samples_ns.sort_unstable();
+        let n = samples_ns.len().max(1);
+
+        let sum: u64 = samples_ns.iter().sum();
+        let mean = sum as f64 / n as f64;
+
+        let variance = samples_ns
+            .iter()
+            .map(|&x| {
+                let diff = x as f64 - mean;
+                diff * diff
+            })
+            .sum::<f64>()
+            / n as f64;
+
+        PerformanceTestResult {
+            name: name.to_string(),
+            mean,
+            std_dev: variance.sqrt(),
+            min: *samples_ns.first().unwrap_or(&0) as f64,
+            max: *samples_ns.last().unwrap_or(&0) as f64,
+            p50: Self::percentile(&samples_ns, 0.50),
+            p99: Self::percentile(&samples_ns, 0.99),
+            p999: Self::percentile(&samples_ns, 0.999),
+        }
+    }
+}
+
 fn benchmark_production_performance() {
-    println!("‚ö° PRODUCTION PERFORMANCE BENCHMARK");
+    println!("⚡️ PRODUCTION PERFORMANCE BENCHMARK");
     println!("===================================");
 
     let queue: Arc<SafeBoundedQueue<ProductionOptimizedRequest>> = Arc::new(SafeBoundedQueue::new());
     let counter: Arc<EnterpriseCacheAlignedCounter> = Arc::new(EnterpriseCacheAlignedCounter::new());
-    let timer = ProductionHighPrecisionTimer::new();
 
-    let iterations = 100_000;
+    let sampler = LatencySampler::new(10, 10_000);
+    let mut next_request_id: u64 = 0;
 
-    let (_, duration, cycles) = timer.measure_precise(|| {
-        for i in 0..iterations {
-            let mut request = ProductionOptimizedRequest::default();
-            request.request_id = i as u64;
-            request.priority = (i % 4) as u32;
+    let result = sampler.run("full_pipeline_enqueue_increment_dequeue", || {
+        let mut request = ProductionOptimizedRequest::default();
+        request.request_id = next_request_id;
+        request.priority = (next_request_id % 4) as u32;
+        next_request_id += 1;
 
-            match queue.enqueue(request) {
-                Ok(_) => {
-                    counter.increment();
-                    if let Some(_) = queue.dequeue() {
-                        // Processing would happen here
-                    }
-                }
-                Err(_) => {
-                    // Queue full - backpressure
+        match queue.enqueue(request) {
+            Ok(_) => {
+                counter.increment();
+                if let Some(_) = queue.dequeue() {
+                    // Processing would happen here
                 }
             }
+            Err(_) => {
+                // Queue full - backpressure
+            }
         }
     });
 
-    let avg_latency_ns = duration.as_nanos() as f64 / iterations as f64;
-    let throughput = iterations as f64 / duration.as_secs_f64();
-
-    println!("   üìä Benchmark Results:");
-    println!("   ‚Ä¢ Iterations: {}", iterations);
-    println!("   ‚Ä¢ Total time: {:?}", duration);
-    println!("   ‚Ä¢ Average latency: {:.2}ns per request", avg_latency_ns);
-    println!("   ‚Ä¢ Throughput: {:.0} requests/second", throughput);
-    println!("   ‚Ä¢ CPU cycles (if available): {}", cycles);
+    println!("   📊 Benchmark Results ({} samples across {} rounds):", sampler.rounds * sampler.iterations_per_round, sampler.rounds);
+    println!("   • Mean: {:.2}ns  StdDev: {:.2}ns", result.mean, result.std_dev);
+    println!("   • Min: {:.2}ns  Max: {:.2}ns", result.min, result.max);
+    println!("   • p50: {:.2}ns  p99: {:.2}ns  p999: {:.2}ns", result.p50, result.p99, result.p999);
 
     let target_latency_ns = 20_000_000.0;
-    let performance_ratio = avg_latency_ns / target_latency_ns;
+    let performance_ratio = result.mean / target_latency_ns;
 
-    println!("   üéØ Performance vs Target:");
-    println!("   ‚Ä¢ Target latency: {}ns", target_latency_ns as u64);
-    println!("   ‚Ä¢ Actual latency: {:.0}ns", avg_latency_ns);
-    println!("   ‚Ä¢ Performance ratio: {:.2}% of target", performance_ratio * 100.0);
-    println!("   ‚Ä¢ Safety factor: {:.0}x", 1.0 / performance_ratio);
+    println!("   🎯 Performance vs Target:");
+    println!("   • Target latency: {}ns", target_latency_ns as u64);
+    println!("   • Actual latency (mean): {:.0}ns", result.mean);
+    println!("   • Performance ratio: {:.2}% of target", performance_ratio * 100.0);
+    println!("   • Safety factor: {:.0}x", 1.0 / performance_ratio);
 
     if performance_ratio < 1.0 {
-        println!("   ‚úÖ TARGET ACHIEVED: Sub-20ms latency confirmed!");
+        println!("   ✅ TARGET ACHIEVED: Sub-20ms latency confirmed!");
     } else {
-        println!("   ‚ö†Ô∏è  Target not met, but still excellent performance");
+        println!("   ⚠️  Target not met, but still excellent performance");
     }
-}
 
+    let report = MetricsReport {
+        git_revision: git_command_output(&["describe", "--dirty", "--always"]),
+        git_commit_date: git_command_output(&["log", "-1", "--format=%cI"]),
+        date: report_date_string(),
+        results: vec![result],
+    };
+
+    match report.write_to_file("benchmark_metrics.json") {
+        Ok(()) => println!("   📂 Metrics exported to benchmark_metrics.json"),
+        Err(e) => println!("   ⚠️  Failed to export metrics: {}", e),
+    }
+}
 fn demonstrate_comprehensive_latency_breakdown() {
-    println!("üìà COMPREHENSIVE LATENCY BREAKDOWN ANALYSIS");
+    println!("📈 COMPREHENSIVE LATENCY BREAKDOWN ANALYSIS");
     println!("===========================================");
 
+    let profile = HardwareProfile::probe();
+    println!(
+        "   Hardware profile: CPU score {:.2}x reference, memory bandwidth {:.2} GiB/s, cache line {} bytes",
+        profile.cpu_score, profile.memory_bandwidth_gib_per_sec, profile.cache_line_size()
+    );
+
+    // Reference (baseline-machine) latency per component, the scaling that
+    // component should track, and a human description. NIC components are
+    // bounded by the network, not by this host's CPU or memory, so they are
+    // left unscaled.
+    enum Scaling {
+        Fixed,
+        Cpu,
+        Memory,
+    }
+
     let components = vec![
-        ("Network RX", 500, "NIC processing + DMA"),
-        ("Kernel‚ÜíUser Copy", 50, "Context switch + memcpy"),
-        ("Bounds Check", 5, "Safety validation"),
-        ("Queue Lookup", 15, "Atomic load + bit mask"),
-        ("Cache Line Access", 3, "L1 cache hit"),
-        ("Prefetch Overhead", 2, "CPU prefetch instruction"),
-        ("Processing Logic", 25, "Business logic execution"),
-        ("Response Serialize", 20, "JSON/binary serialization"),
-        ("Network TX", 500, "NIC transmission"),
+        ("Network RX", 500u64, Scaling::Fixed, "NIC processing + DMA"),
+        ("Kernel→User Copy", 50, Scaling::Memory, "Context switch + memcpy"),
+        ("Bounds Check", 5, Scaling::Cpu, "Safety validation"),
+        ("Queue Lookup", 15, Scaling::Cpu, "Atomic load + bit mask"),
+        ("Cache Line Access", 3, Scaling::Cpu, "L1 cache hit"),
+        ("Prefetch Overhead", 2, Scaling::Cpu, "CPU prefetch instruction"),
+        ("Processing Logic", 25, Scaling::Cpu, "Business logic execution"),
+        ("Response Serialize", 20, Scaling::Memory, "JSON/binary serialization"),
+        ("Network TX", 500, Scaling::Fixed, "NIC transmission"),
     ];
 
-    let mut total_ns = 0;
-    println!("   Component Breakdown:");
+    let memory_ratio =
+        HardwareProfile::REFERENCE_MEMORY_BANDWIDTH_GIB_PER_SEC / profile.memory_bandwidth_gib_per_sec.max(0.01);
+    let cpu_ratio = 1.0 / profile.cpu_score.max(0.01);
+
+    let calibrated_ns = |reference_ns: u64, scaling: &Scaling| -> u64 {
+        match scaling {
+            Scaling::Fixed => reference_ns,
+            Scaling::Cpu => ((reference_ns as f64) * cpu_ratio).round() as u64,
+            Scaling::Memory => ((reference_ns as f64) * memory_ratio).round() as u64,
+        }
+    };
+
+    let mut total_ns = 0u64;
+    println!("   Component Breakdown (calibrated to this machine):");
     println!("   {:<25} {:>8} {:<30}", "Component", "Latency", "Description");
     println!("   {:-<25} {:-<8} {:-<30}", "", "", "");
 
-    for (component, ns, description) in &components {
+    for (component, reference_ns, scaling, description) in &components {
+        let ns = calibrated_ns(*reference_ns, scaling);
         println!("   {:<25} {:>8}ns {:<30}", component, ns, description);
         total_ns += ns;
     }
@@ -651,56 +2346,169 @@ fn demonstrate_comprehensive_latency_breakdown() {
     println!("   {:-<25} {:-<8} {:-<30}", "", "", "");
     println!("   {:<25} {:>8}ns", "TOTAL LATENCY", total_ns);
 
-    let target_ns = 20_000_000;
-    let safety_factor = target_ns / total_ns;
+    // The target budget scales the same way the CPU-bound components do:
+    // a faster machine gets a tighter realistic target instead of every
+    // machine being judged against the same fixed 20ms figure.
+    const REFERENCE_TARGET_NS: u64 = 20_000_000;
+    let target_ns = ((REFERENCE_TARGET_NS as f64) * cpu_ratio).round().max(1.0) as u64;
+    let safety_factor = target_ns / total_ns.max(1);
 
     println!("   ");
-    println!("   üéØ Performance Analysis:");
-    println!("   ‚Ä¢ Theoretical latency: {}ns = {:.2}Œºs", total_ns, total_ns as f64 / 1000.0);
-    println!("   ‚Ä¢ Target latency: {}ns = 20ms", target_ns);
-    println!("   ‚Ä¢ Safety factor: {}x", safety_factor);
-    println!("   ‚Ä¢ Performance margin: {:.1}%", (1.0 - (total_ns as f64 / target_ns as f64)) * 100.0);
+    println!("   🎯 Performance Analysis:");
+    println!("   • Theoretical latency: {}ns = {:.2}μs", total_ns, total_ns as f64 / 1000.0);
+    println!("   • Calibrated target latency: {}ns = {:.2}ms", target_ns, target_ns as f64 / 1_000_000.0);
+    println!("   • Safety factor: {}x", safety_factor);
+    println!("   • Performance margin: {:.1}%", (1.0 - (total_ns as f64 / target_ns as f64)) * 100.0);
 
     if total_ns < target_ns {
-        println!("   ‚úÖ SUB-20MS TARGET ACHIEVED!");
-        println!("   ‚úÖ Enterprise-grade performance confirmed!");
+        println!("   ✅ SUB-TARGET LATENCY ACHIEVED!");
+        println!("   ✅ Enterprise-grade performance confirmed!");
     }
 }
 
-fn main() {
-    println!("üöÄ 99.9% PRODUCTION-READY LOW LATENCY BACKEND");
-    println!("==============================================");
-    println!();
+/// Outcome of one guarded validation/benchmark phase.
+enum PhaseOutcome {
+    Passed,
+    Failed(String),
+    TimedOut,
+}
 
-    validate_production_safety();
-    println!();
+struct PhaseResult {
+    name: &'static str,
+    outcome: PhaseOutcome,
+    elapsed: Duration,
+}
 
-    validate_enterprise_monitoring();
-    println!();
+/// Runs `phase` on a spawned thread and waits up to `timeout` for it to
+/// report back over an `mpsc` channel, rather than calling it inline. A
+/// deadlocked lock-free structure then times out that one phase instead of
+/// hanging the whole suite - important for running this in CI, where a hang
+/// must fail loudly rather than block forever.
+fn run_guarded_phase(name: &'static str, timeout: Duration, phase: fn()) -> PhaseResult {
+    let (tx, rx) = mpsc::channel();
+    let start = Instant::now();
+
+    thread::spawn(move || {
+        let outcome = match std::panic::catch_unwind(phase) {
+            Ok(()) => PhaseOutcome::Passed,
+            Err(payload) => PhaseOutcome::Failed(panic_payload_message(&payload)),
+        };
+        // The receiver may already have timed out and moved on; there is
+        // nothing left to deliver to in that case, so ignore send errors.
+        let _ = tx.send(outcome);
+    });
 
-    validate_network_optimization();
-    println!();
+    let outcome = rx.recv_timeout(timeout).unwrap_or(PhaseOutcome::TimedOut);
+    PhaseResult { name, outcome, elapsed: start.elapsed() }
+}
 
-    validate_request_structure();
-    println!();
+fn panic_payload_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic payload".to_string()
+    }
+}
 
-    demonstrate_comprehensive_latency_breakdown();
-    println!();
+fn print_phase_result(result: &PhaseResult) {
+    match &result.outcome {
+        PhaseOutcome::Passed => println!("   ✅ {} - PASSED ({:?})", result.name, result.elapsed),
+        PhaseOutcome::Failed(message) => println!("   ❌ {} - FAILED: {} ({:?})", result.name, message, result.elapsed),
+        PhaseOutcome::TimedOut => println!("   ⏰ {} - TIMED OUT after {:?}", result.name, result.elapsed),
+    }
+}
 
-    benchmark_production_performance();
+fn main() {
+    println!("🚀 99.9% PRODUCTION-READY LOW LATENCY BACKEND");
+    println!("==============================================");
     println!();
 
-    println!("üéâ VALIDATION COMPLETE - 99.9% ACHIEVED!");
+    const PHASE_TIMEOUT: Duration = Duration::from_secs(30);
+
+    let phases: Vec<(&'static str, fn())> = vec![
+        ("Production Safety", validate_production_safety),
+        ("Enterprise Monitoring", validate_enterprise_monitoring),
+        ("Network Optimization", validate_network_optimization),
+        ("Live Metrics", validate_live_metrics),
+        ("Adaptive Buffer Sizing", validate_adaptive_buffer_sizing),
+        ("Request Structure", validate_request_structure),
+        ("QUIC-style Transport", validate_quic_transport),
+        ("Latency Breakdown", demonstrate_comprehensive_latency_breakdown),
+        ("Performance Benchmark", benchmark_production_performance),
+    ];
+
+    let mut results = Vec::with_capacity(phases.len());
+    for (name, phase) in phases {
+        let result = run_guarded_phase(name, PHASE_TIMEOUT, phase);
+        print_phase_result(&result);
+        println!();
+        results.push(result);
+    }
+
+    let passed = results.iter().filter(|r| matches!(r.outcome, PhaseOutcome::Passed)).count();
+    let failed = results.iter().filter(|r| matches!(r.outcome, PhaseOutcome::Failed(_))).count();
+    let timed_out = results.iter().filter(|r| matches!(r.outcome, PhaseOutcome::TimedOut)).count();
+
+    println!("🎉 VALIDATION SUITE SUMMARY");
     println!("========================================");
-    println!("‚úÖ Production Safety: 100/100");
-    println!("‚úÖ Enterprise Monitoring: 100/100");
-    println!("‚úÖ Network Optimization: 100/100");
-    println!("‚úÖ Request Structure: 100/100");
-    println!("‚úÖ Performance Benchmark: 99/100");
-    println!("‚úÖ Mathematical Foundations: 100/100");
+    for result in &results {
+        print_phase_result(result);
+    }
     println!("========================================");
-    println!("üèÜ OVERALL SCORE: 99.9/100");
-    println!("üéØ SUB-20MS LATENCY: CONFIRMED");
-    println!("üõ°Ô∏è ENTERPRISE SAFETY: VERIFIED");
-    println!("‚ö° PRODUCTION READY: DEPLOYMENT APPROVED");
+    println!("✅ Passed: {}/{}", passed, results.len());
+    if failed > 0 {
+        println!("❌ Failed: {}/{}", failed, results.len());
+    }
+    if timed_out > 0 {
+        println!("⏰ Timed out: {}/{}", timed_out, results.len());
+    }
+
+    if failed == 0 && timed_out == 0 {
+        println!("🏆 ALL PHASES PASSED: DEPLOYMENT APPROVED");
+    } else {
+        println!("⚠️ SUITE DID NOT FULLY PASS: REVIEW FAILURES ABOVE");
+    }
+}
+
+#[cfg(all(test, feature = "failpoints"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn armed_queue_bounds_failpoint_forces_enqueue_err() {
+        let queue: SafeBoundedQueue<u32> = SafeBoundedQueue::new();
+
+        failpoints::arm("queue.enqueue.bounds", 1.0);
+        assert!(queue.enqueue(1).is_err());
+        failpoints::disarm("queue.enqueue.bounds");
+
+        assert!(queue.enqueue(1).is_ok());
+    }
+
+    #[test]
+    fn armed_pool_allocate_failpoint_forces_none_and_counts_failure() {
+        let mut pool: EnterpriseMemoryPool<u32> = EnterpriseMemoryPool::new();
+
+        failpoints::arm("pool.allocate.fail", 1.0);
+        assert!(pool.allocate().is_none());
+        assert_eq!(pool.get_stats().allocation_failures, 1);
+        failpoints::disarm("pool.allocate.fail");
+
+        assert!(pool.allocate().is_some());
+    }
+
+    #[test]
+    fn armed_counter_overflow_failpoint_increments_overflow_count() {
+        let counter = EnterpriseCacheAlignedCounter::new();
+
+        failpoints::arm("counter.overflow", 1.0);
+        counter.increment();
+        assert_eq!(counter.overflow_count(), 1);
+        failpoints::disarm("counter.overflow");
+
+        counter.increment();
+        assert_eq!(counter.overflow_count(), 1);
+    }
 }